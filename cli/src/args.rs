@@ -5,6 +5,7 @@ use std::path::PathBuf;
 #[non_exhaustive]
 pub enum AvailableLanguage {
     Kotlin,
+    Java,
     Scala,
     Swift,
     Typescript,
@@ -12,6 +13,16 @@ pub enum AvailableLanguage {
     Go,
     #[cfg(feature = "python")]
     Python,
+    #[value(name = "csharp")]
+    CSharp,
+    Dart,
+    #[value(name = "jsonschema")]
+    JsonSchema,
+    #[value(name = "openapi")]
+    OpenApi,
+    Protobuf,
+    #[value(name = "graphql")]
+    GraphQl,
 }
 
 #[derive(clap::Parser)]
@@ -29,6 +40,44 @@ pub struct Args {
     #[arg(short, long = "lang", required_unless_present = "generate_config")]
     pub language: Option<AvailableLanguage>,
 
+    #[command(flatten)]
+    pub config: ConfigOptions,
+
+    #[command(flatten)]
+    pub output: Output,
+
+    /// Follow symbolic links to directories instead of ignoring them.
+    #[arg(short = 'L', long)]
+    pub follow_links: bool,
+
+    /// Name crates after the packages in their Cargo.toml manifests when using
+    /// --output-folder, instead of after the directory containing `src`
+    #[arg(long)]
+    pub cargo_manifests: bool,
+
+    /// Cache parsed files in this directory so unchanged files are not parsed
+    /// again, target/typeshare if no directory is given
+    #[arg(long = "cache", value_name = "DIR", num_args = 0..=1, default_missing_value = "target/typeshare")]
+    pub cache_dir: Option<PathBuf>,
+
+    /// Check that the generated files are up to date without writing or removing
+    /// anything, failing if they are not
+    #[arg(long)]
+    pub check: bool,
+
+    /// Parse a single snippet of rust code read from standard input instead of
+    /// searching directories
+    #[arg(long, conflicts_with_all = ["directories", "folder"])]
+    pub stdin: bool,
+
+    /// Directories within which to recursively find and process rust files
+    #[arg(required_unless_present = "stdin", num_args = 1..)]
+    pub directories: Vec<PathBuf>,
+}
+
+/// The configuration file and the options that override its values.
+#[derive(clap::Args, Debug, Clone)]
+pub struct ConfigOptions {
     /// Prefix for generated Swift types
     #[arg(short, long)]
     pub swift_prefix: Option<String>,
@@ -37,7 +86,7 @@ pub struct Args {
     #[arg(short, long)]
     pub kotlin_prefix: Option<String>,
 
-    /// JAVA package name
+    /// Java package name of the generated Kotlin and Java types
     #[arg(short, long)]
     pub java_package: Option<String>,
 
@@ -62,36 +111,66 @@ pub struct Args {
     #[arg(short, long)]
     pub config_file: Option<PathBuf>,
 
-    #[command(flatten)]
-    pub output: Output,
-
-    /// Follow symbolic links to directories instead of ignoring them.
-    #[arg(short = 'L', long)]
-    pub follow_links: bool,
-
-    /// Directories within which to recursively find and process rust files
-    #[arg(required=true, num_args = 1..)]
-    pub directories: Vec<PathBuf>,
-
     /// Optional restrict to target_os
     #[arg(short, long, num_args = 1..)]
     pub target_os: Option<Vec<String>>,
 }
 
-#[derive(Debug, Clone, Copy, clap::Subcommand)]
+#[derive(Debug, Clone, clap::Subcommand)]
 pub enum Command {
     /// Generate shell completions
     Completions {
         /// The shell to generate the completions for
         shell: clap_complete::Shell,
     },
+    /// Classify the changes between two versions of the typeshared types
+    Compat {
+        /// The old version, either a directory or a git tree such as `origin/main:src`
+        old: String,
+
+        /// The new version, either a directory or a git tree such as `HEAD:src`
+        new: String,
+
+        /// The compatibility every change must provide
+        #[arg(long, value_enum, default_value_t = CompatRequirement::Full)]
+        require: CompatRequirement,
+    },
+    /// Inspect the configuration file
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommand,
+    },
+}
+
+#[derive(Debug, Clone, clap::Subcommand)]
+#[allow(clippy::large_enum_variant)]
+pub enum ConfigCommand {
+    /// Print the JSON Schema of `typeshare.toml`
+    Schema,
+    /// Print the configuration typeshare uses, after applying the command line
+    /// options to the configuration file
+    Show {
+        #[command(flatten)]
+        config: ConfigOptions,
+    },
+}
+
+/// The compatibility a `compat` run requires of every change.
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum CompatRequirement {
+    /// Old and new readers must accept data written by either version
+    Full,
+    /// New readers must accept data written by the old version
+    Backward,
+    /// Old readers must accept data written by the new version
+    Forward,
 }
 
 #[derive(clap::Args, Debug)]
 #[group(multiple = false, required = true)]
 pub struct Output {
-    /// File to write output to. mtime will be preserved if the file contents
-    /// don't change
+    /// File to write output to, or `-` for standard output. mtime will be
+    /// preserved if the file contents don't change
     #[arg(short = 'o', long = "output-file")]
     pub file: Option<PathBuf>,
 
@@ -0,0 +1,177 @@
+//! Schema compatibility checks between two versions of the typeshared types.
+use anyhow::{bail, ensure, Context};
+use log::{error, info};
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+};
+use typeshare_core::{
+    compat::{compare, Compatibility},
+    context::{ParseContext, ParseFileContext},
+    generator::walker_builder,
+    language::SINGLE_FILE_CRATE_NAME,
+    parser::{parse, ParsedData},
+};
+
+use crate::args::CompatRequirement;
+
+/// Print every change between the `old` and `new` versions and fail if any of
+/// them does not meet the required compatibility.
+pub fn check_compatibility(old: &str, new: &str, require: CompatRequirement) -> anyhow::Result<()> {
+    let old = load_version(old).with_context(|| format!("Failed to load version {old}"))?;
+    let new = load_version(new).with_context(|| format!("Failed to load version {new}"))?;
+
+    let required = match require {
+        CompatRequirement::Full => Compatibility::Full,
+        CompatRequirement::Backward => Compatibility::Backward,
+        CompatRequirement::Forward => Compatibility::Forward,
+    };
+
+    let changes = compare(&old, &new);
+    if changes.is_empty() {
+        info!("No changes found");
+    }
+
+    let mut failures = 0;
+    for change in &changes {
+        println!("{change}");
+        if !change.compatibility.satisfies(required) {
+            failures += 1;
+        }
+    }
+
+    ensure!(failures == 0, "{failures} change(s) are not {required}");
+    Ok(())
+}
+
+/// Parse every typeshared type of a version, given as a directory or as a git
+/// tree in `<tree-ish>:<path>` form.
+fn load_version(version: &str) -> anyhow::Result<ParsedData> {
+    let sources = if Path::new(version).is_dir() {
+        directory_sources(Path::new(version))?
+    } else if let Some((tree, path)) = version.split_once(':') {
+        git_sources(tree, path)?
+    } else {
+        bail!("`{version}` is neither a directory nor a git tree such as `HEAD:src`");
+    };
+
+    let parse_context = ParseContext::default();
+    let mut parsed_data = ParsedData::default();
+    for (file_path, source_code) in sources {
+        let parse_file_context = ParseFileContext {
+            source_code,
+            crate_name: SINGLE_FILE_CRATE_NAME,
+            file_name: String::new(),
+            file_path: file_path.clone(),
+        };
+        if let Some(data) = parse(&parse_context, parse_file_context)
+            .with_context(|| format!("Parsing failed: {file_path:?}"))?
+        {
+            parsed_data += data;
+        }
+    }
+
+    for error in &parsed_data.errors {
+        error!(
+            "Parsing error: \"{}\" in file \"{}\"",
+            error.error, error.file_name
+        );
+    }
+    ensure!(
+        parsed_data.errors.is_empty(),
+        "Errors encountered during parsing."
+    );
+
+    Ok(parsed_data)
+}
+
+/// Read all rust sources below a directory.
+fn directory_sources(directory: &Path) -> anyhow::Result<Vec<(PathBuf, String)>> {
+    let mut sources = Vec::new();
+    for entry in walker_builder(&[directory.to_path_buf()], false)?.build() {
+        let entry = entry.context("Failed traversing")?;
+        if entry
+            .file_type()
+            .is_some_and(|file_type| file_type.is_file())
+        {
+            let source = std::fs::read_to_string(entry.path())
+                .with_context(|| format!("Failed to read input: {:?}", entry.path()))?;
+            sources.push((entry.into_path(), source));
+        }
+    }
+    Ok(sources)
+}
+
+/// Read all rust sources below `path` in a git tree, without checking it out.
+fn git_sources(tree: &str, path: &str) -> anyhow::Result<Vec<(PathBuf, String)>> {
+    let mut ls_tree = Command::new("git");
+    ls_tree.args(["ls-tree", "-r", "-z", "--name-only", "--full-tree", tree]);
+    if !path.is_empty() {
+        ls_tree.args(["--", path]);
+    }
+    let listing = ls_tree.output().context("Failed to run git")?;
+    ensure!(
+        listing.status.success(),
+        "git ls-tree failed: {}",
+        String::from_utf8_lossy(&listing.stderr).trim()
+    );
+
+    let files = String::from_utf8(listing.stdout)
+        .context("git returned a non UTF-8 path")?
+        .split('\0')
+        .filter(|file| file.ends_with(".rs") && !file.contains("tools/typeshare/"))
+        .map(ToOwned::to_owned)
+        .collect::<Vec<_>>();
+
+    // Fetch every blob through a single `git cat-file` process.
+    let mut cat_file = Command::new("git")
+        .args(["cat-file", "--batch"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("Failed to run git")?;
+    let mut stdin = cat_file.stdin.take().expect("stdin is piped");
+    let requests = files
+        .iter()
+        .map(|file| format!("{tree}:{file}\n"))
+        .collect::<String>();
+    let writer = thread::spawn(move || stdin.write_all(requests.as_bytes()));
+    let output = cat_file.wait_with_output().context("Failed to run git")?;
+    writer
+        .join()
+        .expect("writer thread panicked")
+        .context("Failed to write to git")?;
+    ensure!(
+        output.status.success(),
+        "git cat-file failed: {}",
+        String::from_utf8_lossy(&output.stderr).trim()
+    );
+
+    // Each object is a `<oid> blob <size>` header line followed by its content and a newline.
+    let mut rest = output.stdout.as_slice();
+    files
+        .into_iter()
+        .map(|file| {
+            let header_end = rest
+                .iter()
+                .position(|byte| *byte == b'\n')
+                .context("Unexpected end of git output")?;
+            let header = String::from_utf8_lossy(&rest[..header_end]).into_owned();
+            let size = match header.split(' ').collect::<Vec<_>>().as_slice() {
+                [_, "blob", size] => size.parse::<usize>().ok(),
+                _ => None,
+            }
+            .with_context(|| format!("Unexpected git output for {file}: {header}"))?;
+            let content = rest
+                .get(header_end + 1..header_end + 1 + size)
+                .context("Unexpected end of git output")?;
+            let source = String::from_utf8(content.to_vec())
+                .with_context(|| format!("Failed to read input: {file}"))?;
+            rest = rest.get(header_end + size + 2..).unwrap_or_default();
+            Ok((PathBuf::from(file), source))
+        })
+        .collect()
+}
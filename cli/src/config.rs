@@ -1,84 +1,511 @@
 use anyhow::Context;
+use schemars::{schema::RootSchema, JsonSchema};
 use serde::{Deserialize, Serialize};
 use std::{
-    borrow::Cow,
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     env,
     fs::{self, OpenOptions},
     io::{self, Write},
     path::{Path, PathBuf},
 };
+use typeshare_core::{
+    filter::FilterConfig,
+    generator::FileTemplates,
+    language::{
+        JsonConverter, MappedType, OpenApiFormat, SupportedLanguage, UnitEnumStyle, ZodSchemas,
+    },
+    FieldNaming, TypeOrdering,
+};
 
 const DEFAULT_CONFIG_FILE_NAME: &str = "typeshare.toml";
-
-#[derive(Default, Serialize, Deserialize, PartialEq, Eq, Debug)]
-#[serde(default)]
-#[cfg(feature = "python")]
+/// The prefix of the environment variables that override configuration values.
+const ENV_PREFIX: &str = "TYPESHARE_";
+/// The configuration sections environment variables can override values of.
+const ENV_SECTIONS: &[&str] = &[
+    "swift",
+    "typescript",
+    "kotlin",
+    "java",
+    "scala",
+    "python",
+    "go",
+    "csharp",
+    "dart",
+    "jsonschema",
+    "openapi",
+    "protobuf",
+    "graphql",
+    "filter",
+];
+
+/// Python options.
+#[derive(Default, Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
 pub struct PythonParams {
-    pub type_mappings: HashMap<String, String>,
+    /// Rust types to replace with the given types.
+    pub type_mappings: HashMap<String, MappedType>,
+    /// The order to write the types of each file in.
+    pub type_ordering: TypeOrdering,
+    /// The path of each crate's file in the output folder, like `{crate}/index.ts`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_layout: Option<String>,
+    /// Written at the top of each file instead of the version header.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<String>,
+    /// Written at the end of each file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<String>,
+    /// Files, crates and types to generate, replacing the lists of the top level filter.
+    #[serde(skip_serializing_if = "FilterConfig::is_empty")]
+    pub filter: FilterConfig,
 }
 
-#[derive(Default, Serialize, Deserialize, Debug, PartialEq, Eq)]
-#[serde(default)]
+/// Kotlin options.
+#[derive(Default, Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
 pub struct KotlinParams {
+    /// The package of the generated files.
     pub package: String,
+    /// The name of the serializer module.
     pub module_name: String,
+    /// The prefix to add to generated type names.
     pub prefix: String,
-    pub type_mappings: HashMap<String, String>,
+    /// Rust types to replace with the given types.
+    pub type_mappings: HashMap<String, MappedType>,
+    /// How to name properties.
+    pub field_naming: FieldNaming,
+    /// The order to write the types of each file in.
+    pub type_ordering: TypeOrdering,
+    /// The path of each crate's file in the output folder, like `{crate}/index.ts`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_layout: Option<String>,
+    /// Written at the top of each file instead of the version header.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<String>,
+    /// Written at the end of each file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<String>,
+    /// Files, crates and types to generate, replacing the lists of the top level filter.
+    #[serde(skip_serializing_if = "FilterConfig::is_empty")]
+    pub filter: FilterConfig,
 }
 
-#[derive(Default, Serialize, Deserialize, Debug, PartialEq, Eq)]
-#[serde(default)]
+/// Java options.
+#[derive(Default, Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct JavaParams {
+    /// The package of the generated files.
+    pub package: String,
+    /// Rust types to replace with the given types.
+    pub type_mappings: HashMap<String, MappedType>,
+    /// The order to write the types of each file in.
+    pub type_ordering: TypeOrdering,
+    /// The path of each crate's file in the output folder, like `{crate}/index.ts`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_layout: Option<String>,
+    /// Written at the top of each file instead of the version header.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<String>,
+    /// Written at the end of each file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<String>,
+    /// Files, crates and types to generate, replacing the lists of the top level filter.
+    #[serde(skip_serializing_if = "FilterConfig::is_empty")]
+    pub filter: FilterConfig,
+}
+
+/// Scala options.
+#[derive(Default, Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
 pub struct ScalaParams {
+    /// The package of the generated files.
     pub package: String,
+    /// The name of the serializer module.
     pub module_name: String,
-    pub type_mappings: HashMap<String, String>,
+    /// Rust types to replace with the given types.
+    pub type_mappings: HashMap<String, MappedType>,
+    /// The order to write the types of each file in.
+    pub type_ordering: TypeOrdering,
+    /// The path of each crate's file in the output folder, like `{crate}/index.ts`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_layout: Option<String>,
+    /// Written at the top of each file instead of the version header.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<String>,
+    /// Written at the end of each file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<String>,
+    /// Files, crates and types to generate, replacing the lists of the top level filter.
+    #[serde(skip_serializing_if = "FilterConfig::is_empty")]
+    pub filter: FilterConfig,
 }
 
-#[derive(Default, Serialize, Deserialize, Debug, PartialEq, Eq)]
-#[serde(default)]
+/// Swift options.
+#[derive(Default, Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
 pub struct SwiftParams {
+    /// The prefix to add to generated type names.
     pub prefix: String,
+    /// Protocols every generated type conforms to.
     pub default_decorators: Vec<String>,
+    /// Constraints for every generic parameter.
     pub default_generic_constraints: Vec<String>,
     /// The constraints to apply to `CodableVoid`.
     pub codablevoid_constraints: Vec<String>,
-    pub type_mappings: HashMap<String, String>,
+    /// Rust types to replace with the given types.
+    pub type_mappings: HashMap<String, MappedType>,
+    /// How to name properties.
+    pub field_naming: FieldNaming,
+    /// The order to write the types of each file in.
+    pub type_ordering: TypeOrdering,
+    /// The path of each crate's file in the output folder, like `{crate}/index.ts`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_layout: Option<String>,
+    /// Written at the top of each file instead of the version header.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<String>,
+    /// Written at the end of each file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<String>,
+    /// Files, crates and types to generate, replacing the lists of the top level filter.
+    #[serde(skip_serializing_if = "FilterConfig::is_empty")]
+    pub filter: FilterConfig,
 }
 
-#[derive(Default, Serialize, Deserialize, PartialEq, Eq, Debug)]
-#[serde(default)]
+/// TypeScript options.
+#[derive(Default, Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
 pub struct TypeScriptParams {
-    pub type_mappings: HashMap<String, String>,
+    /// Rust types to replace with the given types.
+    pub type_mappings: HashMap<String, MappedType>,
+    /// Whether to write Zod schemas, `off`, `alongside` the types or `instead` of interfaces.
+    pub zod: ZodSchemas,
+    /// The Zod schemas of mapped types, like `z.coerce.date()`, with `$0`, `$1`… for the
+    /// schemas of generic parameters. Other mapped types are `z.unknown()`, unless they
+    /// map to `string`, `number` or `boolean`.
+    pub zod_type_mappings: HashMap<String, String>,
+    /// Whether to write type guards, constructors and a `match` function for algebraic enums.
+    pub helpers: bool,
+    /// How to write unit enums, as an `enum`, a `const_enum`, a `union` of string
+    /// literals, or a union and a `const_object` of the values.
+    pub unit_enums: UnitEnumStyle,
+    /// The order to write the types of each file in.
+    pub type_ordering: TypeOrdering,
+    /// The path of each crate's file in the output folder, like `{crate}/index.ts`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_layout: Option<String>,
+    /// Written at the top of each file instead of the version header.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<String>,
+    /// Written at the end of each file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<String>,
+    /// Files, crates and types to generate, replacing the lists of the top level filter.
+    #[serde(skip_serializing_if = "FilterConfig::is_empty")]
+    pub filter: FilterConfig,
 }
 
-#[derive(Default, Serialize, Deserialize, PartialEq, Eq, Debug)]
-#[serde(default)]
-#[cfg(feature = "go")]
+/// Go options.
+#[derive(Default, Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
 pub struct GoParams {
+    /// The package of the generated files.
     pub package: String,
+    /// Acronyms to write in uppercase in type and field names.
     pub uppercase_acronyms: Vec<String>,
+    /// Write optional slices as slices instead of pointers to slices.
     pub no_pointer_slice: bool,
-    pub type_mappings: HashMap<String, String>,
+    /// Rust types to replace with the given types.
+    pub type_mappings: HashMap<String, MappedType>,
+    /// The order to write the types of each file in.
+    pub type_ordering: TypeOrdering,
+    /// The path of each crate's file in the output folder, like `{crate}/index.ts`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_layout: Option<String>,
+    /// Written at the top of each file instead of the version header.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<String>,
+    /// Written at the end of each file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<String>,
+    /// Files, crates and types to generate, replacing the lists of the top level filter.
+    #[serde(skip_serializing_if = "FilterConfig::is_empty")]
+    pub filter: FilterConfig,
+}
+
+/// C# options.
+#[derive(Default, Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct CSharpParams {
+    /// The namespace of the generated types.
+    pub namespace: String,
+    /// Rust types to replace with the given types.
+    pub type_mappings: HashMap<String, MappedType>,
+    /// The order to write the types of each file in.
+    pub type_ordering: TypeOrdering,
+    /// The path of each crate's file in the output folder, like `{crate}/index.ts`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_layout: Option<String>,
+    /// Written at the top of each file instead of the version header.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<String>,
+    /// Written at the end of each file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<String>,
+    /// Files, crates and types to generate, replacing the lists of the top level filter.
+    #[serde(skip_serializing_if = "FilterConfig::is_empty")]
+    pub filter: FilterConfig,
+}
+
+/// Dart options.
+#[derive(Default, Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct DartParams {
+    /// The `package:` path of the output folder, like `my_app/models`, used to
+    /// import the files of other crates.
+    pub package: String,
+    /// Rust types to replace with the given types.
+    pub type_mappings: HashMap<String, MappedType>,
+    /// The functions converting mapped types from and to JSON, by Rust type name.
+    pub json_converters: HashMap<String, JsonConverter>,
+    /// The order to write the types of each file in.
+    pub type_ordering: TypeOrdering,
+    /// The path of each crate's file in the output folder, like `{crate}/index.ts`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_layout: Option<String>,
+    /// Written at the top of each file instead of the version header.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<String>,
+    /// Written at the end of each file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<String>,
+    /// Files, crates and types to generate, replacing the lists of the top level filter.
+    #[serde(skip_serializing_if = "FilterConfig::is_empty")]
+    pub filter: FilterConfig,
+}
+
+/// JSON Schema options.
+#[derive(Default, Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct JsonSchemaParams {
+    /// Rust types to replace with the given schemas, either as JSON or as the name of a JSON type.
+    pub type_mappings: HashMap<String, MappedType>,
+    /// The order to write the types of each file in.
+    pub type_ordering: TypeOrdering,
+    /// The path of each crate's file in the output folder, like `{crate}/index.ts`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_layout: Option<String>,
+    /// Files, crates and types to generate, replacing the lists of the top level filter.
+    #[serde(skip_serializing_if = "FilterConfig::is_empty")]
+    pub filter: FilterConfig,
+}
+
+/// OpenAPI options.
+#[derive(Default, Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct OpenApiParams {
+    /// The format to write documents in, `yaml` or `json`.
+    pub format: OpenApiFormat,
+    /// The `info.title` of new documents. Defaults to the name of the crate.
+    pub title: String,
+    /// The `info.version` of new documents. Defaults to `1.0.0`.
+    pub version: String,
+    /// An existing document to add the schemas to, like a specification with hand
+    /// written paths, relative to the working directory. Its comments are not kept.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spec: Option<PathBuf>,
+    /// Rust types to replace with the given schemas, either as JSON or as the name of a JSON type.
+    pub type_mappings: HashMap<String, MappedType>,
+    /// The order to write the types of each file in.
+    pub type_ordering: TypeOrdering,
+    /// The path of each crate's file in the output folder, like `{crate}/index.ts`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_layout: Option<String>,
+    /// Written at the top of each YAML document instead of the version header. JSON
+    /// documents can't have a header.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<String>,
+    /// Written at the end of each YAML document.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<String>,
+    /// Files, crates and types to generate, replacing the lists of the top level filter.
+    #[serde(skip_serializing_if = "FilterConfig::is_empty")]
+    pub filter: FilterConfig,
+}
+
+/// Protocol Buffers options.
+#[derive(Default, Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProtobufParams {
+    /// The package of the generated files, like `acme.models`.
+    pub package: String,
+    /// The file to keep the numbers of fields in, relative to the working directory.
+    /// Without one, numbers are assigned in the order of the fields on every run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lock_file: Option<PathBuf>,
+    /// Rust types to replace with the given types.
+    pub type_mappings: HashMap<String, MappedType>,
+    /// The order to write the types of each file in.
+    pub type_ordering: TypeOrdering,
+    /// The path of each crate's file in the output folder, like `{crate}/index.ts`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_layout: Option<String>,
+    /// Written at the top of each file instead of the version header.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<String>,
+    /// Written at the end of each file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<String>,
+    /// Files, crates and types to generate, replacing the lists of the top level filter.
+    #[serde(skip_serializing_if = "FilterConfig::is_empty")]
+    pub filter: FilterConfig,
+}
+
+/// GraphQL options.
+#[derive(Default, Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct GraphQlParams {
+    /// Rust types to replace with the given types. Types that aren't built in are
+    /// declared as custom scalars, with the `import` of a mapping as the URL of the
+    /// scalar's `@specifiedBy` directive.
+    pub type_mappings: HashMap<String, MappedType>,
+    /// The order to write the types of each file in.
+    pub type_ordering: TypeOrdering,
+    /// The path of each crate's file in the output folder, like `{crate}/index.ts`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_layout: Option<String>,
+    /// Written at the top of each file instead of the version header.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<String>,
+    /// Written at the end of each file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<String>,
+    /// Files, crates and types to generate, replacing the lists of the top level filter.
+    #[serde(skip_serializing_if = "FilterConfig::is_empty")]
+    pub filter: FilterConfig,
 }
 
 /// The parameters that are used to configure the behaviour of typeshare
 /// from the configuration file `typeshare.toml`
-#[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
-#[serde(default)]
+#[derive(Serialize, Deserialize, JsonSchema, Default, Debug, PartialEq)]
+#[serde(default, deny_unknown_fields)]
 pub(crate) struct Config {
+    /// The text of the `{license}` placeholder in headers and footers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license: Option<String>,
     pub swift: SwiftParams,
     pub typescript: TypeScriptParams,
     pub kotlin: KotlinParams,
+    pub java: JavaParams,
     pub scala: ScalaParams,
-    #[cfg(feature = "python")]
     pub python: PythonParams,
-    #[cfg(feature = "go")]
     pub go: GoParams,
+    pub csharp: CSharpParams,
+    pub dart: DartParams,
+    pub jsonschema: JsonSchemaParams,
+    pub openapi: OpenApiParams,
+    pub protobuf: ProtobufParams,
+    pub graphql: GraphQlParams,
+    /// Files, crates and types to generate, for every language.
+    #[serde(skip_serializing_if = "FilterConfig::is_empty")]
+    pub filter: FilterConfig,
+    /// Another configuration file to take the values this file doesn't set from,
+    /// relative to this file.
+    #[serde(skip_serializing)]
+    pub extends: Option<PathBuf>,
+    /// Don't take values from the configuration files of parent directories.
+    #[serde(skip_serializing)]
+    pub root: bool,
     #[serde(skip)]
     pub target_os: Vec<String>,
 }
 
+impl Config {
+    /// The filter for a language, with its own filter lists replacing the global ones.
+    pub fn filter(&self, language_type: SupportedLanguage) -> FilterConfig {
+        let overrides = match language_type {
+            SupportedLanguage::Swift => &self.swift.filter,
+            SupportedLanguage::TypeScript => &self.typescript.filter,
+            SupportedLanguage::Kotlin => &self.kotlin.filter,
+            SupportedLanguage::Java => &self.java.filter,
+            SupportedLanguage::Scala => &self.scala.filter,
+            SupportedLanguage::Python => &self.python.filter,
+            SupportedLanguage::Go => &self.go.filter,
+            SupportedLanguage::CSharp => &self.csharp.filter,
+            SupportedLanguage::Dart => &self.dart.filter,
+            SupportedLanguage::JsonSchema => &self.jsonschema.filter,
+            SupportedLanguage::OpenApi => &self.openapi.filter,
+            SupportedLanguage::Protobuf => &self.protobuf.filter,
+            SupportedLanguage::GraphQl => &self.graphql.filter,
+        };
+        self.filter.with_overrides(overrides)
+    }
+
+    /// The file layout of a language, if one is configured.
+    pub fn file_layout(&self, language_type: SupportedLanguage) -> Option<&str> {
+        match language_type {
+            SupportedLanguage::Swift => &self.swift.file_layout,
+            SupportedLanguage::TypeScript => &self.typescript.file_layout,
+            SupportedLanguage::Kotlin => &self.kotlin.file_layout,
+            SupportedLanguage::Java => &self.java.file_layout,
+            SupportedLanguage::Scala => &self.scala.file_layout,
+            SupportedLanguage::Python => &self.python.file_layout,
+            SupportedLanguage::Go => &self.go.file_layout,
+            SupportedLanguage::CSharp => &self.csharp.file_layout,
+            SupportedLanguage::Dart => &self.dart.file_layout,
+            SupportedLanguage::JsonSchema => &self.jsonschema.file_layout,
+            SupportedLanguage::OpenApi => &self.openapi.file_layout,
+            SupportedLanguage::Protobuf => &self.protobuf.file_layout,
+            SupportedLanguage::GraphQl => &self.graphql.file_layout,
+        }
+        .as_deref()
+    }
+
+    /// The order to write the types of a language's files in.
+    pub fn type_ordering(&self, language_type: SupportedLanguage) -> TypeOrdering {
+        match language_type {
+            SupportedLanguage::Swift => self.swift.type_ordering,
+            SupportedLanguage::TypeScript => self.typescript.type_ordering,
+            SupportedLanguage::Kotlin => self.kotlin.type_ordering,
+            SupportedLanguage::Java => self.java.type_ordering,
+            SupportedLanguage::Scala => self.scala.type_ordering,
+            SupportedLanguage::Python => self.python.type_ordering,
+            SupportedLanguage::Go => self.go.type_ordering,
+            SupportedLanguage::CSharp => self.csharp.type_ordering,
+            SupportedLanguage::Dart => self.dart.type_ordering,
+            SupportedLanguage::JsonSchema => self.jsonschema.type_ordering,
+            SupportedLanguage::OpenApi => self.openapi.type_ordering,
+            SupportedLanguage::Protobuf => self.protobuf.type_ordering,
+            SupportedLanguage::GraphQl => self.graphql.type_ordering,
+        }
+    }
+
+    /// The header and footer of a language's files.
+    pub fn file_templates(&self, language_type: SupportedLanguage) -> FileTemplates {
+        let (header, footer) = match language_type {
+            SupportedLanguage::Swift => (&self.swift.header, &self.swift.footer),
+            SupportedLanguage::TypeScript => (&self.typescript.header, &self.typescript.footer),
+            SupportedLanguage::Kotlin => (&self.kotlin.header, &self.kotlin.footer),
+            SupportedLanguage::Java => (&self.java.header, &self.java.footer),
+            SupportedLanguage::Scala => (&self.scala.header, &self.scala.footer),
+            SupportedLanguage::Python => (&self.python.header, &self.python.footer),
+            SupportedLanguage::Go => (&self.go.header, &self.go.footer),
+            SupportedLanguage::CSharp => (&self.csharp.header, &self.csharp.footer),
+            SupportedLanguage::Dart => (&self.dart.header, &self.dart.footer),
+            // JSON has no comments to write templates as.
+            SupportedLanguage::JsonSchema => (&None, &None),
+            SupportedLanguage::OpenApi => (&self.openapi.header, &self.openapi.footer),
+            SupportedLanguage::Protobuf => (&self.protobuf.header, &self.protobuf.footer),
+            SupportedLanguage::GraphQl => (&self.graphql.header, &self.graphql.footer),
+        };
+        FileTemplates {
+            header: header.clone(),
+            footer: footer.clone(),
+            license: self.license.clone().unwrap_or_default(),
+        }
+    }
+}
+
 pub(crate) fn store_config(config: &Config, file_path: Option<&Path>) -> anyhow::Result<()> {
     let file_path = file_path.unwrap_or(Path::new(DEFAULT_CONFIG_FILE_NAME));
     let config_output = toml::to_string_pretty(config).context("Failed to serialize to toml")?;
@@ -94,37 +521,194 @@ pub(crate) fn store_config(config: &Config, file_path: Option<&Path>) -> anyhow:
     Ok(())
 }
 
-pub(crate) fn load_config(file_path: Option<&Path>) -> Result<Config, io::Error> {
-    let file_path = file_path
-        .map(Cow::Borrowed)
-        .or_else(|| find_configuration_file().map(Cow::Owned));
+/// Load the configuration, merging in order:
+///
+/// 1. The configuration file given, or else every `typeshare.toml` from the
+///    outermost directory to `current_dir`, stopping at a file with `root = true`.
+///    A file that `extends` another is merged over the file it extends.
+/// 2. The `TYPESHARE_<SECTION>_<KEY>` environment variables in `vars`.
+///
+/// Tables are merged key by key, any other value replaces the earlier one.
+pub(crate) fn load_config(
+    file_path: Option<&Path>,
+    current_dir: &Path,
+    vars: impl IntoIterator<Item = (String, String)>,
+) -> Result<Config, io::Error> {
+    let layers = match file_path {
+        Some(file_path) => vec![read_config_file(
+            &current_dir.join(file_path),
+            &mut Vec::new(),
+        )?],
+        None => find_configuration_files(current_dir)?,
+    };
+
+    let mut config = toml::Table::new();
+    for layer in layers.into_iter().chain([environment_layer(vars)?]) {
+        merge(&mut config, layer);
+    }
+    let config: Config = toml::Value::Table(config)
+        .try_into()
+        .map_err(|err| io::Error::other(explain_error(err)))?;
+    if config.openapi.format == OpenApiFormat::Json
+        && (config.openapi.header.is_some() || config.openapi.footer.is_some())
+    {
+        return Err(io::Error::other(
+            "`openapi.header` and `openapi.footer` are written as comments, which JSON \
+             documents can't hold; remove them or use the `yaml` format",
+        ));
+    }
+    Ok(config)
+}
+
+/// The environment variables that can override configuration values.
+pub(crate) fn environment() -> impl Iterator<Item = (String, String)> {
+    env::vars_os().filter_map(|(name, value)| {
+        let name = name.into_string().ok()?;
+        name.starts_with(ENV_PREFIX)
+            .then(|| Some((name, value.into_string().ok()?)))
+            .flatten()
+    })
+}
+
+/// Read a configuration file and the files it extends.
+///
+/// `extending` holds the files that extend this one, to catch cycles.
+fn read_config_file(path: &Path, extending: &mut Vec<PathBuf>) -> io::Result<toml::Table> {
+    let describe = |err: &dyn std::fmt::Display| format!("{}: {err}", path.display());
+
+    let config_string =
+        fs::read_to_string(path).map_err(|err| io::Error::new(err.kind(), describe(&err)))?;
+    // Check the file on its own, so errors point to where they are in the file.
+    toml::from_str::<Config>(&config_string)
+        .map_err(|err| io::Error::other(describe(&explain_error(err))))?;
+    let mut table: toml::Table = toml::from_str(&config_string).map_err(io::Error::other)?;
+
+    let Some(toml::Value::String(base)) = table.remove("extends") else {
+        return Ok(table);
+    };
+    let canonical_path = fs::canonicalize(path)?;
+    if extending.contains(&canonical_path) {
+        return Err(io::Error::other(describe(&"the file extends itself")));
+    }
+
+    extending.push(canonical_path);
+    let base_path = path.parent().unwrap_or(Path::new("")).join(base);
+    let mut config = read_config_file(&base_path, extending)?;
+    extending.pop();
+
+    merge(&mut config, table);
+    Ok(config)
+}
+
+/// Read the configuration files of `dir` and its ancestors, outermost first.
+fn find_configuration_files(dir: &Path) -> io::Result<Vec<toml::Table>> {
+    let mut layers = Vec::new();
+    for dir in dir.ancestors() {
+        let path = dir.join(DEFAULT_CONFIG_FILE_NAME);
+        if !path.is_file() {
+            continue;
+        }
 
-    if let Some(file_path) = file_path {
-        let config_string = fs::read_to_string(file_path)?;
-        toml::from_str(&config_string).map_err(|e| io::Error::new(io::ErrorKind::Other, e))
-    } else {
-        Ok(Config::default())
+        let layer = read_config_file(&path, &mut Vec::new())?;
+        let root = matches!(layer.get("root"), Some(toml::Value::Boolean(true)));
+        layers.push(layer);
+        if root {
+            break;
+        }
     }
+    layers.reverse();
+
+    Ok(layers)
 }
 
-/// Search each ancestor directory for configuration file
-fn find_configuration_file() -> Option<PathBuf> {
-    let mut path = env::current_dir().ok()?;
-    let file = Path::new(DEFAULT_CONFIG_FILE_NAME);
+/// Turn the `TYPESHARE_<SECTION>_<KEY>` environment variables into configuration
+/// values, like `TYPESHARE_SWIFT_PREFIX` into `prefix` in the `[swift]` section.
+///
+/// Values of keys that hold strings are taken as is, and other values are parsed
+/// as TOML, like `true` or `["Sendable"]`.
+fn environment_layer(vars: impl IntoIterator<Item = (String, String)>) -> io::Result<toml::Table> {
+    let defaults = toml::Table::try_from(Config::default()).map_err(io::Error::other)?;
+    let mut layer = toml::Table::new();
+
+    for (name, value) in vars.into_iter().collect::<BTreeMap<_, _>>() {
+        let Some(variable) = name.strip_prefix(ENV_PREFIX) else {
+            continue;
+        };
+        let variable = variable.to_ascii_lowercase();
+        let Some((section, key)) = variable
+            .split_once('_')
+            .filter(|(section, _)| ENV_SECTIONS.contains(section))
+        else {
+            continue;
+        };
+
+        let value = match defaults.get(section).and_then(|section| section.get(key)) {
+            Some(toml::Value::String(_)) => toml::Value::String(value),
+            _ => toml::from_str::<toml::Table>(&format!("value = {value}"))
+                .ok()
+                .and_then(|mut table| table.remove("value"))
+                .unwrap_or(toml::Value::String(value)),
+        };
+        if let toml::Value::Table(section) = layer
+            .entry(section)
+            .or_insert_with(|| toml::Value::Table(toml::Table::new()))
+        {
+            section.insert(key.to_owned(), value);
+        }
+    }
+
+    toml::Value::Table(layer.clone())
+        .try_into::<Config>()
+        .map_err(|err| {
+            io::Error::other(format!("environment variables: {}", explain_error(err)))
+        })?;
 
-    // TODO: I want to use `path.ancestors` here but it would requiring
-    // allocating on every loop iteration and that makes me sad.
-    loop {
-        path.push(file);
+    Ok(layer)
+}
 
-        if path.is_file() {
-            break Some(path);
-        } else if !(path.pop() && path.pop()) {
-            break None;
+/// Merge `layer` into `config`, with the values of `layer` taking precedence.
+fn merge(config: &mut toml::Table, layer: toml::Table) {
+    for (key, value) in layer {
+        match (config.get_mut(&key), value) {
+            (Some(toml::Value::Table(config)), toml::Value::Table(layer)) => merge(config, layer),
+            (_, value) => {
+                config.insert(key, value);
+            }
         }
     }
 }
 
+/// The JSON Schema of the configuration file.
+pub(crate) fn config_schema() -> RootSchema {
+    schemars::schema_for!(Config)
+}
+
+/// Describe a configuration error, suggesting the key that was most likely
+/// meant when the error is about an unknown key.
+fn explain_error(err: toml::de::Error) -> String {
+    let description = err.to_string();
+    let description = description.trim_end();
+
+    // Serde describes unknown keys as "unknown field `key`, expected one of `a`, `b`".
+    let Some((unknown, expected)) = err
+        .message()
+        .strip_prefix("unknown field `")
+        .and_then(|rest| rest.split_once('`'))
+    else {
+        return description.to_owned();
+    };
+
+    expected
+        .split('`')
+        .skip(1)
+        .step_by(2)
+        .map(|key| (strsim::jaro_winkler(unknown, key), key))
+        .filter(|(similarity, _)| *similarity > 0.8)
+        .max_by(|(a, _), (b, _)| a.total_cmp(b))
+        .map(|(_, key)| format!("{description}\ndid you mean `{key}`?"))
+        .unwrap_or_else(|| description.to_owned())
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -139,7 +723,7 @@ mod test {
     #[test]
     fn to_string_and_back() {
         let path = config_file_path("mappings_config.toml");
-        let config = load_config(Some(&path)).unwrap();
+        let config = load_config(Some(&path), Path::new(CURRENT_DIR), []).unwrap();
 
         toml::from_str::<Config>(&toml::to_string_pretty(&config).unwrap()).unwrap();
     }
@@ -147,7 +731,7 @@ mod test {
     #[test]
     fn default_test() {
         let path = config_file_path("default_config.toml");
-        let config = load_config(Some(&path)).unwrap();
+        let config = load_config(Some(&path), Path::new(CURRENT_DIR), []).unwrap();
 
         assert_eq!(config, Config::default());
     }
@@ -155,7 +739,7 @@ mod test {
     #[test]
     fn empty_test() {
         let path = config_file_path("empty_config.toml");
-        let config = load_config(Some(&path)).unwrap();
+        let config = load_config(Some(&path), Path::new(CURRENT_DIR), []).unwrap();
 
         assert_eq!(config, Config::default());
     }
@@ -163,25 +747,37 @@ mod test {
     #[test]
     fn mappings_test() {
         let path = config_file_path("mappings_config.toml");
-        let config = load_config(Some(&path)).unwrap();
+        let config = load_config(Some(&path), Path::new(CURRENT_DIR), []).unwrap();
+
+        assert_eq!(config.swift.type_mappings["DateTime"].ty(), "Date");
+        assert_eq!(config.kotlin.type_mappings["DateTime"].ty(), "String");
+        assert_eq!(config.scala.type_mappings["DateTime"].ty(), "String");
+        assert_eq!(config.typescript.type_mappings["DateTime"].ty(), "string");
+        assert_eq!(
+            config.typescript.zod_type_mappings["DateTime"],
+            "z.string().datetime()"
+        );
+        assert_eq!(config.python.type_mappings["Url"].ty(), "AnyUrl");
+        assert_eq!(config.python.type_mappings["DateTime"].ty(), "datetime");
+        assert_eq!(config.go.type_mappings["DateTime"].ty(), "string");
+    }
 
-        assert_eq!(config.swift.type_mappings["DateTime"], "Date");
-        assert_eq!(config.kotlin.type_mappings["DateTime"], "String");
-        assert_eq!(config.scala.type_mappings["DateTime"], "String");
-        assert_eq!(config.typescript.type_mappings["DateTime"], "string");
-        #[cfg(feature = "python")]
-        {
-            assert_eq!(config.python.type_mappings["Url"], "AnyUrl");
-            assert_eq!(config.python.type_mappings["DateTime"], "datetime");
-        }
-        #[cfg(feature = "go")]
-        assert_eq!(config.go.type_mappings["DateTime"], "string");
+    #[test]
+    fn mapping_imports_test() {
+        let path = config_file_path("mapping_imports_config.toml");
+        let config = load_config(Some(&path), Path::new(CURRENT_DIR), []).unwrap();
+
+        let (types, imports) = MappedType::split(&config.kotlin.type_mappings);
+        assert_eq!(types["DateTime"].as_str(), "Instant");
+        assert_eq!(types["Url"].as_str(), "String");
+        assert_eq!(imports["DateTime"], "kotlinx.datetime.Instant");
+        assert!(!imports.contains_key("Url"));
     }
 
     #[test]
     fn decorators_test() {
         let path = config_file_path("decorators_config.toml");
-        let config = load_config(Some(&path)).unwrap();
+        let config = load_config(Some(&path), Path::new(CURRENT_DIR), []).unwrap();
 
         assert_eq!(config.swift.default_decorators.len(), 1);
         assert_eq!(config.swift.default_decorators[0], "Sendable");
@@ -190,7 +786,7 @@ mod test {
     #[test]
     fn constraints_test() {
         let path = config_file_path("constraints_config.toml");
-        let config = load_config(Some(&path)).unwrap();
+        let config = load_config(Some(&path), Path::new(CURRENT_DIR), []).unwrap();
 
         assert_eq!(config.swift.default_generic_constraints.len(), 1);
         assert_eq!(config.swift.default_generic_constraints[0], "Sendable");
@@ -199,15 +795,204 @@ mod test {
     #[test]
     fn swift_prefix_test() {
         let path = config_file_path("swift_prefix_config.toml");
-        let config = load_config(Some(&path)).unwrap();
+        let config = load_config(Some(&path), Path::new(CURRENT_DIR), []).unwrap();
 
         assert_eq!(config.swift.prefix, "test");
     }
     #[test]
-    #[cfg(feature = "go")]
+    fn filter_test() {
+        let path = config_file_path("filter_config.toml");
+        let config = load_config(Some(&path), Path::new(CURRENT_DIR), []).unwrap();
+
+        let typescript = config.filter(SupportedLanguage::TypeScript);
+        assert_eq!(typescript.include_types, Some(vec!["Api*".to_owned()]));
+        assert_eq!(typescript.exclude_types, Some(vec!["ApiDebug".to_owned()]));
+        assert_eq!(
+            typescript.exclude_paths,
+            Some(vec!["**/internal/**".to_owned()])
+        );
+
+        let swift = config.filter(SupportedLanguage::Swift);
+        assert_eq!(swift.include_types, Some(vec!["Api*".to_owned()]));
+        assert_eq!(swift.exclude_types, None);
+    }
+
+    #[test]
+    fn unknown_key_test() {
+        let path = config_file_path("unknown_key_config.toml");
+        let err = load_config(Some(&path), Path::new(CURRENT_DIR), [])
+            .unwrap_err()
+            .to_string();
+
+        assert!(err.contains("unknown field `type_mapping`"), "{err}");
+        assert!(err.contains("did you mean `type_mappings`?"), "{err}");
+    }
+
+    #[test]
+    fn unknown_key_without_suggestion_test() {
+        let err = toml::from_str::<Config>("[swift]\nzzz = 1\n").unwrap_err();
+
+        assert!(!explain_error(err).contains("did you mean"));
+    }
+
+    #[test]
+    fn layered_test() {
+        let config = load_config(None, &config_file_path("layered/member"), []).unwrap();
+
+        assert_eq!(config.swift.prefix, "Workspace");
+        assert_eq!(config.swift.default_decorators, vec!["Sendable".to_owned()]);
+        assert_eq!(config.swift.type_mappings["DateTime"].ty(), "Date");
+        assert_eq!(config.swift.type_mappings["Url"].ty(), "URL");
+        assert_eq!(config.kotlin.package, "com.example");
+        assert_eq!(config.kotlin.prefix, "Member");
+    }
+
+    #[test]
+    fn root_test() {
+        let path = config_file_path("layered/member/isolated");
+        let config = load_config(None, &path, []).unwrap();
+
+        assert_eq!(config.kotlin.prefix, "Isolated");
+        assert_eq!(config.kotlin.package, "");
+        assert_eq!(config.swift, SwiftParams::default());
+    }
+
+    #[test]
+    fn extends_cycle_test() {
+        let path = config_file_path("layered/cycle/a.toml");
+        let err = load_config(Some(&path), Path::new(CURRENT_DIR), []).unwrap_err();
+
+        assert!(err.to_string().contains("extends itself"), "{err}");
+    }
+
+    #[test]
+    fn environment_test() {
+        let vars = [
+            ("TYPESHARE_SWIFT_PREFIX", "Env"),
+            ("TYPESHARE_GO_NO_POINTER_SLICE", "true"),
+            ("TYPESHARE_FILTER_EXCLUDE_TYPES", r#"["Debug*"]"#),
+            ("TYPESHARE_UNRELATED", "ignored"),
+        ]
+        .map(|(name, value)| (name.to_owned(), value.to_owned()));
+        let config = load_config(None, &config_file_path("layered/member"), vars).unwrap();
+
+        assert_eq!(config.swift.prefix, "Env");
+        assert_eq!(config.swift.type_mappings["Url"].ty(), "URL");
+        assert_eq!(config.kotlin.prefix, "Member");
+        assert!(config.go.no_pointer_slice);
+        assert_eq!(config.filter.exclude_types, Some(vec!["Debug*".to_owned()]));
+    }
+
+    #[test]
+    fn environment_unknown_key_test() {
+        let vars = [("TYPESHARE_SWIFT_PREFX".to_owned(), "Env".to_owned())];
+        let err = load_config(None, &config_file_path("layered/member"), vars).unwrap_err();
+
+        assert!(err.to_string().contains("did you mean `prefix`?"), "{err}");
+    }
+
+    #[test]
+    fn schema_test() {
+        let schema = serde_json::to_value(config_schema()).unwrap();
+
+        assert!(schema["properties"]["swift"].is_object());
+        assert!(schema["definitions"]["SwiftParams"]["properties"]["type_mappings"].is_object());
+        assert_eq!(
+            schema["definitions"]["SwiftParams"]["additionalProperties"],
+            false
+        );
+    }
+
+    #[test]
+    fn field_naming_test() {
+        let path = config_file_path("field_naming_config.toml");
+        let config = load_config(Some(&path), Path::new(CURRENT_DIR), []).unwrap();
+
+        assert_eq!(config.swift.field_naming, FieldNaming::CamelCase);
+        assert_eq!(config.kotlin.field_naming, FieldNaming::CamelCase);
+    }
+
+    #[test]
+    fn file_layout_test() {
+        let path = config_file_path("file_layout_config.toml");
+        let config = load_config(Some(&path), Path::new(CURRENT_DIR), []).unwrap();
+
+        assert_eq!(
+            config.file_layout(SupportedLanguage::TypeScript),
+            Some("{crate}/index.ts")
+        );
+        assert_eq!(
+            config.file_layout(SupportedLanguage::Python),
+            Some("{crate_snake}/models.py")
+        );
+        assert_eq!(config.file_layout(SupportedLanguage::Swift), None);
+    }
+
+    #[test]
+    fn file_templates_test() {
+        let path = config_file_path("templates_config.toml");
+        let config = load_config(Some(&path), Path::new(CURRENT_DIR), []).unwrap();
+
+        let typescript = config.file_templates(SupportedLanguage::TypeScript);
+        assert_eq!(
+            typescript.header.as_deref(),
+            Some("/* eslint-disable */\n// Generated by typeshare {version} from {crates}. {license}\n")
+        );
+        assert_eq!(typescript.footer.as_deref(), Some("// {hash}"));
+        assert_eq!(typescript.license, "SPDX-License-Identifier: MIT");
+
+        let swift = config.file_templates(SupportedLanguage::Swift);
+        assert_eq!(swift.header, None);
+        assert_eq!(swift.license, "SPDX-License-Identifier: MIT");
+    }
+
+    #[test]
+    fn json_templates_test() {
+        let err = toml::from_str::<Config>("[jsonschema]\nheader = \"// x\"\n").unwrap_err();
+        assert!(err.to_string().contains("unknown field `header`"), "{err}");
+
+        let path = config_file_path("openapi_json_templates_config.toml");
+        let err = load_config(Some(&path), Path::new(CURRENT_DIR), []).unwrap_err();
+        assert!(err.to_string().contains("`yaml` format"), "{err}");
+
+        let vars = [("TYPESHARE_OPENAPI_FORMAT".to_owned(), "yaml".to_owned())];
+        let config = load_config(Some(&path), Path::new(CURRENT_DIR), vars).unwrap();
+        assert_eq!(
+            config
+                .file_templates(SupportedLanguage::OpenApi)
+                .header
+                .as_deref(),
+            Some("# {hash}")
+        );
+    }
+
+    #[test]
+    fn type_ordering_test() {
+        let path = config_file_path("type_ordering_config.toml");
+        let config = load_config(Some(&path), Path::new(CURRENT_DIR), []).unwrap();
+
+        assert_eq!(
+            config.type_ordering(SupportedLanguage::TypeScript),
+            TypeOrdering::Alphabetical
+        );
+        assert_eq!(
+            config.type_ordering(SupportedLanguage::Python),
+            TypeOrdering::Source
+        );
+        assert_eq!(
+            config.type_ordering(SupportedLanguage::Kotlin),
+            TypeOrdering::Dependencies
+        );
+        assert_eq!(
+            config.type_ordering(SupportedLanguage::Swift),
+            TypeOrdering::Legacy
+        );
+    }
+
+    #[test]
     fn go_package_test() {
         let path = config_file_path("go_config.toml");
-        let config = load_config(Some(&path)).unwrap();
+        let config = load_config(Some(&path), Path::new(CURRENT_DIR), []).unwrap();
 
         assert_eq!(config.go.package, "testPackage");
     }
@@ -3,40 +3,33 @@
 //!
 
 mod args;
+mod compat;
 mod config;
-mod parse;
-mod writer;
 
-use std::{
-    collections::{BTreeMap, HashMap},
-    io,
-    path::Path,
-};
+use std::{env, io, path::Path};
 
-use anyhow::{anyhow, Context};
+use anyhow::Context;
 use clap::{CommandFactory, Parser};
 use clap_complete::aot::generate;
 use flexi_logger::AdaptiveFormat;
-use ignore::{overrides::OverrideBuilder, types::TypesBuilder, WalkBuilder};
 use log::{error, info};
-use parse::parallel_parse;
 use typeshare_core::language::GenericConstraints;
 #[cfg(feature = "go")]
 use typeshare_core::language::Go;
 #[cfg(feature = "python")]
 use typeshare_core::language::Python;
 use typeshare_core::{
-    context::ParseContext,
-    language::{CrateName, Kotlin, Language, Scala, SupportedLanguage, Swift, TypeScript},
-    parser::ParsedData,
-    reconcile::reconcile_aliases,
+    filter::Filter,
+    generator::{FileLayout, Generator, Output},
+    language::{
+        CSharp, Dart, GraphQl, Java, JsonSchema, Kotlin, Language, MappedType, OpenApi, Protobuf,
+        Scala, SupportedLanguage, Swift, TypeScript,
+    },
 };
 
 use crate::{
-    args::{Args, Command},
+    args::{Args, Command, ConfigCommand, ConfigOptions},
     config::Config,
-    parse::all_types,
-    writer::{write_generated, Output},
 };
 
 fn main() -> anyhow::Result<()> {
@@ -54,6 +47,16 @@ fn main() -> anyhow::Result<()> {
                 let bin_name = cmd.get_name().to_string();
                 generate(shell, &mut cmd, bin_name, &mut io::stdout());
             }
+            Command::Compat { old, new, require } => {
+                return compat::check_compatibility(&old, &new, require).inspect_err(|err| {
+                    error!("typeshare compatibility check failed: {err}");
+                });
+            }
+            Command::Config { command } => {
+                return show_config(command).inspect_err(|err| {
+                    error!("typeshare failed to show the configuration: {err}");
+                });
+            }
         }
 
         return Ok(());
@@ -61,11 +64,12 @@ fn main() -> anyhow::Result<()> {
 
     // Note that this can be `None`; the relevant functions handle this case
     // on their own.
-    let config_file = options.config_file.as_deref();
+    let config_file = options.config.config_file.as_deref();
 
     if options.output.generate_config {
-        override_configuration(Config::default(), &options)
-            .and_then(|config| config::store_config(&config, config_file))
+        let config = override_configuration(Config::default(), &options.config);
+        check_configuration(&config, &options)
+            .and_then(|()| config::store_config(&config, config_file))
             .inspect_err(|err| error!("typeshare failed to create new config file: {err}"))
     } else {
         generate_types(config_file, &options).inspect_err(|err| {
@@ -77,17 +81,24 @@ fn main() -> anyhow::Result<()> {
 fn generate_types(config_file: Option<&Path>, options: &Args) -> anyhow::Result<()> {
     info!("typeshare started generating types");
 
-    let config = config::load_config(config_file).context("Unable to read configuration file")?;
-    let config = override_configuration(config, options)?;
+    let config = config::load_config(config_file, &env::current_dir()?, config::environment())
+        .context("Unable to read configuration file")?;
+    let config = override_configuration(config, &options.config);
+    check_configuration(&config, options)?;
 
     let directories = options.directories.as_slice();
 
-    info!("Using directories: {directories:?}");
+    if options.stdin {
+        info!("Using standard input");
+    } else {
+        info!("Using directories: {directories:?}");
+    }
 
     let language_type = match options.language {
         None => panic!("no language specified; `clap` should have guaranteed its presence"),
         Some(language) => match language {
             args::AvailableLanguage::Kotlin => SupportedLanguage::Kotlin,
+            args::AvailableLanguage::Java => SupportedLanguage::Java,
             args::AvailableLanguage::Scala => SupportedLanguage::Scala,
             args::AvailableLanguage::Swift => SupportedLanguage::Swift,
             args::AvailableLanguage::Typescript => SupportedLanguage::TypeScript,
@@ -95,13 +106,23 @@ fn generate_types(config_file: Option<&Path>, options: &Args) -> anyhow::Result<
             args::AvailableLanguage::Go => SupportedLanguage::Go,
             #[cfg(feature = "python")]
             args::AvailableLanguage::Python => SupportedLanguage::Python,
+            args::AvailableLanguage::CSharp => SupportedLanguage::CSharp,
+            args::AvailableLanguage::Dart => SupportedLanguage::Dart,
+            args::AvailableLanguage::JsonSchema => SupportedLanguage::JsonSchema,
+            args::AvailableLanguage::OpenApi => SupportedLanguage::OpenApi,
+            args::AvailableLanguage::Protobuf => SupportedLanguage::Protobuf,
+            args::AvailableLanguage::GraphQl => SupportedLanguage::GraphQl,
         },
     };
 
     let destination = if let Some(ref file) = options.output.file {
-        Output::File(file)
+        if file == Path::new("-") {
+            Output::Stdout
+        } else {
+            Output::File(file.clone())
+        }
     } else if let Some(ref folder) = options.output.folder {
-        Output::Folder(folder)
+        Output::Folder(folder.clone())
     } else {
         panic!(
             "Got neither a file nor a folder to output to; this indicates a
@@ -109,135 +130,253 @@ fn generate_types(config_file: Option<&Path>, options: &Args) -> anyhow::Result<
         )
     };
 
+    anyhow::ensure!(
+        !(options.check && destination == Output::Stdout),
+        "--check needs an output file or folder to compare against"
+    );
+
     let multi_file = matches!(destination, Output::Folder(_));
     let target_os = config.target_os.clone();
-    let mut lang = language(language_type, config, multi_file);
-
-    let parse_context = ParseContext {
-        ignored_types: lang.ignored_reference_types(),
-        multi_file,
-        target_os,
-    };
-
-    let mut parsed_data = parallel_parse(
-        &parse_context,
-        walker_builder(directories, options)?,
-        language_type,
-    )?;
-
-    reconcile_aliases(&mut parsed_data);
-
-    // Collect all the types into a map of the file name they
-    // belong too and the list of type names. Used for generating
-    // imports in generated files.
-    let import_candidates = if multi_file {
-        all_types(&mut parsed_data)
-    } else {
-        HashMap::new()
-    };
-
-    check_parse_errors(&parsed_data)?;
-
-    info!("typeshare started writing generated types");
-
-    write_generated(destination, lang.as_mut(), parsed_data, import_candidates)?;
+    let filter = Filter::new(&config.filter(language_type)).context("Invalid filter")?;
+    let file_layout = config
+        .file_layout(language_type)
+        .map(FileLayout::new)
+        .transpose()
+        .context("Invalid file layout")?;
+    let file_templates = config.file_templates(language_type);
+    let type_ordering = config.type_ordering(language_type);
+    let lang = language(language_type, config, multi_file);
+
+    let mut generator = Generator::new()
+        .lang(language_type, lang)
+        .inputs(directories)
+        .output(destination)
+        .target_os(target_os)
+        .follow_links(options.follow_links)
+        .cargo_manifests(options.cargo_manifests)
+        .filter(filter)
+        .file_templates(file_templates)
+        .type_ordering(type_ordering)
+        .check(options.check);
+    if let Some(cache_dir) = options.cache_dir.as_ref() {
+        generator = generator.cache_dir(cache_dir);
+    }
+    if let Some(file_layout) = file_layout {
+        generator = generator.file_layout(file_layout);
+    }
+    if options.stdin {
+        let source_code =
+            io::read_to_string(io::stdin()).context("Unable to read standard input")?;
+        generator = generator.source(source_code);
+    }
+    generator.run()?;
 
     info!("typeshare finished generating types");
     Ok(())
 }
 
-fn walker_builder(
-    directories: &[std::path::PathBuf],
-    options: &Args,
-) -> anyhow::Result<WalkBuilder> {
-    let mut types = TypesBuilder::new();
-    types
-        .add("rust", "*.rs")
-        .context("Failed to add rust type extensions")?;
-    types.select("rust");
-
-    let first_root = directories
-        .first()
-        .expect("directories is empty; this shouldn't be possible");
-    let overrides = OverrideBuilder::new(first_root)
-        // Don't process files inside of tools/typeshare/
-        .add("!**/tools/typeshare/**")
-        .context("Failed to parse override")?
-        .build()
-        .context("Failed to build override")?;
-    let mut walker_builder = WalkBuilder::new(first_root);
-    walker_builder
-        .sort_by_file_path(Path::cmp)
-        .types(types.build().context("Failed to build types")?)
-        .overrides(overrides)
-        .follow_links(options.follow_links);
-    for root in directories.iter().skip(1) {
-        walker_builder.add(root);
-    }
-    Ok(walker_builder)
-}
-
 /// Get the language trait impl for the given supported language and configuration.
 fn language(
     language_type: SupportedLanguage,
     config: Config,
     multi_file: bool,
 ) -> Box<dyn Language> {
+    // A configured header replaces the version header.
+    let no_version_header = config.file_templates(language_type).header.is_some();
     match language_type {
-        SupportedLanguage::Swift => Box::new(Swift {
-            prefix: config.swift.prefix,
-            type_mappings: config.swift.type_mappings,
-            default_decorators: config.swift.default_decorators,
-            default_generic_constraints: GenericConstraints::from_config(
-                config.swift.default_generic_constraints,
-            ),
-            multi_file,
-            codablevoid_constraints: config.swift.codablevoid_constraints,
-            ..Default::default()
-        }),
-        SupportedLanguage::Kotlin => Box::new(Kotlin {
-            package: config.kotlin.package,
-            module_name: config.kotlin.module_name,
-            prefix: config.kotlin.prefix,
-            type_mappings: config.kotlin.type_mappings,
-            ..Default::default()
-        }),
-        SupportedLanguage::Scala => Box::new(Scala {
-            package: config.scala.package,
-            module_name: config.scala.module_name,
-            type_mappings: config.scala.type_mappings,
-            ..Default::default()
-        }),
-        SupportedLanguage::TypeScript => Box::new(TypeScript {
-            type_mappings: config.typescript.type_mappings,
-            ..Default::default()
-        }),
+        SupportedLanguage::Swift => {
+            let (type_mappings, type_mapping_imports) =
+                MappedType::split(&config.swift.type_mappings);
+            Box::new(Swift {
+                prefix: config.swift.prefix,
+                type_mappings,
+                type_mapping_imports,
+                default_decorators: config.swift.default_decorators,
+                default_generic_constraints: GenericConstraints::from_config(
+                    config.swift.default_generic_constraints,
+                ),
+                multi_file,
+                codablevoid_constraints: config.swift.codablevoid_constraints,
+                field_naming: config.swift.field_naming,
+                no_version_header,
+                ..Default::default()
+            })
+        }
+        SupportedLanguage::Kotlin => {
+            let (type_mappings, type_mapping_imports) =
+                MappedType::split(&config.kotlin.type_mappings);
+            Box::new(Kotlin {
+                package: config.kotlin.package,
+                module_name: config.kotlin.module_name,
+                prefix: config.kotlin.prefix,
+                type_mappings,
+                type_mapping_imports,
+                field_naming: config.kotlin.field_naming,
+                no_version_header,
+            })
+        }
+        SupportedLanguage::Java => {
+            let (type_mappings, type_mapping_imports) =
+                MappedType::split(&config.java.type_mappings);
+            Box::new(Java {
+                package: config.java.package,
+                type_mappings,
+                type_mapping_imports,
+                no_version_header,
+                ..Default::default()
+            })
+        }
+        SupportedLanguage::Scala => {
+            let (type_mappings, type_mapping_imports) =
+                MappedType::split(&config.scala.type_mappings);
+            Box::new(Scala {
+                package: config.scala.package,
+                module_name: config.scala.module_name,
+                type_mappings,
+                type_mapping_imports,
+                no_version_header,
+            })
+        }
+        SupportedLanguage::TypeScript => {
+            let (type_mappings, type_mapping_imports) =
+                MappedType::split(&config.typescript.type_mappings);
+            Box::new(TypeScript {
+                type_mappings,
+                type_mapping_imports,
+                no_version_header,
+                zod: config.typescript.zod,
+                zod_type_mappings: config
+                    .typescript
+                    .zod_type_mappings
+                    .iter()
+                    .map(|(rust_type, schema)| (rust_type.clone(), schema.as_str().into()))
+                    .collect(),
+                helpers: config.typescript.helpers,
+                unit_enums: config.typescript.unit_enums,
+                ..Default::default()
+            })
+        }
         #[cfg(feature = "go")]
-        SupportedLanguage::Go => Box::new(Go {
-            package: config.go.package,
-            type_mappings: config.go.type_mappings,
-            uppercase_acronyms: config.go.uppercase_acronyms,
-            no_pointer_slice: config.go.no_pointer_slice,
-            ..Default::default()
-        }),
+        SupportedLanguage::Go => {
+            let (type_mappings, type_mapping_imports) = MappedType::split(&config.go.type_mappings);
+            Box::new(Go {
+                package: config.go.package,
+                type_mappings,
+                type_mapping_imports,
+                uppercase_acronyms: config.go.uppercase_acronyms,
+                no_pointer_slice: config.go.no_pointer_slice,
+                no_version_header,
+            })
+        }
         #[cfg(not(feature = "go"))]
         SupportedLanguage::Go => {
             panic!("go support is currently experimental and must be enabled as a feature flag for typeshare-cli")
         }
         #[cfg(feature = "python")]
-        SupportedLanguage::Python => Box::new(Python {
-            type_mappings: config.python.type_mappings,
-            ..Default::default()
-        }),
+        SupportedLanguage::Python => {
+            let (type_mappings, type_mapping_imports) =
+                MappedType::split(&config.python.type_mappings);
+            Box::new(Python {
+                type_mappings,
+                type_mapping_imports,
+                no_version_header,
+                ..Default::default()
+            })
+        }
         #[cfg(not(feature = "python"))]
         SupportedLanguage::Python => {
             panic!("python support is currently experimental and must be enabled as a feature flag for typeshare-cli")
         }
+        SupportedLanguage::CSharp => {
+            let (type_mappings, type_mapping_imports) =
+                MappedType::split(&config.csharp.type_mappings);
+            Box::new(CSharp {
+                namespace: config.csharp.namespace,
+                type_mappings,
+                type_mapping_imports,
+                no_version_header,
+                ..Default::default()
+            })
+        }
+        SupportedLanguage::Dart => {
+            let (type_mappings, type_mapping_imports) =
+                MappedType::split(&config.dart.type_mappings);
+            Box::new(Dart {
+                package: config.dart.package,
+                type_mappings,
+                type_mapping_imports,
+                json_converters: config.dart.json_converters,
+                no_version_header,
+                ..Default::default()
+            })
+        }
+        SupportedLanguage::JsonSchema => {
+            let (type_mappings, _) = MappedType::split(&config.jsonschema.type_mappings);
+            Box::new(JsonSchema {
+                type_mappings,
+                no_version_header,
+                ..Default::default()
+            })
+        }
+        SupportedLanguage::OpenApi => {
+            let (type_mappings, _) = MappedType::split(&config.openapi.type_mappings);
+            Box::new(OpenApi {
+                type_mappings,
+                no_version_header,
+                format: config.openapi.format,
+                title: config.openapi.title,
+                version: config.openapi.version,
+                spec: config.openapi.spec,
+                ..Default::default()
+            })
+        }
+        SupportedLanguage::Protobuf => {
+            let (type_mappings, type_mapping_imports) =
+                MappedType::split(&config.protobuf.type_mappings);
+            Box::new(Protobuf {
+                package: config.protobuf.package,
+                type_mappings,
+                type_mapping_imports,
+                no_version_header,
+                lock_file: config.protobuf.lock_file,
+                ..Default::default()
+            })
+        }
+        SupportedLanguage::GraphQl => {
+            let (type_mappings, scalar_specifications) =
+                MappedType::split(&config.graphql.type_mappings);
+            Box::new(GraphQl {
+                type_mappings,
+                scalar_specifications,
+                no_version_header,
+                ..Default::default()
+            })
+        }
     }
 }
 
+/// Print the configuration schema or the effective configuration.
+fn show_config(command: ConfigCommand) -> anyhow::Result<()> {
+    let output = match command {
+        ConfigCommand::Schema => serde_json::to_string_pretty(&config::config_schema())?,
+        ConfigCommand::Show { config: options } => {
+            let config = config::load_config(
+                options.config_file.as_deref(),
+                &env::current_dir()?,
+                config::environment(),
+            )
+            .context("Unable to read configuration file")?;
+            toml::to_string_pretty(&override_configuration(config, &options))
+                .context("Failed to serialize to toml")?
+        }
+    };
+    println!("{}", output.trim_end());
+
+    Ok(())
+}
+
 /// Overrides any configuration values with provided arguments
-fn override_configuration(mut config: Config, options: &Args) -> anyhow::Result<Config> {
+fn override_configuration(mut config: Config, options: &ConfigOptions) -> Config {
     if let Some(swift_prefix) = options.swift_prefix.as_ref() {
         config.swift.prefix = swift_prefix.clone();
     }
@@ -248,6 +387,7 @@ fn override_configuration(mut config: Config, options: &Args) -> anyhow::Result<
 
     if let Some(java_package) = options.java_package.as_ref() {
         config.kotlin.package = java_package.clone();
+        config.java.package = java_package.clone();
     }
 
     if let Some(module_name) = options.kotlin_module_name.as_ref() {
@@ -263,44 +403,26 @@ fn override_configuration(mut config: Config, options: &Args) -> anyhow::Result<
     }
 
     #[cfg(feature = "go")]
-    {
-        if let Some(go_package) = options.go_package.as_ref() {
-            config.go.package = go_package.to_string();
-        }
-
-        if matches!(options.language, Some(args::AvailableLanguage::Go)) {
-            anyhow::ensure!(
-                    !config.go.package.is_empty(),
-                   "Please provide a package name in the typeshare.toml or using --go-package <package name>"
-                );
-        }
+    if let Some(go_package) = options.go_package.as_ref() {
+        config.go.package = go_package.to_string();
     }
 
     config.target_os = options.target_os.as_deref().unwrap_or_default().to_vec();
 
-    Ok(config)
+    config
 }
 
-/// Prints out all parsing errors if any and returns Err.
-fn check_parse_errors(parsed_crates: &BTreeMap<CrateName, ParsedData>) -> anyhow::Result<()> {
-    let mut errors_encountered = false;
-    for data in parsed_crates
-        .values()
-        .filter(|parsed_data| !parsed_data.errors.is_empty())
-    {
-        errors_encountered = true;
-        for error in &data.errors {
-            error!(
-                "Parsing error: \"{}\" in file \"{}\"",
-                error.error, error.file_name
-            );
-        }
+/// Checks that the configuration has everything the language to generate needs
+fn check_configuration(config: &Config, options: &Args) -> anyhow::Result<()> {
+    #[cfg(feature = "go")]
+    if matches!(options.language, Some(args::AvailableLanguage::Go)) {
+        anyhow::ensure!(
+            !config.go.package.is_empty(),
+            "Please provide a package name in the typeshare.toml or using --go-package <package name>"
+        );
     }
+    #[cfg(not(feature = "go"))]
+    let _ = (config, options);
 
-    if errors_encountered {
-        error!("Errors encountered during parsing.");
-        Err(anyhow!("Errors encountered during parsing."))
-    } else {
-        Ok(())
-    }
+    Ok(())
 }
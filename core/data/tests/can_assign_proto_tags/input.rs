@@ -0,0 +1,34 @@
+/// A user of the service.
+#[typeshare]
+#[serde(rename_all = "camelCase")]
+pub struct User {
+    #[typeshare(proto_tag = 3)]
+    pub id: String,
+    pub display_name: String,
+    /// Added after `id` was numbered.
+    #[typeshare(proto_tag = 1)]
+    pub email: Option<String>,
+    pub roles: Vec<Role>,
+    pub settings: HashMap<String, String>,
+}
+
+#[typeshare]
+pub enum Role {
+    #[typeshare(proto_tag = 2)]
+    Admin,
+    Member,
+}
+
+#[typeshare]
+#[serde(tag = "type", content = "content")]
+pub enum Event {
+    #[typeshare(proto_tag = 5)]
+    Joined(User),
+    Left,
+    Renamed {
+        #[typeshare(proto_tag = 2)]
+        from: String,
+        to: String,
+    },
+    Tagged(Vec<String>),
+}
@@ -0,0 +1,66 @@
+#nullable enable
+
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace AgileBits.OnePassword;
+
+/// <summary>
+/// Struct comment
+/// </summary>
+public record ItemDetailsFieldValue;
+
+/// <summary>
+/// Enum comment
+/// </summary>
+[JsonPolymorphic(TypeDiscriminatorPropertyName = "type")]
+[JsonDerivedType(typeof(AdvancedColors.String), "String")]
+[JsonDerivedType(typeof(AdvancedColors.Number), "Number")]
+[JsonDerivedType(typeof(AdvancedColors.UnsignedNumber), "UnsignedNumber")]
+[JsonDerivedType(typeof(AdvancedColors.NumberArray), "NumberArray")]
+[JsonDerivedType(typeof(AdvancedColors.ReallyCoolType), "ReallyCoolType")]
+public abstract record AdvancedColors
+{
+	private AdvancedColors() {}
+
+	/// <summary>
+	/// This is a case comment
+	/// </summary>
+	public sealed record String([property: JsonPropertyName("content")] string Content) : AdvancedColors;
+
+	public sealed record Number([property: JsonPropertyName("content")] int Content) : AdvancedColors;
+
+	public sealed record UnsignedNumber([property: JsonPropertyName("content")] uint Content) : AdvancedColors;
+
+	public sealed record NumberArray([property: JsonPropertyName("content")] List<int> Content) : AdvancedColors;
+
+	/// <summary>
+	/// Comment on the last element
+	/// </summary>
+	public sealed record ReallyCoolType([property: JsonPropertyName("content")] ItemDetailsFieldValue Content) : AdvancedColors;
+}
+
+[JsonPolymorphic(TypeDiscriminatorPropertyName = "type")]
+[JsonDerivedType(typeof(AdvancedColors2.String), "string")]
+[JsonDerivedType(typeof(AdvancedColors2.Number), "number")]
+[JsonDerivedType(typeof(AdvancedColors2.NumberArray), "number-array")]
+[JsonDerivedType(typeof(AdvancedColors2.ReallyCoolType), "really-cool-type")]
+public abstract record AdvancedColors2
+{
+	private AdvancedColors2() {}
+
+	/// <summary>
+	/// This is a case comment
+	/// </summary>
+	public sealed record String([property: JsonPropertyName("content")] string Content) : AdvancedColors2;
+
+	public sealed record Number([property: JsonPropertyName("content")] int Content) : AdvancedColors2;
+
+	public sealed record NumberArray([property: JsonPropertyName("content")] List<int> Content) : AdvancedColors2;
+
+	/// <summary>
+	/// Comment on the last element
+	/// </summary>
+	public sealed record ReallyCoolType([property: JsonPropertyName("content")] ItemDetailsFieldValue Content) : AdvancedColors2;
+}
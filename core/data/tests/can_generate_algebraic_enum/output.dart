@@ -0,0 +1,181 @@
+/// Struct comment
+class ItemDetailsFieldValue {
+  const ItemDetailsFieldValue();
+
+  factory ItemDetailsFieldValue.fromJson(Map<String, dynamic> json) =>
+      const ItemDetailsFieldValue();
+
+  Map<String, dynamic> toJson() => {};
+}
+
+/// Enum comment
+sealed class AdvancedColors {
+  const AdvancedColors();
+
+  factory AdvancedColors.fromJson(Map<String, dynamic> json) =>
+      switch (json['type']) {
+        'String' => AdvancedColorsString(json['content'] as String),
+        'Number' => AdvancedColorsNumber((json['content'] as num).toInt()),
+        'UnsignedNumber' => AdvancedColorsUnsignedNumber((json['content'] as num).toInt()),
+        'NumberArray' => AdvancedColorsNumberArray((json['content'] as List<dynamic>).map((v0) => (v0 as num).toInt()).toList()),
+        'ReallyCoolType' => AdvancedColorsReallyCoolType(ItemDetailsFieldValue.fromJson(json['content'])),
+        final tag => throw ArgumentError.value(tag, 'type', 'Unknown AdvancedColors variant'),
+      };
+
+  Map<String, dynamic> toJson();
+}
+
+/// This is a case comment
+class AdvancedColorsString extends AdvancedColors {
+  const AdvancedColorsString(this.content);
+
+  final String content;
+
+  AdvancedColorsString copyWith({String? content}) =>
+      AdvancedColorsString(content ?? this.content);
+
+  @override
+  Map<String, dynamic> toJson() => {
+        'type': 'String',
+        'content': content,
+      };
+}
+
+class AdvancedColorsNumber extends AdvancedColors {
+  const AdvancedColorsNumber(this.content);
+
+  final int content;
+
+  AdvancedColorsNumber copyWith({int? content}) =>
+      AdvancedColorsNumber(content ?? this.content);
+
+  @override
+  Map<String, dynamic> toJson() => {
+        'type': 'Number',
+        'content': content,
+      };
+}
+
+class AdvancedColorsUnsignedNumber extends AdvancedColors {
+  const AdvancedColorsUnsignedNumber(this.content);
+
+  final int content;
+
+  AdvancedColorsUnsignedNumber copyWith({int? content}) =>
+      AdvancedColorsUnsignedNumber(content ?? this.content);
+
+  @override
+  Map<String, dynamic> toJson() => {
+        'type': 'UnsignedNumber',
+        'content': content,
+      };
+}
+
+class AdvancedColorsNumberArray extends AdvancedColors {
+  const AdvancedColorsNumberArray(this.content);
+
+  final List<int> content;
+
+  AdvancedColorsNumberArray copyWith({List<int>? content}) =>
+      AdvancedColorsNumberArray(content ?? this.content);
+
+  @override
+  Map<String, dynamic> toJson() => {
+        'type': 'NumberArray',
+        'content': content,
+      };
+}
+
+/// Comment on the last element
+class AdvancedColorsReallyCoolType extends AdvancedColors {
+  const AdvancedColorsReallyCoolType(this.content);
+
+  final ItemDetailsFieldValue content;
+
+  AdvancedColorsReallyCoolType copyWith({ItemDetailsFieldValue? content}) =>
+      AdvancedColorsReallyCoolType(content ?? this.content);
+
+  @override
+  Map<String, dynamic> toJson() => {
+        'type': 'ReallyCoolType',
+        'content': content.toJson(),
+      };
+}
+
+sealed class AdvancedColors2 {
+  const AdvancedColors2();
+
+  factory AdvancedColors2.fromJson(Map<String, dynamic> json) =>
+      switch (json['type']) {
+        'string' => AdvancedColors2String(json['content'] as String),
+        'number' => AdvancedColors2Number((json['content'] as num).toInt()),
+        'number-array' => AdvancedColors2NumberArray((json['content'] as List<dynamic>).map((v0) => (v0 as num).toInt()).toList()),
+        'really-cool-type' => AdvancedColors2ReallyCoolType(ItemDetailsFieldValue.fromJson(json['content'])),
+        final tag => throw ArgumentError.value(tag, 'type', 'Unknown AdvancedColors2 variant'),
+      };
+
+  Map<String, dynamic> toJson();
+}
+
+/// This is a case comment
+class AdvancedColors2String extends AdvancedColors2 {
+  const AdvancedColors2String(this.content);
+
+  final String content;
+
+  AdvancedColors2String copyWith({String? content}) =>
+      AdvancedColors2String(content ?? this.content);
+
+  @override
+  Map<String, dynamic> toJson() => {
+        'type': 'string',
+        'content': content,
+      };
+}
+
+class AdvancedColors2Number extends AdvancedColors2 {
+  const AdvancedColors2Number(this.content);
+
+  final int content;
+
+  AdvancedColors2Number copyWith({int? content}) =>
+      AdvancedColors2Number(content ?? this.content);
+
+  @override
+  Map<String, dynamic> toJson() => {
+        'type': 'number',
+        'content': content,
+      };
+}
+
+class AdvancedColors2NumberArray extends AdvancedColors2 {
+  const AdvancedColors2NumberArray(this.content);
+
+  final List<int> content;
+
+  AdvancedColors2NumberArray copyWith({List<int>? content}) =>
+      AdvancedColors2NumberArray(content ?? this.content);
+
+  @override
+  Map<String, dynamic> toJson() => {
+        'type': 'number-array',
+        'content': content,
+      };
+}
+
+/// Comment on the last element
+class AdvancedColors2ReallyCoolType extends AdvancedColors2 {
+  const AdvancedColors2ReallyCoolType(this.content);
+
+  final ItemDetailsFieldValue content;
+
+  AdvancedColors2ReallyCoolType copyWith({ItemDetailsFieldValue? content}) =>
+      AdvancedColors2ReallyCoolType(content ?? this.content);
+
+  @override
+  Map<String, dynamic> toJson() => {
+        'type': 'really-cool-type',
+        'content': content.toJson(),
+      };
+}
+
@@ -0,0 +1,59 @@
+package com.agilebits.onepassword;
+
+import com.fasterxml.jackson.annotation.*;
+import java.util.List;
+import java.util.Map;
+
+public final class file_name {
+	private file_name() {}
+
+	/**
+	 * Struct comment
+	 */
+	public record ItemDetailsFieldValue() {}
+
+	/**
+	 * Enum comment
+	 */
+	@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "type")
+	@JsonSubTypes({
+		@JsonSubTypes.Type(value = AdvancedColors.String.class, name = "String"),
+		@JsonSubTypes.Type(value = AdvancedColors.Number.class, name = "Number"),
+		@JsonSubTypes.Type(value = AdvancedColors.UnsignedNumber.class, name = "UnsignedNumber"),
+		@JsonSubTypes.Type(value = AdvancedColors.NumberArray.class, name = "NumberArray"),
+		@JsonSubTypes.Type(value = AdvancedColors.ReallyCoolType.class, name = "ReallyCoolType"),
+	})
+	public sealed interface AdvancedColors {
+		/**
+		 * This is a case comment
+		 */
+		record String(@JsonProperty("content") java.lang.String content) implements AdvancedColors {}
+		record Number(@JsonProperty("content") Integer content) implements AdvancedColors {}
+		record UnsignedNumber(@JsonProperty("content") Long content) implements AdvancedColors {}
+		record NumberArray(@JsonProperty("content") List<Integer> content) implements AdvancedColors {}
+		/**
+		 * Comment on the last element
+		 */
+		record ReallyCoolType(@JsonProperty("content") ItemDetailsFieldValue content) implements AdvancedColors {}
+	}
+
+	@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "type")
+	@JsonSubTypes({
+		@JsonSubTypes.Type(value = AdvancedColors2.String.class, name = "string"),
+		@JsonSubTypes.Type(value = AdvancedColors2.Number.class, name = "number"),
+		@JsonSubTypes.Type(value = AdvancedColors2.NumberArray.class, name = "number-array"),
+		@JsonSubTypes.Type(value = AdvancedColors2.ReallyCoolType.class, name = "really-cool-type"),
+	})
+	public sealed interface AdvancedColors2 {
+		/**
+		 * This is a case comment
+		 */
+		record String(@JsonProperty("content") java.lang.String content) implements AdvancedColors2 {}
+		record Number(@JsonProperty("content") Integer content) implements AdvancedColors2 {}
+		record NumberArray(@JsonProperty("content") List<Integer> content) implements AdvancedColors2 {}
+		/**
+		 * Comment on the last element
+		 */
+		record ReallyCoolType(@JsonProperty("content") ItemDetailsFieldValue content) implements AdvancedColors2 {}
+	}
+}
@@ -0,0 +1,29 @@
+import { z } from "zod";
+
+/** Struct comment */
+export const ItemDetailsFieldValueSchema = z.object({
+});
+export type ItemDetailsFieldValue = z.infer<typeof ItemDetailsFieldValueSchema>;
+
+/** Enum comment */
+export const AdvancedColorsSchema = z.discriminatedUnion("type", [
+	/** This is a case comment */
+	z.object({ type: z.literal("String"), content: z.string() }),
+	z.object({ type: z.literal("Number"), content: z.number().int().min(-2147483648).max(2147483647) }),
+	z.object({ type: z.literal("UnsignedNumber"), content: z.number().int().min(0).max(4294967295) }),
+	z.object({ type: z.literal("NumberArray"), content: z.array(z.number().int().min(-2147483648).max(2147483647)) }),
+	/** Comment on the last element */
+	z.object({ type: z.literal("ReallyCoolType"), content: ItemDetailsFieldValueSchema }),
+]);
+export type AdvancedColors = z.infer<typeof AdvancedColorsSchema>;
+
+export const AdvancedColors2Schema = z.discriminatedUnion("type", [
+	/** This is a case comment */
+	z.object({ type: z.literal("string"), content: z.string() }),
+	z.object({ type: z.literal("number"), content: z.number().int().min(-2147483648).max(2147483647) }),
+	z.object({ type: z.literal("number-array"), content: z.array(z.number().int().min(-2147483648).max(2147483647)) }),
+	/** Comment on the last element */
+	z.object({ type: z.literal("really-cool-type"), content: ItemDetailsFieldValueSchema }),
+]);
+export type AdvancedColors2 = z.infer<typeof AdvancedColors2Schema>;
+
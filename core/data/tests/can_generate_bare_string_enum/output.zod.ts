@@ -0,0 +1,11 @@
+import { z } from "zod";
+
+/** This is a comment. */
+export enum Colors {
+	Red = "Red",
+	Blue = "Blue",
+	Green = "Green",
+}
+
+export const ColorsSchema = z.nativeEnum(Colors);
+
@@ -0,0 +1,7 @@
+import { z } from "zod";
+
+export const SomeStructSchema = z.object({
+	field_a: z.number().int().min(0).max(4294967295).nullish(),
+});
+export type SomeStruct = z.infer<typeof SomeStructSchema>;
+
@@ -0,0 +1,21 @@
+#nullable enable
+
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace AgileBits.OnePassword;
+
+public record AddressDetails;
+
+[JsonPolymorphic(TypeDiscriminatorPropertyName = "type")]
+[JsonDerivedType(typeof(Address.FixedAddress), "FixedAddress")]
+[JsonDerivedType(typeof(Address.NoFixedAddress), "NoFixedAddress")]
+public abstract record Address
+{
+	private Address() {}
+
+	public sealed record FixedAddress([property: JsonPropertyName("content")] AddressDetails Content) : Address;
+
+	public sealed record NoFixedAddress() : Address;
+}
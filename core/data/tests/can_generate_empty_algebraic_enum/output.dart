@@ -0,0 +1,46 @@
+class AddressDetails {
+  const AddressDetails();
+
+  factory AddressDetails.fromJson(Map<String, dynamic> json) =>
+      const AddressDetails();
+
+  Map<String, dynamic> toJson() => {};
+}
+
+sealed class Address {
+  const Address();
+
+  factory Address.fromJson(Map<String, dynamic> json) =>
+      switch (json['type']) {
+        'FixedAddress' => AddressFixedAddress(AddressDetails.fromJson(json['content'])),
+        'NoFixedAddress' => AddressNoFixedAddress(),
+        final tag => throw ArgumentError.value(tag, 'type', 'Unknown Address variant'),
+      };
+
+  Map<String, dynamic> toJson();
+}
+
+class AddressFixedAddress extends Address {
+  const AddressFixedAddress(this.content);
+
+  final AddressDetails content;
+
+  AddressFixedAddress copyWith({AddressDetails? content}) =>
+      AddressFixedAddress(content ?? this.content);
+
+  @override
+  Map<String, dynamic> toJson() => {
+        'type': 'FixedAddress',
+        'content': content.toJson(),
+      };
+}
+
+class AddressNoFixedAddress extends Address {
+  const AddressNoFixedAddress();
+
+  @override
+  Map<String, dynamic> toJson() => {
+        'type': 'NoFixedAddress',
+      };
+}
+
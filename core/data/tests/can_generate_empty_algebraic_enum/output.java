@@ -0,0 +1,21 @@
+package com.agilebits.onepassword;
+
+import com.fasterxml.jackson.annotation.*;
+import java.util.List;
+import java.util.Map;
+
+public final class file_name {
+	private file_name() {}
+
+	public record AddressDetails() {}
+
+	@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "type")
+	@JsonSubTypes({
+		@JsonSubTypes.Type(value = Address.FixedAddress.class, name = "FixedAddress"),
+		@JsonSubTypes.Type(value = Address.NoFixedAddress.class, name = "NoFixedAddress"),
+	})
+	public sealed interface Address {
+		record FixedAddress(@JsonProperty("content") AddressDetails content) implements Address {}
+		record NoFixedAddress() implements Address {}
+	}
+}
@@ -0,0 +1,12 @@
+import { z } from "zod";
+
+export const AddressDetailsSchema = z.object({
+});
+export type AddressDetails = z.infer<typeof AddressDetailsSchema>;
+
+export const AddressSchema = z.discriminatedUnion("type", [
+	z.object({ type: z.literal("FixedAddress"), content: AddressDetailsSchema }),
+	z.object({ type: z.literal("NoFixedAddress") }),
+]);
+export type Address = z.infer<typeof AddressSchema>;
+
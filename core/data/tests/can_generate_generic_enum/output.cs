@@ -0,0 +1,91 @@
+#nullable enable
+
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace AgileBits.OnePassword;
+
+// Register the derived types of each closed type with JsonPolymorphismOptions.
+public abstract record GenericEnum<A, B>
+{
+	private GenericEnum() {}
+
+	public sealed record VariantA([property: JsonPropertyName("content")] A Content) : GenericEnum<A, B>;
+
+	public sealed record VariantB([property: JsonPropertyName("content")] B Content) : GenericEnum<A, B>;
+}
+
+public record StructUsingGenericEnum
+{
+	[JsonPropertyName("enum_field")]
+	public required GenericEnum<string, short> EnumField { get; init; }
+}
+
+// Register the derived types of each closed type with JsonPolymorphismOptions.
+public abstract record GenericEnumUsingGenericEnum<T>
+{
+	private GenericEnumUsingGenericEnum() {}
+
+	public sealed record VariantC([property: JsonPropertyName("content")] GenericEnum<T, T> Content) : GenericEnumUsingGenericEnum<T>;
+
+	public sealed record VariantD([property: JsonPropertyName("content")] GenericEnum<string, Dictionary<string, T>> Content) : GenericEnumUsingGenericEnum<T>;
+
+	public sealed record VariantE([property: JsonPropertyName("content")] GenericEnum<string, uint> Content) : GenericEnumUsingGenericEnum<T>;
+}
+
+/// <summary>
+/// Generated type representing the anonymous struct variant `VariantF` of the `GenericEnumsUsingStructVariants` Rust enum
+/// </summary>
+public record GenericEnumsUsingStructVariantsVariantFInner<T>
+{
+	[JsonPropertyName("action")]
+	public required T Action { get; init; }
+}
+
+/// <summary>
+/// Generated type representing the anonymous struct variant `VariantG` of the `GenericEnumsUsingStructVariants` Rust enum
+/// </summary>
+public record GenericEnumsUsingStructVariantsVariantGInner<T, U>
+{
+	[JsonPropertyName("action")]
+	public required T Action { get; init; }
+
+	[JsonPropertyName("response")]
+	public required U Response { get; init; }
+}
+
+/// <summary>
+/// Generated type representing the anonymous struct variant `VariantH` of the `GenericEnumsUsingStructVariants` Rust enum
+/// </summary>
+public record GenericEnumsUsingStructVariantsVariantHInner
+{
+	[JsonPropertyName("non_generic")]
+	public required int NonGeneric { get; init; }
+}
+
+/// <summary>
+/// Generated type representing the anonymous struct variant `VariantI` of the `GenericEnumsUsingStructVariants` Rust enum
+/// </summary>
+public record GenericEnumsUsingStructVariantsVariantIInner<T, U>
+{
+	[JsonPropertyName("vec")]
+	public required List<T> Vec { get; init; }
+
+	[JsonPropertyName("action")]
+	public required MyType<T, U> Action { get; init; }
+}
+
+// Register the derived types of each closed type with JsonPolymorphismOptions.
+public abstract record GenericEnumsUsingStructVariants<T, U>
+{
+	private GenericEnumsUsingStructVariants() {}
+
+	public sealed record VariantF([property: JsonPropertyName("content")] GenericEnumsUsingStructVariantsVariantFInner<T> Content) : GenericEnumsUsingStructVariants<T, U>;
+
+	public sealed record VariantG([property: JsonPropertyName("content")] GenericEnumsUsingStructVariantsVariantGInner<T, U> Content) : GenericEnumsUsingStructVariants<T, U>;
+
+	public sealed record VariantH([property: JsonPropertyName("content")] GenericEnumsUsingStructVariantsVariantHInner Content) : GenericEnumsUsingStructVariants<T, U>;
+
+	public sealed record VariantI([property: JsonPropertyName("content")] GenericEnumsUsingStructVariantsVariantIInner<T, U> Content) : GenericEnumsUsingStructVariants<T, U>;
+}
@@ -0,0 +1,318 @@
+sealed class GenericEnum<A, B> {
+  const GenericEnum();
+
+  factory GenericEnum.fromJson(Map<String, dynamic> json, A Function(Object? json) fromJsonA, B Function(Object? json) fromJsonB) =>
+      switch (json['type']) {
+        'VariantA' => GenericEnumVariantA<A, B>(fromJsonA(json['content'])),
+        'VariantB' => GenericEnumVariantB<A, B>(fromJsonB(json['content'])),
+        final tag => throw ArgumentError.value(tag, 'type', 'Unknown GenericEnum variant'),
+      };
+
+  Map<String, dynamic> toJson(Object? Function(A value) toJsonA, Object? Function(B value) toJsonB);
+}
+
+class GenericEnumVariantA<A, B> extends GenericEnum<A, B> {
+  const GenericEnumVariantA(this.content);
+
+  final A content;
+
+  GenericEnumVariantA<A, B> copyWith({A? content}) =>
+      GenericEnumVariantA<A, B>(content ?? this.content);
+
+  @override
+  Map<String, dynamic> toJson(Object? Function(A value) toJsonA, Object? Function(B value) toJsonB) => {
+        'type': 'VariantA',
+        'content': toJsonA(content),
+      };
+}
+
+class GenericEnumVariantB<A, B> extends GenericEnum<A, B> {
+  const GenericEnumVariantB(this.content);
+
+  final B content;
+
+  GenericEnumVariantB<A, B> copyWith({B? content}) =>
+      GenericEnumVariantB<A, B>(content ?? this.content);
+
+  @override
+  Map<String, dynamic> toJson(Object? Function(A value) toJsonA, Object? Function(B value) toJsonB) => {
+        'type': 'VariantB',
+        'content': toJsonB(content),
+      };
+}
+
+class StructUsingGenericEnum {
+  const StructUsingGenericEnum({
+    required this.enumField,
+  });
+
+  factory StructUsingGenericEnum.fromJson(Map<String, dynamic> json) =>
+      StructUsingGenericEnum(
+        enumField: GenericEnum<String, int>.fromJson(json['enum_field'], (dynamic v0) => v0 as String, (dynamic v0) => (v0 as num).toInt()),
+      );
+
+  final GenericEnum<String, int> enumField;
+
+  Map<String, dynamic> toJson() => {
+        'enum_field': enumField.toJson((v0) => v0, (v0) => v0),
+      };
+
+  StructUsingGenericEnum copyWith({
+    GenericEnum<String, int>? enumField,
+  }) =>
+      StructUsingGenericEnum(
+        enumField: enumField ?? this.enumField,
+      );
+}
+
+sealed class GenericEnumUsingGenericEnum<T> {
+  const GenericEnumUsingGenericEnum();
+
+  factory GenericEnumUsingGenericEnum.fromJson(Map<String, dynamic> json, T Function(Object? json) fromJsonT) =>
+      switch (json['type']) {
+        'VariantC' => GenericEnumUsingGenericEnumVariantC<T>(GenericEnum<T, T>.fromJson(json['content'], (dynamic v0) => fromJsonT(v0), (dynamic v0) => fromJsonT(v0))),
+        'VariantD' => GenericEnumUsingGenericEnumVariantD<T>(GenericEnum<String, Map<String, T>>.fromJson(json['content'], (dynamic v0) => v0 as String, (dynamic v0) => (v0 as Map<String, dynamic>).map((k1, v1) => MapEntry(k1, fromJsonT(v1))))),
+        'VariantE' => GenericEnumUsingGenericEnumVariantE<T>(GenericEnum<String, int>.fromJson(json['content'], (dynamic v0) => v0 as String, (dynamic v0) => (v0 as num).toInt())),
+        final tag => throw ArgumentError.value(tag, 'type', 'Unknown GenericEnumUsingGenericEnum variant'),
+      };
+
+  Map<String, dynamic> toJson(Object? Function(T value) toJsonT);
+}
+
+class GenericEnumUsingGenericEnumVariantC<T> extends GenericEnumUsingGenericEnum<T> {
+  const GenericEnumUsingGenericEnumVariantC(this.content);
+
+  final GenericEnum<T, T> content;
+
+  GenericEnumUsingGenericEnumVariantC<T> copyWith({GenericEnum<T, T>? content}) =>
+      GenericEnumUsingGenericEnumVariantC<T>(content ?? this.content);
+
+  @override
+  Map<String, dynamic> toJson(Object? Function(T value) toJsonT) => {
+        'type': 'VariantC',
+        'content': content.toJson((v0) => toJsonT(v0), (v0) => toJsonT(v0)),
+      };
+}
+
+class GenericEnumUsingGenericEnumVariantD<T> extends GenericEnumUsingGenericEnum<T> {
+  const GenericEnumUsingGenericEnumVariantD(this.content);
+
+  final GenericEnum<String, Map<String, T>> content;
+
+  GenericEnumUsingGenericEnumVariantD<T> copyWith({GenericEnum<String, Map<String, T>>? content}) =>
+      GenericEnumUsingGenericEnumVariantD<T>(content ?? this.content);
+
+  @override
+  Map<String, dynamic> toJson(Object? Function(T value) toJsonT) => {
+        'type': 'VariantD',
+        'content': content.toJson((v0) => v0, (v0) => v0.map((k1, v1) => MapEntry(k1, toJsonT(v1)))),
+      };
+}
+
+class GenericEnumUsingGenericEnumVariantE<T> extends GenericEnumUsingGenericEnum<T> {
+  const GenericEnumUsingGenericEnumVariantE(this.content);
+
+  final GenericEnum<String, int> content;
+
+  GenericEnumUsingGenericEnumVariantE<T> copyWith({GenericEnum<String, int>? content}) =>
+      GenericEnumUsingGenericEnumVariantE<T>(content ?? this.content);
+
+  @override
+  Map<String, dynamic> toJson(Object? Function(T value) toJsonT) => {
+        'type': 'VariantE',
+        'content': content.toJson((v0) => v0, (v0) => v0),
+      };
+}
+
+/// Generated type representing the anonymous struct variant `VariantF` of the `GenericEnumsUsingStructVariants` Rust enum
+class GenericEnumsUsingStructVariantsVariantFInner<T> {
+  const GenericEnumsUsingStructVariantsVariantFInner({
+    required this.action,
+  });
+
+  factory GenericEnumsUsingStructVariantsVariantFInner.fromJson(Map<String, dynamic> json, T Function(Object? json) fromJsonT) =>
+      GenericEnumsUsingStructVariantsVariantFInner<T>(
+        action: fromJsonT(json['action']),
+      );
+
+  final T action;
+
+  Map<String, dynamic> toJson(Object? Function(T value) toJsonT) => {
+        'action': toJsonT(action),
+      };
+
+  GenericEnumsUsingStructVariantsVariantFInner<T> copyWith({
+    Object? action = _unset,
+  }) =>
+      GenericEnumsUsingStructVariantsVariantFInner<T>(
+        action: identical(action, _unset) ? this.action : action as T,
+      );
+}
+
+/// Generated type representing the anonymous struct variant `VariantG` of the `GenericEnumsUsingStructVariants` Rust enum
+class GenericEnumsUsingStructVariantsVariantGInner<T, U> {
+  const GenericEnumsUsingStructVariantsVariantGInner({
+    required this.action,
+    required this.response,
+  });
+
+  factory GenericEnumsUsingStructVariantsVariantGInner.fromJson(Map<String, dynamic> json, T Function(Object? json) fromJsonT, U Function(Object? json) fromJsonU) =>
+      GenericEnumsUsingStructVariantsVariantGInner<T, U>(
+        action: fromJsonT(json['action']),
+        response: fromJsonU(json['response']),
+      );
+
+  final T action;
+
+  final U response;
+
+  Map<String, dynamic> toJson(Object? Function(T value) toJsonT, Object? Function(U value) toJsonU) => {
+        'action': toJsonT(action),
+        'response': toJsonU(response),
+      };
+
+  GenericEnumsUsingStructVariantsVariantGInner<T, U> copyWith({
+    Object? action = _unset,
+    Object? response = _unset,
+  }) =>
+      GenericEnumsUsingStructVariantsVariantGInner<T, U>(
+        action: identical(action, _unset) ? this.action : action as T,
+        response: identical(response, _unset) ? this.response : response as U,
+      );
+}
+
+/// Generated type representing the anonymous struct variant `VariantH` of the `GenericEnumsUsingStructVariants` Rust enum
+class GenericEnumsUsingStructVariantsVariantHInner {
+  const GenericEnumsUsingStructVariantsVariantHInner({
+    required this.nonGeneric,
+  });
+
+  factory GenericEnumsUsingStructVariantsVariantHInner.fromJson(Map<String, dynamic> json) =>
+      GenericEnumsUsingStructVariantsVariantHInner(
+        nonGeneric: (json['non_generic'] as num).toInt(),
+      );
+
+  final int nonGeneric;
+
+  Map<String, dynamic> toJson() => {
+        'non_generic': nonGeneric,
+      };
+
+  GenericEnumsUsingStructVariantsVariantHInner copyWith({
+    int? nonGeneric,
+  }) =>
+      GenericEnumsUsingStructVariantsVariantHInner(
+        nonGeneric: nonGeneric ?? this.nonGeneric,
+      );
+}
+
+/// Generated type representing the anonymous struct variant `VariantI` of the `GenericEnumsUsingStructVariants` Rust enum
+class GenericEnumsUsingStructVariantsVariantIInner<T, U> {
+  const GenericEnumsUsingStructVariantsVariantIInner({
+    required this.vec,
+    required this.action,
+  });
+
+  factory GenericEnumsUsingStructVariantsVariantIInner.fromJson(Map<String, dynamic> json, T Function(Object? json) fromJsonT, U Function(Object? json) fromJsonU) =>
+      GenericEnumsUsingStructVariantsVariantIInner<T, U>(
+        vec: (json['vec'] as List<dynamic>).map((v0) => fromJsonT(v0)).toList(),
+        action: MyType<T, U>.fromJson(json['action'], (dynamic v0) => fromJsonT(v0), (dynamic v0) => fromJsonU(v0)),
+      );
+
+  final List<T> vec;
+
+  final MyType<T, U> action;
+
+  Map<String, dynamic> toJson(Object? Function(T value) toJsonT, Object? Function(U value) toJsonU) => {
+        'vec': vec.map((v0) => toJsonT(v0)).toList(),
+        'action': action.toJson((v0) => toJsonT(v0), (v0) => toJsonU(v0)),
+      };
+
+  GenericEnumsUsingStructVariantsVariantIInner<T, U> copyWith({
+    List<T>? vec,
+    MyType<T, U>? action,
+  }) =>
+      GenericEnumsUsingStructVariantsVariantIInner<T, U>(
+        vec: vec ?? this.vec,
+        action: action ?? this.action,
+      );
+}
+
+sealed class GenericEnumsUsingStructVariants<T, U> {
+  const GenericEnumsUsingStructVariants();
+
+  factory GenericEnumsUsingStructVariants.fromJson(Map<String, dynamic> json, T Function(Object? json) fromJsonT, U Function(Object? json) fromJsonU) =>
+      switch (json['type']) {
+        'VariantF' => GenericEnumsUsingStructVariantsVariantF<T, U>(GenericEnumsUsingStructVariantsVariantFInner<T>.fromJson(json['content'], (dynamic v0) => fromJsonT(v0))),
+        'VariantG' => GenericEnumsUsingStructVariantsVariantG<T, U>(GenericEnumsUsingStructVariantsVariantGInner<T, U>.fromJson(json['content'], (dynamic v0) => fromJsonT(v0), (dynamic v0) => fromJsonU(v0))),
+        'VariantH' => GenericEnumsUsingStructVariantsVariantH<T, U>(GenericEnumsUsingStructVariantsVariantHInner.fromJson(json['content'])),
+        'VariantI' => GenericEnumsUsingStructVariantsVariantI<T, U>(GenericEnumsUsingStructVariantsVariantIInner<T, U>.fromJson(json['content'], (dynamic v0) => fromJsonT(v0), (dynamic v0) => fromJsonU(v0))),
+        final tag => throw ArgumentError.value(tag, 'type', 'Unknown GenericEnumsUsingStructVariants variant'),
+      };
+
+  Map<String, dynamic> toJson(Object? Function(T value) toJsonT, Object? Function(U value) toJsonU);
+}
+
+class GenericEnumsUsingStructVariantsVariantF<T, U> extends GenericEnumsUsingStructVariants<T, U> {
+  const GenericEnumsUsingStructVariantsVariantF(this.content);
+
+  final GenericEnumsUsingStructVariantsVariantFInner<T> content;
+
+  GenericEnumsUsingStructVariantsVariantF<T, U> copyWith({GenericEnumsUsingStructVariantsVariantFInner<T>? content}) =>
+      GenericEnumsUsingStructVariantsVariantF<T, U>(content ?? this.content);
+
+  @override
+  Map<String, dynamic> toJson(Object? Function(T value) toJsonT, Object? Function(U value) toJsonU) => {
+        'type': 'VariantF',
+        'content': content.toJson((v0) => toJsonT(v0)),
+      };
+}
+
+class GenericEnumsUsingStructVariantsVariantG<T, U> extends GenericEnumsUsingStructVariants<T, U> {
+  const GenericEnumsUsingStructVariantsVariantG(this.content);
+
+  final GenericEnumsUsingStructVariantsVariantGInner<T, U> content;
+
+  GenericEnumsUsingStructVariantsVariantG<T, U> copyWith({GenericEnumsUsingStructVariantsVariantGInner<T, U>? content}) =>
+      GenericEnumsUsingStructVariantsVariantG<T, U>(content ?? this.content);
+
+  @override
+  Map<String, dynamic> toJson(Object? Function(T value) toJsonT, Object? Function(U value) toJsonU) => {
+        'type': 'VariantG',
+        'content': content.toJson((v0) => toJsonT(v0), (v0) => toJsonU(v0)),
+      };
+}
+
+class GenericEnumsUsingStructVariantsVariantH<T, U> extends GenericEnumsUsingStructVariants<T, U> {
+  const GenericEnumsUsingStructVariantsVariantH(this.content);
+
+  final GenericEnumsUsingStructVariantsVariantHInner content;
+
+  GenericEnumsUsingStructVariantsVariantH<T, U> copyWith({GenericEnumsUsingStructVariantsVariantHInner? content}) =>
+      GenericEnumsUsingStructVariantsVariantH<T, U>(content ?? this.content);
+
+  @override
+  Map<String, dynamic> toJson(Object? Function(T value) toJsonT, Object? Function(U value) toJsonU) => {
+        'type': 'VariantH',
+        'content': content.toJson(),
+      };
+}
+
+class GenericEnumsUsingStructVariantsVariantI<T, U> extends GenericEnumsUsingStructVariants<T, U> {
+  const GenericEnumsUsingStructVariantsVariantI(this.content);
+
+  final GenericEnumsUsingStructVariantsVariantIInner<T, U> content;
+
+  GenericEnumsUsingStructVariantsVariantI<T, U> copyWith({GenericEnumsUsingStructVariantsVariantIInner<T, U>? content}) =>
+      GenericEnumsUsingStructVariantsVariantI<T, U>(content ?? this.content);
+
+  @override
+  Map<String, dynamic> toJson(Object? Function(T value) toJsonT, Object? Function(U value) toJsonU) => {
+        'type': 'VariantI',
+        'content': content.toJson((v0) => toJsonT(v0), (v0) => toJsonU(v0)),
+      };
+}
+
+/// The default of nullable `copyWith` parameters, which tells an omitted
+/// argument apart from `null`.
+const Object _unset = Object();
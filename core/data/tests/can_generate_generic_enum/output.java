@@ -0,0 +1,79 @@
+package com.agilebits.onepassword;
+
+import com.fasterxml.jackson.annotation.*;
+import java.util.List;
+import java.util.Map;
+
+public final class file_name {
+	private file_name() {}
+
+	@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "type")
+	@JsonSubTypes({
+		@JsonSubTypes.Type(value = GenericEnum.VariantA.class, name = "VariantA"),
+		@JsonSubTypes.Type(value = GenericEnum.VariantB.class, name = "VariantB"),
+	})
+	public sealed interface GenericEnum<A, B> {
+		record VariantA<A, B>(@JsonProperty("content") A content) implements GenericEnum<A, B> {}
+		record VariantB<A, B>(@JsonProperty("content") B content) implements GenericEnum<A, B> {}
+	}
+
+	public record StructUsingGenericEnum(
+		@JsonProperty("enum_field") GenericEnum<String, Short> enumField
+	) {}
+
+	@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "type")
+	@JsonSubTypes({
+		@JsonSubTypes.Type(value = GenericEnumUsingGenericEnum.VariantC.class, name = "VariantC"),
+		@JsonSubTypes.Type(value = GenericEnumUsingGenericEnum.VariantD.class, name = "VariantD"),
+		@JsonSubTypes.Type(value = GenericEnumUsingGenericEnum.VariantE.class, name = "VariantE"),
+	})
+	public sealed interface GenericEnumUsingGenericEnum<T> {
+		record VariantC<T>(@JsonProperty("content") GenericEnum<T, T> content) implements GenericEnumUsingGenericEnum<T> {}
+		record VariantD<T>(@JsonProperty("content") GenericEnum<String, Map<String, T>> content) implements GenericEnumUsingGenericEnum<T> {}
+		record VariantE<T>(@JsonProperty("content") GenericEnum<String, Long> content) implements GenericEnumUsingGenericEnum<T> {}
+	}
+
+	/**
+	 * Generated type representing the anonymous struct variant `VariantF` of the `GenericEnumsUsingStructVariants` Rust enum
+	 */
+	public record GenericEnumsUsingStructVariantsVariantFInner<T>(
+		@JsonProperty("action") T action
+	) {}
+
+	/**
+	 * Generated type representing the anonymous struct variant `VariantG` of the `GenericEnumsUsingStructVariants` Rust enum
+	 */
+	public record GenericEnumsUsingStructVariantsVariantGInner<T, U>(
+		@JsonProperty("action") T action,
+		@JsonProperty("response") U response
+	) {}
+
+	/**
+	 * Generated type representing the anonymous struct variant `VariantH` of the `GenericEnumsUsingStructVariants` Rust enum
+	 */
+	public record GenericEnumsUsingStructVariantsVariantHInner(
+		@JsonProperty("non_generic") int nonGeneric
+	) {}
+
+	/**
+	 * Generated type representing the anonymous struct variant `VariantI` of the `GenericEnumsUsingStructVariants` Rust enum
+	 */
+	public record GenericEnumsUsingStructVariantsVariantIInner<T, U>(
+		@JsonProperty("vec") List<T> vec,
+		@JsonProperty("action") MyType<T, U> action
+	) {}
+
+	@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "type")
+	@JsonSubTypes({
+		@JsonSubTypes.Type(value = GenericEnumsUsingStructVariants.VariantF.class, name = "VariantF"),
+		@JsonSubTypes.Type(value = GenericEnumsUsingStructVariants.VariantG.class, name = "VariantG"),
+		@JsonSubTypes.Type(value = GenericEnumsUsingStructVariants.VariantH.class, name = "VariantH"),
+		@JsonSubTypes.Type(value = GenericEnumsUsingStructVariants.VariantI.class, name = "VariantI"),
+	})
+	public sealed interface GenericEnumsUsingStructVariants<T, U> {
+		record VariantF<T, U>(@JsonProperty("content") GenericEnumsUsingStructVariantsVariantFInner<T> content) implements GenericEnumsUsingStructVariants<T, U> {}
+		record VariantG<T, U>(@JsonProperty("content") GenericEnumsUsingStructVariantsVariantGInner<T, U> content) implements GenericEnumsUsingStructVariants<T, U> {}
+		record VariantH<T, U>(@JsonProperty("content") GenericEnumsUsingStructVariantsVariantHInner content) implements GenericEnumsUsingStructVariants<T, U> {}
+		record VariantI<T, U>(@JsonProperty("content") GenericEnumsUsingStructVariantsVariantIInner<T, U> content) implements GenericEnumsUsingStructVariants<T, U> {}
+	}
+}
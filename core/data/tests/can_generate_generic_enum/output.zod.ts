@@ -0,0 +1,60 @@
+import { z } from "zod";
+
+export type GenericEnum<A, B> = 
+	| { type: "VariantA", content: A }
+	| { type: "VariantB", content: B };
+
+export const GenericEnumSchema = <A extends z.ZodTypeAny, B extends z.ZodTypeAny>(A: A, B: B) => z.discriminatedUnion("type", [
+	z.object({ type: z.literal("VariantA"), content: A }),
+	z.object({ type: z.literal("VariantB"), content: B }),
+]);
+
+export const StructUsingGenericEnumSchema = z.object({
+	enum_field: GenericEnumSchema(z.string(), z.number().int().min(-32768).max(32767)),
+});
+export type StructUsingGenericEnum = z.infer<typeof StructUsingGenericEnumSchema>;
+
+export type GenericEnumUsingGenericEnum<T> = 
+	| { type: "VariantC", content: GenericEnum<T, T> }
+	| { type: "VariantD", content: GenericEnum<string, Record<string, T>> }
+	| { type: "VariantE", content: GenericEnum<string, number> };
+
+export const GenericEnumUsingGenericEnumSchema = <T extends z.ZodTypeAny>(T: T) => z.discriminatedUnion("type", [
+	z.object({ type: z.literal("VariantC"), content: GenericEnumSchema(T, T) }),
+	z.object({ type: z.literal("VariantD"), content: GenericEnumSchema(z.string(), z.record(z.string(), T)) }),
+	z.object({ type: z.literal("VariantE"), content: GenericEnumSchema(z.string(), z.number().int().min(0).max(4294967295)) }),
+]);
+
+export type GenericEnumsUsingStructVariants<T, U> = 
+	| { type: "VariantF", content: {
+	action: T;
+}}
+	| { type: "VariantG", content: {
+	action: T;
+	response: U;
+}}
+	| { type: "VariantH", content: {
+	non_generic: number;
+}}
+	| { type: "VariantI", content: {
+	vec: T[];
+	action: MyType<T, U>;
+}};
+
+export const GenericEnumsUsingStructVariantsSchema = <T extends z.ZodTypeAny, U extends z.ZodTypeAny>(T: T, U: U) => z.discriminatedUnion("type", [
+	z.object({ type: z.literal("VariantF"), content: z.object({
+		action: T,
+	}) }),
+	z.object({ type: z.literal("VariantG"), content: z.object({
+		action: T,
+		response: U,
+	}) }),
+	z.object({ type: z.literal("VariantH"), content: z.object({
+		non_generic: z.number().int().min(-2147483648).max(2147483647),
+	}) }),
+	z.object({ type: z.literal("VariantI"), content: z.object({
+		vec: z.array(T),
+		action: MyTypeSchema(T, U),
+	}) }),
+]);
+
@@ -0,0 +1,46 @@
+#nullable enable
+
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace AgileBits.OnePassword;
+
+public record GenericStruct<A, B>
+{
+	[JsonPropertyName("field_a")]
+	public required A FieldA { get; init; }
+
+	[JsonPropertyName("field_b")]
+	public required List<B> FieldB { get; init; }
+}
+
+public record GenericStructUsingGenericStruct<T>
+{
+	[JsonPropertyName("struct_field")]
+	public required GenericStruct<string, T> StructField { get; init; }
+
+	[JsonPropertyName("second_struct_field")]
+	public required GenericStruct<T, string> SecondStructField { get; init; }
+
+	[JsonPropertyName("third_struct_field")]
+	public required GenericStruct<T, List<T>> ThirdStructField { get; init; }
+}
+
+[JsonPolymorphic(TypeDiscriminatorPropertyName = "type")]
+[JsonDerivedType(typeof(EnumUsingGenericStruct.VariantA), "VariantA")]
+[JsonDerivedType(typeof(EnumUsingGenericStruct.VariantB), "VariantB")]
+[JsonDerivedType(typeof(EnumUsingGenericStruct.VariantC), "VariantC")]
+[JsonDerivedType(typeof(EnumUsingGenericStruct.VariantD), "VariantD")]
+public abstract record EnumUsingGenericStruct
+{
+	private EnumUsingGenericStruct() {}
+
+	public sealed record VariantA([property: JsonPropertyName("content")] GenericStruct<string, float> Content) : EnumUsingGenericStruct;
+
+	public sealed record VariantB([property: JsonPropertyName("content")] GenericStruct<string, int> Content) : EnumUsingGenericStruct;
+
+	public sealed record VariantC([property: JsonPropertyName("content")] GenericStruct<string, bool> Content) : EnumUsingGenericStruct;
+
+	public sealed record VariantD([property: JsonPropertyName("content")] GenericStructUsingGenericStruct<object?> Content) : EnumUsingGenericStruct;
+}
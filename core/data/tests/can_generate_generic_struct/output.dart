@@ -0,0 +1,147 @@
+class GenericStruct<A, B> {
+  const GenericStruct({
+    required this.fieldA,
+    required this.fieldB,
+  });
+
+  factory GenericStruct.fromJson(Map<String, dynamic> json, A Function(Object? json) fromJsonA, B Function(Object? json) fromJsonB) =>
+      GenericStruct<A, B>(
+        fieldA: fromJsonA(json['field_a']),
+        fieldB: (json['field_b'] as List<dynamic>).map((v0) => fromJsonB(v0)).toList(),
+      );
+
+  final A fieldA;
+
+  final List<B> fieldB;
+
+  Map<String, dynamic> toJson(Object? Function(A value) toJsonA, Object? Function(B value) toJsonB) => {
+        'field_a': toJsonA(fieldA),
+        'field_b': fieldB.map((v0) => toJsonB(v0)).toList(),
+      };
+
+  GenericStruct<A, B> copyWith({
+    Object? fieldA = _unset,
+    List<B>? fieldB,
+  }) =>
+      GenericStruct<A, B>(
+        fieldA: identical(fieldA, _unset) ? this.fieldA : fieldA as A,
+        fieldB: fieldB ?? this.fieldB,
+      );
+}
+
+class GenericStructUsingGenericStruct<T> {
+  const GenericStructUsingGenericStruct({
+    required this.structField,
+    required this.secondStructField,
+    required this.thirdStructField,
+  });
+
+  factory GenericStructUsingGenericStruct.fromJson(Map<String, dynamic> json, T Function(Object? json) fromJsonT) =>
+      GenericStructUsingGenericStruct<T>(
+        structField: GenericStruct<String, T>.fromJson(json['struct_field'], (dynamic v0) => v0 as String, (dynamic v0) => fromJsonT(v0)),
+        secondStructField: GenericStruct<T, String>.fromJson(json['second_struct_field'], (dynamic v0) => fromJsonT(v0), (dynamic v0) => v0 as String),
+        thirdStructField: GenericStruct<T, List<T>>.fromJson(json['third_struct_field'], (dynamic v0) => fromJsonT(v0), (dynamic v0) => (v0 as List<dynamic>).map((v1) => fromJsonT(v1)).toList()),
+      );
+
+  final GenericStruct<String, T> structField;
+
+  final GenericStruct<T, String> secondStructField;
+
+  final GenericStruct<T, List<T>> thirdStructField;
+
+  Map<String, dynamic> toJson(Object? Function(T value) toJsonT) => {
+        'struct_field': structField.toJson((v0) => v0, (v0) => toJsonT(v0)),
+        'second_struct_field': secondStructField.toJson((v0) => toJsonT(v0), (v0) => v0),
+        'third_struct_field': thirdStructField.toJson((v0) => toJsonT(v0), (v0) => v0.map((v1) => toJsonT(v1)).toList()),
+      };
+
+  GenericStructUsingGenericStruct<T> copyWith({
+    GenericStruct<String, T>? structField,
+    GenericStruct<T, String>? secondStructField,
+    GenericStruct<T, List<T>>? thirdStructField,
+  }) =>
+      GenericStructUsingGenericStruct<T>(
+        structField: structField ?? this.structField,
+        secondStructField: secondStructField ?? this.secondStructField,
+        thirdStructField: thirdStructField ?? this.thirdStructField,
+      );
+}
+
+sealed class EnumUsingGenericStruct {
+  const EnumUsingGenericStruct();
+
+  factory EnumUsingGenericStruct.fromJson(Map<String, dynamic> json) =>
+      switch (json['type']) {
+        'VariantA' => EnumUsingGenericStructVariantA(GenericStruct<String, double>.fromJson(json['content'], (dynamic v0) => v0 as String, (dynamic v0) => (v0 as num).toDouble())),
+        'VariantB' => EnumUsingGenericStructVariantB(GenericStruct<String, int>.fromJson(json['content'], (dynamic v0) => v0 as String, (dynamic v0) => (v0 as num).toInt())),
+        'VariantC' => EnumUsingGenericStructVariantC(GenericStruct<String, bool>.fromJson(json['content'], (dynamic v0) => v0 as String, (dynamic v0) => v0 as bool)),
+        'VariantD' => EnumUsingGenericStructVariantD(GenericStructUsingGenericStruct<Null>.fromJson(json['content'], (dynamic v0) => null)),
+        final tag => throw ArgumentError.value(tag, 'type', 'Unknown EnumUsingGenericStruct variant'),
+      };
+
+  Map<String, dynamic> toJson();
+}
+
+class EnumUsingGenericStructVariantA extends EnumUsingGenericStruct {
+  const EnumUsingGenericStructVariantA(this.content);
+
+  final GenericStruct<String, double> content;
+
+  EnumUsingGenericStructVariantA copyWith({GenericStruct<String, double>? content}) =>
+      EnumUsingGenericStructVariantA(content ?? this.content);
+
+  @override
+  Map<String, dynamic> toJson() => {
+        'type': 'VariantA',
+        'content': content.toJson((v0) => v0, (v0) => v0),
+      };
+}
+
+class EnumUsingGenericStructVariantB extends EnumUsingGenericStruct {
+  const EnumUsingGenericStructVariantB(this.content);
+
+  final GenericStruct<String, int> content;
+
+  EnumUsingGenericStructVariantB copyWith({GenericStruct<String, int>? content}) =>
+      EnumUsingGenericStructVariantB(content ?? this.content);
+
+  @override
+  Map<String, dynamic> toJson() => {
+        'type': 'VariantB',
+        'content': content.toJson((v0) => v0, (v0) => v0),
+      };
+}
+
+class EnumUsingGenericStructVariantC extends EnumUsingGenericStruct {
+  const EnumUsingGenericStructVariantC(this.content);
+
+  final GenericStruct<String, bool> content;
+
+  EnumUsingGenericStructVariantC copyWith({GenericStruct<String, bool>? content}) =>
+      EnumUsingGenericStructVariantC(content ?? this.content);
+
+  @override
+  Map<String, dynamic> toJson() => {
+        'type': 'VariantC',
+        'content': content.toJson((v0) => v0, (v0) => v0),
+      };
+}
+
+class EnumUsingGenericStructVariantD extends EnumUsingGenericStruct {
+  const EnumUsingGenericStructVariantD(this.content);
+
+  final GenericStructUsingGenericStruct<Null> content;
+
+  EnumUsingGenericStructVariantD copyWith({GenericStructUsingGenericStruct<Null>? content}) =>
+      EnumUsingGenericStructVariantD(content ?? this.content);
+
+  @override
+  Map<String, dynamic> toJson() => {
+        'type': 'VariantD',
+        'content': content.toJson((v0) => v0),
+      };
+}
+
+/// The default of nullable `copyWith` parameters, which tells an omitted
+/// argument apart from `null`.
+const Object _unset = Object();
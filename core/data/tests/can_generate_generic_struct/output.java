@@ -0,0 +1,34 @@
+package com.agilebits.onepassword;
+
+import com.fasterxml.jackson.annotation.*;
+import java.util.List;
+import java.util.Map;
+
+public final class file_name {
+	private file_name() {}
+
+	public record GenericStruct<A, B>(
+		@JsonProperty("field_a") A fieldA,
+		@JsonProperty("field_b") List<B> fieldB
+	) {}
+
+	public record GenericStructUsingGenericStruct<T>(
+		@JsonProperty("struct_field") GenericStruct<String, T> structField,
+		@JsonProperty("second_struct_field") GenericStruct<T, String> secondStructField,
+		@JsonProperty("third_struct_field") GenericStruct<T, List<T>> thirdStructField
+	) {}
+
+	@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "type")
+	@JsonSubTypes({
+		@JsonSubTypes.Type(value = EnumUsingGenericStruct.VariantA.class, name = "VariantA"),
+		@JsonSubTypes.Type(value = EnumUsingGenericStruct.VariantB.class, name = "VariantB"),
+		@JsonSubTypes.Type(value = EnumUsingGenericStruct.VariantC.class, name = "VariantC"),
+		@JsonSubTypes.Type(value = EnumUsingGenericStruct.VariantD.class, name = "VariantD"),
+	})
+	public sealed interface EnumUsingGenericStruct {
+		record VariantA(@JsonProperty("content") GenericStruct<String, Float> content) implements EnumUsingGenericStruct {}
+		record VariantB(@JsonProperty("content") GenericStruct<String, Integer> content) implements EnumUsingGenericStruct {}
+		record VariantC(@JsonProperty("content") GenericStruct<String, Boolean> content) implements EnumUsingGenericStruct {}
+		record VariantD(@JsonProperty("content") GenericStructUsingGenericStruct<Void> content) implements EnumUsingGenericStruct {}
+	}
+}
@@ -0,0 +1,32 @@
+import { z } from "zod";
+
+export interface GenericStruct<A, B> {
+	field_a: A;
+	field_b: B[];
+}
+
+export const GenericStructSchema = <A extends z.ZodTypeAny, B extends z.ZodTypeAny>(A: A, B: B) => z.object({
+	field_a: A,
+	field_b: z.array(B),
+});
+
+export interface GenericStructUsingGenericStruct<T> {
+	struct_field: GenericStruct<string, T>;
+	second_struct_field: GenericStruct<T, string>;
+	third_struct_field: GenericStruct<T, T[]>;
+}
+
+export const GenericStructUsingGenericStructSchema = <T extends z.ZodTypeAny>(T: T) => z.object({
+	struct_field: GenericStructSchema(z.string(), T),
+	second_struct_field: GenericStructSchema(T, z.string()),
+	third_struct_field: GenericStructSchema(T, z.array(T)),
+});
+
+export const EnumUsingGenericStructSchema = z.discriminatedUnion("type", [
+	z.object({ type: z.literal("VariantA"), content: GenericStructSchema(z.string(), z.number()) }),
+	z.object({ type: z.literal("VariantB"), content: GenericStructSchema(z.string(), z.number().int().min(-2147483648).max(2147483647)) }),
+	z.object({ type: z.literal("VariantC"), content: GenericStructSchema(z.string(), z.boolean()) }),
+	z.object({ type: z.literal("VariantD"), content: GenericStructUsingGenericStructSchema(z.null()) }),
+]);
+export type EnumUsingGenericStruct = z.infer<typeof EnumUsingGenericStructSchema>;
+
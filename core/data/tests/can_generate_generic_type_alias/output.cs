@@ -0,0 +1,40 @@
+#nullable enable
+
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace AgileBits.OnePassword;
+
+[JsonConverter(typeof(GenericTypeAliasJsonConverterFactory))]
+public sealed record GenericTypeAlias<T>(List<T> Value);
+
+public sealed class GenericTypeAliasJsonConverter<T> : JsonConverter<GenericTypeAlias<T>>
+{
+	public override GenericTypeAlias<T> Read(ref Utf8JsonReader reader, System.Type typeToConvert, JsonSerializerOptions options) =>
+		new(JsonSerializer.Deserialize<List<T>>(ref reader, options)!);
+
+	public override void Write(Utf8JsonWriter writer, GenericTypeAlias<T> value, JsonSerializerOptions options) =>
+		JsonSerializer.Serialize(writer, value.Value, options);
+}
+
+public sealed class GenericTypeAliasJsonConverterFactory : JsonConverterFactory
+{
+	public override bool CanConvert(System.Type typeToConvert) =>
+		typeToConvert.IsGenericType && typeToConvert.GetGenericTypeDefinition() == typeof(GenericTypeAlias<>);
+
+	public override JsonConverter? CreateConverter(System.Type typeToConvert, JsonSerializerOptions options) =>
+		(JsonConverter?)System.Activator.CreateInstance(typeof(GenericTypeAliasJsonConverter<>).MakeGenericType(typeToConvert.GetGenericArguments()));
+}
+
+[JsonConverter(typeof(NonGenericAliasJsonConverter))]
+public sealed record NonGenericAlias(GenericTypeAlias<string?> Value);
+
+public sealed class NonGenericAliasJsonConverter : JsonConverter<NonGenericAlias>
+{
+	public override NonGenericAlias Read(ref Utf8JsonReader reader, System.Type typeToConvert, JsonSerializerOptions options) =>
+		new(JsonSerializer.Deserialize<GenericTypeAlias<string?>>(ref reader, options)!);
+
+	public override void Write(Utf8JsonWriter writer, NonGenericAlias value, JsonSerializerOptions options) =>
+		JsonSerializer.Serialize(writer, value.Value, options);
+}
@@ -0,0 +1,16 @@
+typedef GenericTypeAlias<T> = List<T>;
+
+GenericTypeAlias<T> genericTypeAliasFromJson<T>(dynamic json, T Function(Object? json) fromJsonT) =>
+    (json as List<dynamic>).map((v0) => fromJsonT(v0)).toList();
+
+Object? genericTypeAliasToJson<T>(GenericTypeAlias<T> value, Object? Function(T value) toJsonT) =>
+    value.map((v0) => toJsonT(v0)).toList();
+
+typedef NonGenericAlias = GenericTypeAlias<String?>;
+
+NonGenericAlias nonGenericAliasFromJson(dynamic json) =>
+    genericTypeAliasFromJson<String?>(json, (dynamic v0) => v0 as String?);
+
+Object? nonGenericAliasToJson(NonGenericAlias value) =>
+    genericTypeAliasToJson<String?>(value, (v0) => v0);
+
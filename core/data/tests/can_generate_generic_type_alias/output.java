@@ -0,0 +1,19 @@
+package com.agilebits.onepassword;
+
+import com.fasterxml.jackson.annotation.*;
+import java.util.List;
+import java.util.Map;
+
+public final class file_name {
+	private file_name() {}
+
+	public record GenericTypeAlias<T>(@JsonValue List<T> value) {
+		@JsonCreator(mode = JsonCreator.Mode.DELEGATING)
+		public GenericTypeAlias {}
+	}
+
+	public record NonGenericAlias(@JsonValue GenericTypeAlias<String> value) {
+		@JsonCreator(mode = JsonCreator.Mode.DELEGATING)
+		public NonGenericAlias {}
+	}
+}
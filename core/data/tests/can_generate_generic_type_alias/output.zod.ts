@@ -0,0 +1,9 @@
+import { z } from "zod";
+
+export type GenericTypeAlias<T> = T[];
+
+export const GenericTypeAliasSchema = <T extends z.ZodTypeAny>(T: T) => z.array(T);
+
+export const NonGenericAliasSchema = GenericTypeAliasSchema(z.string().nullable().transform((value) => value ?? undefined));
+export type NonGenericAlias = z.infer<typeof NonGenericAliasSchema>;
+
@@ -0,0 +1,36 @@
+/// A page of results.
+#[typeshare]
+#[typeshare(graphql = "type, input")]
+#[serde(rename_all = "camelCase")]
+pub struct Pagination {
+    pub page_size: u16,
+    #[serde(default)]
+    pub cursor: Option<String>,
+}
+
+#[typeshare]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+/// The arguments of a search.
+#[typeshare]
+#[typeshare(graphql = "input")]
+#[serde(rename_all = "camelCase")]
+pub struct SearchInput {
+    pub query: String,
+    pub pagination: Pagination,
+    /// Defaults to ascending.
+    #[serde(default)]
+    pub order: SortOrder,
+    pub tags: Vec<String>,
+}
+
+#[typeshare]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResults {
+    pub pagination: Pagination,
+    pub hits: Vec<String>,
+    pub total: u32,
+}
@@ -0,0 +1,22 @@
+#[typeshare]
+#[typeshare(graphql = "type, input")]
+#[serde(rename = "Window")]
+pub struct Pagination {
+    pub size: u16,
+}
+
+#[typeshare]
+#[serde(rename = "MaybeOffset")]
+pub type Offset = Option<u32>;
+
+#[typeshare]
+#[serde(rename = "Identifier")]
+pub type Id = String;
+
+#[typeshare]
+#[typeshare(graphql = "input")]
+pub struct Query {
+    pub id: Id,
+    pub pagination: Pagination,
+    pub offset: Offset,
+}
@@ -0,0 +1,43 @@
+#nullable enable
+
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace AgileBits.OnePassword;
+
+/// <summary>
+/// This is a comment.
+/// Continued lovingly here
+/// </summary>
+[JsonConverter(typeof(ColorsJsonConverter))]
+public enum Colors
+{
+	Red,
+	Blue,
+	/// <summary>
+	/// Green is a cool color
+	/// </summary>
+	Green,
+}
+
+public sealed class ColorsJsonConverter : JsonConverter<Colors>
+{
+	public override Colors Read(ref Utf8JsonReader reader, System.Type typeToConvert, JsonSerializerOptions options) =>
+		reader.GetString() switch
+		{
+			"Red" => Colors.Red,
+			"Blue" => Colors.Blue,
+			"Green" => Colors.Green,
+			var value => throw new JsonException("Unknown Colors value: " + value),
+		};
+
+	public override void Write(Utf8JsonWriter writer, Colors value, JsonSerializerOptions options) =>
+		writer.WriteStringValue(value switch
+		{
+			Colors.Red => "Red",
+			Colors.Blue => "Blue",
+			Colors.Green => "Green",
+			_ => throw new JsonException("Unknown Colors value: " + value),
+		});
+}
@@ -0,0 +1,25 @@
+import 'package:json_annotation/json_annotation.dart';
+
+/// This is a comment.
+/// Continued lovingly here
+enum Colors {
+  @JsonValue('Red')
+  red('Red'),
+  @JsonValue('Blue')
+  blue('Blue'),
+  /// Green is a cool color
+  @JsonValue('Green')
+  green('Green');
+
+  const Colors(this.value);
+
+  final String value;
+
+  static Colors fromJson(String json) => values.firstWhere(
+        (e) => e.value == json,
+        orElse: () => throw ArgumentError.value(json, 'json', 'Unknown Colors value'),
+      );
+
+  String toJson() => value;
+}
+
@@ -0,0 +1,25 @@
+package com.agilebits.onepassword;
+
+import com.fasterxml.jackson.annotation.*;
+import java.util.List;
+import java.util.Map;
+
+public final class file_name {
+	private file_name() {}
+
+	/**
+	 * This is a comment.
+	 * Continued lovingly here
+	 */
+	public enum Colors {
+		@JsonProperty("Red")
+		Red,
+		@JsonProperty("Blue")
+		Blue,
+		/**
+		 * Green is a cool color
+		 */
+		@JsonProperty("Green")
+		Green,
+	}
+}
@@ -0,0 +1,15 @@
+import { z } from "zod";
+
+/**
+ * This is a comment.
+ * Continued lovingly here
+ */
+export enum Colors {
+	Red = "Red",
+	Blue = "Blue",
+	/** Green is a cool color */
+	Green = "Green",
+}
+
+export const ColorsSchema = z.nativeEnum(Colors);
+
@@ -0,0 +1,33 @@
+#nullable enable
+
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace AgileBits.OnePassword;
+
+public record Location;
+
+/// <summary>
+/// This is a comment.
+/// </summary>
+public record Person
+{
+	/// <summary>
+	/// This is another comment
+	/// </summary>
+	[JsonPropertyName("name")]
+	public required string Name { get; init; }
+
+	[JsonPropertyName("age")]
+	public required byte Age { get; init; }
+
+	[JsonPropertyName("info")]
+	public string? Info { get; init; }
+
+	[JsonPropertyName("emails")]
+	public required List<string> Emails { get; init; }
+
+	[JsonPropertyName("location")]
+	public required Location Location { get; init; }
+}
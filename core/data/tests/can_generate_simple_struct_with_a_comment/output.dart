@@ -0,0 +1,66 @@
+class Location {
+  const Location();
+
+  factory Location.fromJson(Map<String, dynamic> json) =>
+      const Location();
+
+  Map<String, dynamic> toJson() => {};
+}
+
+/// This is a comment.
+class Person {
+  const Person({
+    required this.name_,
+    required this.age,
+    this.info,
+    required this.emails,
+    required this.location,
+  });
+
+  factory Person.fromJson(Map<String, dynamic> json) =>
+      Person(
+        name_: json['name'] as String,
+        age: (json['age'] as num).toInt(),
+        info: json['info'] as String?,
+        emails: (json['emails'] as List<dynamic>).map((v0) => v0 as String).toList(),
+        location: Location.fromJson(json['location']),
+      );
+
+  /// This is another comment
+  final String name_;
+
+  final int age;
+
+  final String? info;
+
+  final List<String> emails;
+
+  final Location location;
+
+  Map<String, dynamic> toJson() => {
+        'name': name_,
+        'age': age,
+        'info': info,
+        'emails': emails,
+        'location': location.toJson(),
+      };
+
+  Person copyWith({
+    String? name_,
+    int? age,
+    Object? info = _unset,
+    List<String>? emails,
+    Location? location,
+  }) =>
+      Person(
+        name_: name_ ?? this.name_,
+        age: age ?? this.age,
+        info: identical(info, _unset) ? this.info : info as String?,
+        emails: emails ?? this.emails,
+        location: location ?? this.location,
+      );
+}
+
+/// The default of nullable `copyWith` parameters, which tells an omitted
+/// argument apart from `null`.
+const Object _unset = Object();
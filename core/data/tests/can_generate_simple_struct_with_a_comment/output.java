@@ -0,0 +1,25 @@
+package com.agilebits.onepassword;
+
+import com.fasterxml.jackson.annotation.*;
+import java.util.List;
+import java.util.Map;
+
+public final class file_name {
+	private file_name() {}
+
+	public record Location() {}
+
+	/**
+	 * This is a comment.
+	 */
+	public record Person(
+		/**
+		 * This is another comment
+		 */
+		@JsonProperty("name") String name,
+		@JsonProperty("age") short age,
+		@JsonProperty("info") String info,
+		@JsonProperty("emails") List<String> emails,
+		@JsonProperty("location") Location location
+	) {}
+}
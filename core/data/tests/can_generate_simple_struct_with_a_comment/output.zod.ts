@@ -0,0 +1,17 @@
+import { z } from "zod";
+
+export const LocationSchema = z.object({
+});
+export type Location = z.infer<typeof LocationSchema>;
+
+/** This is a comment. */
+export const PersonSchema = z.object({
+	/** This is another comment */
+	name: z.string(),
+	age: z.number().int().min(0).max(255),
+	info: z.string().nullish().transform((value) => value ?? undefined),
+	emails: z.array(z.string()),
+	location: LocationSchema,
+});
+export type Person = z.infer<typeof PersonSchema>;
+
@@ -0,0 +1,7 @@
+import { z } from "zod";
+
+export const VideoSchema = z.object({
+	tags: z.array(TagSchema),
+});
+export type Video = z.infer<typeof VideoSchema>;
+
@@ -0,0 +1,16 @@
+/// The shape of a drawing.
+#[typeshare]
+#[serde(tag = "type", content = "content")]
+pub enum Shape {
+    Circle(f64),
+    Rectangle { width: f64, height: f64 },
+    Point,
+    Label(Option<String>),
+}
+
+#[typeshare]
+#[serde(tag = "kind", content = "value", rename_all = "kebab-case")]
+pub enum Response<T> {
+    Ok(T),
+    NotFound,
+}
@@ -0,0 +1,92 @@
+export type Response<T> = 
+	| { kind: "ok", value: T }
+	| { kind: "not-found", value?: undefined };
+
+export function isResponseOk<T>(value: Response<T>): value is Extract<Response<T>, { kind: "ok" }> {
+	return value.kind === "ok";
+}
+
+export function responseOk<T>(content: T): Response<T> {
+	return { kind: "ok", value: content };
+}
+
+export function isResponseNotFound<T>(value: Response<T>): value is Extract<Response<T>, { kind: "not-found" }> {
+	return value.kind === "not-found";
+}
+
+export function responseNotFound<T>(): Response<T> {
+	return { kind: "not-found" };
+}
+
+export function matchResponse<T, R>(value: Response<T>, cases: {
+	Ok: (content: T) => R;
+	NotFound: () => R;
+}): R {
+	switch (value.kind) {
+		case "ok":
+			return cases.Ok(value.value);
+		case "not-found":
+			return cases.NotFound();
+	}
+}
+
+/** The shape of a drawing. */
+export type Shape = 
+	| { type: "Circle", content: number }
+	| { type: "Rectangle", content: {
+	width: number;
+	height: number;
+}}
+	| { type: "Point", content?: undefined }
+	| { type: "Label", content?: string };
+
+export function isShapeCircle(value: Shape): value is Extract<Shape, { type: "Circle" }> {
+	return value.type === "Circle";
+}
+
+export function shapeCircle(content: number): Shape {
+	return { type: "Circle", content };
+}
+
+export function isShapeRectangle(value: Shape): value is Extract<Shape, { type: "Rectangle" }> {
+	return value.type === "Rectangle";
+}
+
+export function shapeRectangle(content: Extract<Shape, { type: "Rectangle" }>["content"]): Shape {
+	return { type: "Rectangle", content };
+}
+
+export function isShapePoint(value: Shape): value is Extract<Shape, { type: "Point" }> {
+	return value.type === "Point";
+}
+
+export function shapePoint(): Shape {
+	return { type: "Point" };
+}
+
+export function isShapeLabel(value: Shape): value is Extract<Shape, { type: "Label" }> {
+	return value.type === "Label";
+}
+
+export function shapeLabel(content: string | undefined): Shape {
+	return { type: "Label", content };
+}
+
+export function matchShape<R>(value: Shape, cases: {
+	Circle: (content: number) => R;
+	Rectangle: (content: Extract<Shape, { type: "Rectangle" }>["content"]) => R;
+	Point: () => R;
+	Label: (content: string | undefined) => R;
+}): R {
+	switch (value.type) {
+		case "Circle":
+			return cases.Circle(value.content);
+		case "Rectangle":
+			return cases.Rectangle(value.content);
+		case "Point":
+			return cases.Point();
+		case "Label":
+			return cases.Label(value.content);
+	}
+}
+
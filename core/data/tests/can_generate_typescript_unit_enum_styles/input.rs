@@ -0,0 +1,15 @@
+/// The colors of a traffic light.
+#[typeshare]
+#[serde(rename_all = "lowercase")]
+pub enum Light {
+    /// Stop.
+    Red,
+    Amber,
+    Green,
+}
+
+#[typeshare]
+pub struct Junction {
+    pub light: Light,
+    pub timings: HashMap<Light, u32>,
+}
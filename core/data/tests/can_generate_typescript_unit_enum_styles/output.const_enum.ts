@@ -0,0 +1,22 @@
+import { z } from "zod";
+
+/** The colors of a traffic light. */
+export const enum Light {
+	/** Stop. */
+	Red = "red",
+	Amber = "amber",
+	Green = "green",
+}
+
+export const LightSchema = z.enum([Light.Red, Light.Amber, Light.Green]);
+
+export interface Junction {
+	light: Light;
+	timings: Record<Light, number>;
+}
+
+export const JunctionSchema = z.object({
+	light: LightSchema,
+	timings: z.record(LightSchema, z.number().int().min(0).max(4294967295)),
+});
+
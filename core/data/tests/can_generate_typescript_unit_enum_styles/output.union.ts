@@ -0,0 +1,21 @@
+import { z } from "zod";
+
+/** The colors of a traffic light. */
+export type Light = 
+	/** Stop. */
+	| "red"
+	| "amber"
+	| "green";
+
+export const LightSchema = z.enum(["red", "amber", "green"]);
+
+export interface Junction {
+	light: Light;
+	timings: Record<Light, number>;
+}
+
+export const JunctionSchema = z.object({
+	light: LightSchema,
+	timings: z.record(LightSchema, z.number().int().min(0).max(4294967295)),
+});
+
@@ -0,0 +1,9 @@
+class UnitStruct {
+  const UnitStruct();
+
+  factory UnitStruct.fromJson(Map<String, dynamic> json) =>
+      const UnitStruct();
+
+  Map<String, dynamic> toJson() => {};
+}
+
@@ -0,0 +1,6 @@
+import { z } from "zod";
+
+export const UnitStructSchema = z.object({
+});
+export type UnitStruct = z.infer<typeof UnitStructSchema>;
+
@@ -0,0 +1,60 @@
+#[typeshare]
+#[serde(rename_all = "camelCase")]
+pub enum Role {
+    Admin,
+    Member,
+}
+
+/// A user of the app.
+#[typeshare]
+#[serde(rename_all = "camelCase")]
+pub struct User {
+    pub id: U53,
+    pub name: String,
+    pub role: Role,
+    /// Seconds from UTC.
+    pub utc_offset: I54,
+    #[serde(default)]
+    pub nickname: String,
+    pub email: Option<String>,
+    pub scores: HashMap<u8, f64>,
+    pub manager: Option<Box<User>>,
+}
+
+#[typeshare]
+#[serde(rename_all = "camelCase")]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>,
+}
+
+#[typeshare]
+#[serde(tag = "kind", content = "data", rename_all = "camelCase")]
+pub enum Event {
+    /// A user signed up.
+    SignedUp(User),
+    LoggedOut,
+    Renamed { from: String, to: String },
+    Listed(Page<User>),
+}
+
+#[typeshare]
+pub struct Tree<T> {
+    pub value: T,
+    pub children: Vec<Tree<T>>,
+}
+
+#[typeshare]
+#[serde(tag = "audit-kind", content = "audit-data")]
+pub enum Audit {
+    Created { by: String },
+    Deleted,
+}
+
+#[typeshare]
+pub struct Upload {
+    #[typeshare(typescript(type = "Blob"))]
+    pub file: String,
+    #[typeshare(typescript(type = "Date", zod = "z.coerce.date()"))]
+    pub at: String,
+}
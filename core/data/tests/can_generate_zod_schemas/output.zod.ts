@@ -0,0 +1,80 @@
+import { z } from "zod";
+
+export interface Page<T> {
+	items: T[];
+	nextCursor?: string;
+}
+
+export const PageSchema = <T extends z.ZodTypeAny>(T: T) => z.object({
+	items: z.array(T),
+	nextCursor: z.string().nullish().transform((value) => value ?? undefined),
+});
+
+export interface Tree<T> {
+	value: T;
+	children: Tree<T>[];
+}
+
+export const TreeSchema = <T extends z.ZodTypeAny>(T: T): z.ZodTypeAny => z.object({
+	value: T,
+	children: z.array(z.lazy(() => TreeSchema(T))),
+});
+
+export const UploadSchema = z.object({
+	file: z.unknown(),
+	at: z.coerce.date(),
+});
+export type Upload = z.infer<typeof UploadSchema>;
+
+export enum Role {
+	Admin = "admin",
+	Member = "member",
+}
+
+export const RoleSchema = z.nativeEnum(Role);
+
+/** A user of the app. */
+export interface User {
+	id: number;
+	name: string;
+	role: Role;
+	/** Seconds from UTC. */
+	utcOffset: number;
+	nickname?: string;
+	email?: string;
+	scores: Record<number, number>;
+	manager?: User;
+}
+
+export const UserSchema: z.ZodType<User, z.ZodTypeDef, unknown> = z.object({
+	id: z.number().int().nonnegative().safe(),
+	name: z.string(),
+	role: RoleSchema,
+	/** Seconds from UTC. */
+	utcOffset: z.number().int().safe(),
+	nickname: z.string().optional(),
+	email: z.string().nullish().transform((value) => value ?? undefined),
+	scores: z.record(z.coerce.number().int().min(0).max(255), z.number()),
+	manager: z.lazy(() => UserSchema).nullish().transform((value) => value ?? undefined),
+});
+
+export const AuditSchema = z.discriminatedUnion("audit-kind", [
+	z.object({ "audit-kind": z.literal("Created"), "audit-data": z.object({
+		by: z.string(),
+	}) }),
+	z.object({ "audit-kind": z.literal("Deleted") }),
+]);
+export type Audit = z.infer<typeof AuditSchema>;
+
+export const EventSchema = z.discriminatedUnion("kind", [
+	/** A user signed up. */
+	z.object({ kind: z.literal("signedUp"), data: UserSchema }),
+	z.object({ kind: z.literal("loggedOut") }),
+	z.object({ kind: z.literal("renamed"), data: z.object({
+		from: z.string(),
+		to: z.string(),
+	}) }),
+	z.object({ kind: z.literal("listed"), data: PageSchema(UserSchema) }),
+]);
+export type Event = z.infer<typeof EventSchema>;
+
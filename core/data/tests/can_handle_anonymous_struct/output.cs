@@ -0,0 +1,106 @@
+#nullable enable
+
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace AgileBits.OnePassword;
+
+/// <summary>
+/// Generated type representing the anonymous struct variant `Us` of the `AutofilledBy` Rust enum
+/// </summary>
+public record AutofilledByUsInner
+{
+	/// <summary>
+	/// The UUID for the fill
+	/// </summary>
+	[JsonPropertyName("uuid")]
+	public required string Uuid { get; init; }
+}
+
+/// <summary>
+/// Generated type representing the anonymous struct variant `SomethingElse` of the `AutofilledBy` Rust enum
+/// </summary>
+public record AutofilledBySomethingElseInner
+{
+	/// <summary>
+	/// The UUID for the fill
+	/// </summary>
+	[JsonPropertyName("uuid")]
+	public required string Uuid { get; init; }
+
+	/// <summary>
+	/// Some other thing
+	/// </summary>
+	[JsonPropertyName("thing")]
+	public required int Thing { get; init; }
+}
+
+/// <summary>
+/// Enum keeping track of who autofilled a field
+/// </summary>
+[JsonPolymorphic(TypeDiscriminatorPropertyName = "type")]
+[JsonDerivedType(typeof(AutofilledBy.Us), "Us")]
+[JsonDerivedType(typeof(AutofilledBy.SomethingElse), "SomethingElse")]
+public abstract record AutofilledBy
+{
+	private AutofilledBy() {}
+
+	/// <summary>
+	/// This field was autofilled by us
+	/// </summary>
+	public sealed record Us([property: JsonPropertyName("content")] AutofilledByUsInner Content) : AutofilledBy;
+
+	/// <summary>
+	/// Something else autofilled this field
+	/// </summary>
+	public sealed record SomethingElse([property: JsonPropertyName("content")] AutofilledBySomethingElseInner Content) : AutofilledBy;
+}
+
+/// <summary>
+/// Generated type representing the anonymous struct variant `AnonVariant` of the `EnumWithManyVariants` Rust enum
+/// </summary>
+public record EnumWithManyVariantsAnonVariantInner
+{
+	[JsonPropertyName("uuid")]
+	public required string Uuid { get; init; }
+}
+
+/// <summary>
+/// Generated type representing the anonymous struct variant `AnotherAnonVariant` of the `EnumWithManyVariants` Rust enum
+/// </summary>
+public record EnumWithManyVariantsAnotherAnonVariantInner
+{
+	[JsonPropertyName("uuid")]
+	public required string Uuid { get; init; }
+
+	[JsonPropertyName("thing")]
+	public required int Thing { get; init; }
+}
+
+/// <summary>
+/// This is a comment (yareek sameek wuz here)
+/// </summary>
+[JsonPolymorphic(TypeDiscriminatorPropertyName = "type")]
+[JsonDerivedType(typeof(EnumWithManyVariants.UnitVariant), "UnitVariant")]
+[JsonDerivedType(typeof(EnumWithManyVariants.TupleVariantString), "TupleVariantString")]
+[JsonDerivedType(typeof(EnumWithManyVariants.AnonVariant), "AnonVariant")]
+[JsonDerivedType(typeof(EnumWithManyVariants.TupleVariantInt), "TupleVariantInt")]
+[JsonDerivedType(typeof(EnumWithManyVariants.AnotherUnitVariant), "AnotherUnitVariant")]
+[JsonDerivedType(typeof(EnumWithManyVariants.AnotherAnonVariant), "AnotherAnonVariant")]
+public abstract record EnumWithManyVariants
+{
+	private EnumWithManyVariants() {}
+
+	public sealed record UnitVariant() : EnumWithManyVariants;
+
+	public sealed record TupleVariantString([property: JsonPropertyName("content")] string Content) : EnumWithManyVariants;
+
+	public sealed record AnonVariant([property: JsonPropertyName("content")] EnumWithManyVariantsAnonVariantInner Content) : EnumWithManyVariants;
+
+	public sealed record TupleVariantInt([property: JsonPropertyName("content")] int Content) : EnumWithManyVariants;
+
+	public sealed record AnotherUnitVariant() : EnumWithManyVariants;
+
+	public sealed record AnotherAnonVariant([property: JsonPropertyName("content")] EnumWithManyVariantsAnotherAnonVariantInner Content) : EnumWithManyVariants;
+}
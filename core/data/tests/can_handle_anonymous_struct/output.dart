@@ -0,0 +1,259 @@
+/// Generated type representing the anonymous struct variant `Us` of the `AutofilledBy` Rust enum
+class AutofilledByUsInner {
+  const AutofilledByUsInner({
+    required this.uuid,
+  });
+
+  factory AutofilledByUsInner.fromJson(Map<String, dynamic> json) =>
+      AutofilledByUsInner(
+        uuid: json['uuid'] as String,
+      );
+
+  /// The UUID for the fill
+  final String uuid;
+
+  Map<String, dynamic> toJson() => {
+        'uuid': uuid,
+      };
+
+  AutofilledByUsInner copyWith({
+    String? uuid,
+  }) =>
+      AutofilledByUsInner(
+        uuid: uuid ?? this.uuid,
+      );
+}
+
+/// Generated type representing the anonymous struct variant `SomethingElse` of the `AutofilledBy` Rust enum
+class AutofilledBySomethingElseInner {
+  const AutofilledBySomethingElseInner({
+    required this.uuid,
+    required this.thing,
+  });
+
+  factory AutofilledBySomethingElseInner.fromJson(Map<String, dynamic> json) =>
+      AutofilledBySomethingElseInner(
+        uuid: json['uuid'] as String,
+        thing: (json['thing'] as num).toInt(),
+      );
+
+  /// The UUID for the fill
+  final String uuid;
+
+  /// Some other thing
+  final int thing;
+
+  Map<String, dynamic> toJson() => {
+        'uuid': uuid,
+        'thing': thing,
+      };
+
+  AutofilledBySomethingElseInner copyWith({
+    String? uuid,
+    int? thing,
+  }) =>
+      AutofilledBySomethingElseInner(
+        uuid: uuid ?? this.uuid,
+        thing: thing ?? this.thing,
+      );
+}
+
+/// Enum keeping track of who autofilled a field
+sealed class AutofilledBy {
+  const AutofilledBy();
+
+  factory AutofilledBy.fromJson(Map<String, dynamic> json) =>
+      switch (json['type']) {
+        'Us' => AutofilledByUs(AutofilledByUsInner.fromJson(json['content'])),
+        'SomethingElse' => AutofilledBySomethingElse(AutofilledBySomethingElseInner.fromJson(json['content'])),
+        final tag => throw ArgumentError.value(tag, 'type', 'Unknown AutofilledBy variant'),
+      };
+
+  Map<String, dynamic> toJson();
+}
+
+/// This field was autofilled by us
+class AutofilledByUs extends AutofilledBy {
+  const AutofilledByUs(this.content);
+
+  final AutofilledByUsInner content;
+
+  AutofilledByUs copyWith({AutofilledByUsInner? content}) =>
+      AutofilledByUs(content ?? this.content);
+
+  @override
+  Map<String, dynamic> toJson() => {
+        'type': 'Us',
+        'content': content.toJson(),
+      };
+}
+
+/// Something else autofilled this field
+class AutofilledBySomethingElse extends AutofilledBy {
+  const AutofilledBySomethingElse(this.content);
+
+  final AutofilledBySomethingElseInner content;
+
+  AutofilledBySomethingElse copyWith({AutofilledBySomethingElseInner? content}) =>
+      AutofilledBySomethingElse(content ?? this.content);
+
+  @override
+  Map<String, dynamic> toJson() => {
+        'type': 'SomethingElse',
+        'content': content.toJson(),
+      };
+}
+
+/// Generated type representing the anonymous struct variant `AnonVariant` of the `EnumWithManyVariants` Rust enum
+class EnumWithManyVariantsAnonVariantInner {
+  const EnumWithManyVariantsAnonVariantInner({
+    required this.uuid,
+  });
+
+  factory EnumWithManyVariantsAnonVariantInner.fromJson(Map<String, dynamic> json) =>
+      EnumWithManyVariantsAnonVariantInner(
+        uuid: json['uuid'] as String,
+      );
+
+  final String uuid;
+
+  Map<String, dynamic> toJson() => {
+        'uuid': uuid,
+      };
+
+  EnumWithManyVariantsAnonVariantInner copyWith({
+    String? uuid,
+  }) =>
+      EnumWithManyVariantsAnonVariantInner(
+        uuid: uuid ?? this.uuid,
+      );
+}
+
+/// Generated type representing the anonymous struct variant `AnotherAnonVariant` of the `EnumWithManyVariants` Rust enum
+class EnumWithManyVariantsAnotherAnonVariantInner {
+  const EnumWithManyVariantsAnotherAnonVariantInner({
+    required this.uuid,
+    required this.thing,
+  });
+
+  factory EnumWithManyVariantsAnotherAnonVariantInner.fromJson(Map<String, dynamic> json) =>
+      EnumWithManyVariantsAnotherAnonVariantInner(
+        uuid: json['uuid'] as String,
+        thing: (json['thing'] as num).toInt(),
+      );
+
+  final String uuid;
+
+  final int thing;
+
+  Map<String, dynamic> toJson() => {
+        'uuid': uuid,
+        'thing': thing,
+      };
+
+  EnumWithManyVariantsAnotherAnonVariantInner copyWith({
+    String? uuid,
+    int? thing,
+  }) =>
+      EnumWithManyVariantsAnotherAnonVariantInner(
+        uuid: uuid ?? this.uuid,
+        thing: thing ?? this.thing,
+      );
+}
+
+/// This is a comment (yareek sameek wuz here)
+sealed class EnumWithManyVariants {
+  const EnumWithManyVariants();
+
+  factory EnumWithManyVariants.fromJson(Map<String, dynamic> json) =>
+      switch (json['type']) {
+        'UnitVariant' => EnumWithManyVariantsUnitVariant(),
+        'TupleVariantString' => EnumWithManyVariantsTupleVariantString(json['content'] as String),
+        'AnonVariant' => EnumWithManyVariantsAnonVariant(EnumWithManyVariantsAnonVariantInner.fromJson(json['content'])),
+        'TupleVariantInt' => EnumWithManyVariantsTupleVariantInt((json['content'] as num).toInt()),
+        'AnotherUnitVariant' => EnumWithManyVariantsAnotherUnitVariant(),
+        'AnotherAnonVariant' => EnumWithManyVariantsAnotherAnonVariant(EnumWithManyVariantsAnotherAnonVariantInner.fromJson(json['content'])),
+        final tag => throw ArgumentError.value(tag, 'type', 'Unknown EnumWithManyVariants variant'),
+      };
+
+  Map<String, dynamic> toJson();
+}
+
+class EnumWithManyVariantsUnitVariant extends EnumWithManyVariants {
+  const EnumWithManyVariantsUnitVariant();
+
+  @override
+  Map<String, dynamic> toJson() => {
+        'type': 'UnitVariant',
+      };
+}
+
+class EnumWithManyVariantsTupleVariantString extends EnumWithManyVariants {
+  const EnumWithManyVariantsTupleVariantString(this.content);
+
+  final String content;
+
+  EnumWithManyVariantsTupleVariantString copyWith({String? content}) =>
+      EnumWithManyVariantsTupleVariantString(content ?? this.content);
+
+  @override
+  Map<String, dynamic> toJson() => {
+        'type': 'TupleVariantString',
+        'content': content,
+      };
+}
+
+class EnumWithManyVariantsAnonVariant extends EnumWithManyVariants {
+  const EnumWithManyVariantsAnonVariant(this.content);
+
+  final EnumWithManyVariantsAnonVariantInner content;
+
+  EnumWithManyVariantsAnonVariant copyWith({EnumWithManyVariantsAnonVariantInner? content}) =>
+      EnumWithManyVariantsAnonVariant(content ?? this.content);
+
+  @override
+  Map<String, dynamic> toJson() => {
+        'type': 'AnonVariant',
+        'content': content.toJson(),
+      };
+}
+
+class EnumWithManyVariantsTupleVariantInt extends EnumWithManyVariants {
+  const EnumWithManyVariantsTupleVariantInt(this.content);
+
+  final int content;
+
+  EnumWithManyVariantsTupleVariantInt copyWith({int? content}) =>
+      EnumWithManyVariantsTupleVariantInt(content ?? this.content);
+
+  @override
+  Map<String, dynamic> toJson() => {
+        'type': 'TupleVariantInt',
+        'content': content,
+      };
+}
+
+class EnumWithManyVariantsAnotherUnitVariant extends EnumWithManyVariants {
+  const EnumWithManyVariantsAnotherUnitVariant();
+
+  @override
+  Map<String, dynamic> toJson() => {
+        'type': 'AnotherUnitVariant',
+      };
+}
+
+class EnumWithManyVariantsAnotherAnonVariant extends EnumWithManyVariants {
+  const EnumWithManyVariantsAnotherAnonVariant(this.content);
+
+  final EnumWithManyVariantsAnotherAnonVariantInner content;
+
+  EnumWithManyVariantsAnotherAnonVariant copyWith({EnumWithManyVariantsAnotherAnonVariantInner? content}) =>
+      EnumWithManyVariantsAnotherAnonVariant(content ?? this.content);
+
+  @override
+  Map<String, dynamic> toJson() => {
+        'type': 'AnotherAnonVariant',
+        'content': content.toJson(),
+      };
+}
+
@@ -0,0 +1,88 @@
+package com.agilebits.onepassword;
+
+import com.fasterxml.jackson.annotation.*;
+import java.util.List;
+import java.util.Map;
+
+public final class file_name {
+	private file_name() {}
+
+	/**
+	 * Generated type representing the anonymous struct variant `Us` of the `AutofilledBy` Rust enum
+	 */
+	public record AutofilledByUsInner(
+		/**
+		 * The UUID for the fill
+		 */
+		@JsonProperty("uuid") String uuid
+	) {}
+
+	/**
+	 * Generated type representing the anonymous struct variant `SomethingElse` of the `AutofilledBy` Rust enum
+	 */
+	public record AutofilledBySomethingElseInner(
+		/**
+		 * The UUID for the fill
+		 */
+		@JsonProperty("uuid") String uuid,
+		/**
+		 * Some other thing
+		 */
+		@JsonProperty("thing") int thing
+	) {}
+
+	/**
+	 * Enum keeping track of who autofilled a field
+	 */
+	@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "type")
+	@JsonSubTypes({
+		@JsonSubTypes.Type(value = AutofilledBy.Us.class, name = "Us"),
+		@JsonSubTypes.Type(value = AutofilledBy.SomethingElse.class, name = "SomethingElse"),
+	})
+	public sealed interface AutofilledBy {
+		/**
+		 * This field was autofilled by us
+		 */
+		record Us(@JsonProperty("content") AutofilledByUsInner content) implements AutofilledBy {}
+		/**
+		 * Something else autofilled this field
+		 */
+		record SomethingElse(@JsonProperty("content") AutofilledBySomethingElseInner content) implements AutofilledBy {}
+	}
+
+	/**
+	 * Generated type representing the anonymous struct variant `AnonVariant` of the `EnumWithManyVariants` Rust enum
+	 */
+	public record EnumWithManyVariantsAnonVariantInner(
+		@JsonProperty("uuid") String uuid
+	) {}
+
+	/**
+	 * Generated type representing the anonymous struct variant `AnotherAnonVariant` of the `EnumWithManyVariants` Rust enum
+	 */
+	public record EnumWithManyVariantsAnotherAnonVariantInner(
+		@JsonProperty("uuid") String uuid,
+		@JsonProperty("thing") int thing
+	) {}
+
+	/**
+	 * This is a comment (yareek sameek wuz here)
+	 */
+	@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "type")
+	@JsonSubTypes({
+		@JsonSubTypes.Type(value = EnumWithManyVariants.UnitVariant.class, name = "UnitVariant"),
+		@JsonSubTypes.Type(value = EnumWithManyVariants.TupleVariantString.class, name = "TupleVariantString"),
+		@JsonSubTypes.Type(value = EnumWithManyVariants.AnonVariant.class, name = "AnonVariant"),
+		@JsonSubTypes.Type(value = EnumWithManyVariants.TupleVariantInt.class, name = "TupleVariantInt"),
+		@JsonSubTypes.Type(value = EnumWithManyVariants.AnotherUnitVariant.class, name = "AnotherUnitVariant"),
+		@JsonSubTypes.Type(value = EnumWithManyVariants.AnotherAnonVariant.class, name = "AnotherAnonVariant"),
+	})
+	public sealed interface EnumWithManyVariants {
+		record UnitVariant() implements EnumWithManyVariants {}
+		record TupleVariantString(@JsonProperty("content") String content) implements EnumWithManyVariants {}
+		record AnonVariant(@JsonProperty("content") EnumWithManyVariantsAnonVariantInner content) implements EnumWithManyVariants {}
+		record TupleVariantInt(@JsonProperty("content") Integer content) implements EnumWithManyVariants {}
+		record AnotherUnitVariant() implements EnumWithManyVariants {}
+		record AnotherAnonVariant(@JsonProperty("content") EnumWithManyVariantsAnotherAnonVariantInner content) implements EnumWithManyVariants {}
+	}
+}
@@ -0,0 +1,35 @@
+import { z } from "zod";
+
+/** Enum keeping track of who autofilled a field */
+export const AutofilledBySchema = z.discriminatedUnion("type", [
+	/** This field was autofilled by us */
+	z.object({ type: z.literal("Us"), content: z.object({
+		/** The UUID for the fill */
+		uuid: z.string(),
+	}) }),
+	/** Something else autofilled this field */
+	z.object({ type: z.literal("SomethingElse"), content: z.object({
+		/** The UUID for the fill */
+		uuid: z.string(),
+		/** Some other thing */
+		thing: z.number().int().min(-2147483648).max(2147483647),
+	}) }),
+]);
+export type AutofilledBy = z.infer<typeof AutofilledBySchema>;
+
+/** This is a comment (yareek sameek wuz here) */
+export const EnumWithManyVariantsSchema = z.discriminatedUnion("type", [
+	z.object({ type: z.literal("UnitVariant") }),
+	z.object({ type: z.literal("TupleVariantString"), content: z.string() }),
+	z.object({ type: z.literal("AnonVariant"), content: z.object({
+		uuid: z.string(),
+	}) }),
+	z.object({ type: z.literal("TupleVariantInt"), content: z.number().int().min(-2147483648).max(2147483647) }),
+	z.object({ type: z.literal("AnotherUnitVariant") }),
+	z.object({ type: z.literal("AnotherAnonVariant"), content: z.object({
+		uuid: z.string(),
+		thing: z.number().int().min(-2147483648).max(2147483647),
+	}) }),
+]);
+export type EnumWithManyVariants = z.infer<typeof EnumWithManyVariantsSchema>;
+
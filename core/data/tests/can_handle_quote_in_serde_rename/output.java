@@ -0,0 +1,17 @@
+package com.agilebits.onepassword;
+
+import com.fasterxml.jackson.annotation.*;
+import java.util.List;
+import java.util.Map;
+
+public final class file_name {
+	private file_name() {}
+
+	/**
+	 * This is a comment.
+	 */
+	public enum Colors {
+		@JsonProperty("Green\"")
+		Green,
+	}
+}
@@ -0,0 +1,9 @@
+import { z } from "zod";
+
+/** This is a comment. */
+export enum Colors {
+	Green = "Green\"",
+}
+
+export const ColorsSchema = z.nativeEnum(Colors);
+
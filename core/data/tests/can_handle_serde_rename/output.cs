@@ -0,0 +1,33 @@
+#nullable enable
+
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace AgileBits.OnePassword;
+
+public record OtherType;
+
+/// <summary>
+/// This is a comment.
+/// </summary>
+public record Person
+{
+	[JsonPropertyName("name")]
+	public required string Name { get; init; }
+
+	[JsonPropertyName("age")]
+	public required byte Age { get; init; }
+
+	[JsonPropertyName("extraSpecialFieldOne")]
+	public required int ExtraSpecialFieldOne { get; init; }
+
+	[JsonPropertyName("extraSpecialFieldTwo")]
+	public List<string>? ExtraSpecialFieldTwo { get; init; }
+
+	[JsonPropertyName("nonStandardDataType")]
+	public required OtherType NonStandardDataType { get; init; }
+
+	[JsonPropertyName("nonStandardDataTypeInArray")]
+	public List<OtherType>? NonStandardDataTypeInArray { get; init; }
+}
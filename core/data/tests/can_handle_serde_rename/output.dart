@@ -0,0 +1,72 @@
+class OtherType {
+  const OtherType();
+
+  factory OtherType.fromJson(Map<String, dynamic> json) =>
+      const OtherType();
+
+  Map<String, dynamic> toJson() => {};
+}
+
+/// This is a comment.
+class Person {
+  const Person({
+    required this.name_,
+    required this.age,
+    required this.extraSpecialFieldOne,
+    this.extraSpecialFieldTwo,
+    required this.nonStandardDataType,
+    this.nonStandardDataTypeInArray,
+  });
+
+  factory Person.fromJson(Map<String, dynamic> json) =>
+      Person(
+        name_: json['name'] as String,
+        age: (json['age'] as num).toInt(),
+        extraSpecialFieldOne: (json['extraSpecialFieldOne'] as num).toInt(),
+        extraSpecialFieldTwo: json['extraSpecialFieldTwo'] == null ? null : (json['extraSpecialFieldTwo'] as List<dynamic>).map((v0) => v0 as String).toList(),
+        nonStandardDataType: OtherType.fromJson(json['nonStandardDataType']),
+        nonStandardDataTypeInArray: json['nonStandardDataTypeInArray'] == null ? null : (json['nonStandardDataTypeInArray'] as List<dynamic>).map((v0) => OtherType.fromJson(v0)).toList(),
+      );
+
+  final String name_;
+
+  final int age;
+
+  final int extraSpecialFieldOne;
+
+  final List<String>? extraSpecialFieldTwo;
+
+  final OtherType nonStandardDataType;
+
+  final List<OtherType>? nonStandardDataTypeInArray;
+
+  Map<String, dynamic> toJson() => {
+        'name': name_,
+        'age': age,
+        'extraSpecialFieldOne': extraSpecialFieldOne,
+        'extraSpecialFieldTwo': extraSpecialFieldTwo,
+        'nonStandardDataType': nonStandardDataType.toJson(),
+        'nonStandardDataTypeInArray': nonStandardDataTypeInArray == null ? null : nonStandardDataTypeInArray!.map((v0) => v0.toJson()).toList(),
+      };
+
+  Person copyWith({
+    String? name_,
+    int? age,
+    int? extraSpecialFieldOne,
+    Object? extraSpecialFieldTwo = _unset,
+    OtherType? nonStandardDataType,
+    Object? nonStandardDataTypeInArray = _unset,
+  }) =>
+      Person(
+        name_: name_ ?? this.name_,
+        age: age ?? this.age,
+        extraSpecialFieldOne: extraSpecialFieldOne ?? this.extraSpecialFieldOne,
+        extraSpecialFieldTwo: identical(extraSpecialFieldTwo, _unset) ? this.extraSpecialFieldTwo : extraSpecialFieldTwo as List<String>?,
+        nonStandardDataType: nonStandardDataType ?? this.nonStandardDataType,
+        nonStandardDataTypeInArray: identical(nonStandardDataTypeInArray, _unset) ? this.nonStandardDataTypeInArray : nonStandardDataTypeInArray as List<OtherType>?,
+      );
+}
+
+/// The default of nullable `copyWith` parameters, which tells an omitted
+/// argument apart from `null`.
+const Object _unset = Object();
@@ -0,0 +1,23 @@
+package com.agilebits.onepassword;
+
+import com.fasterxml.jackson.annotation.*;
+import java.util.List;
+import java.util.Map;
+
+public final class file_name {
+	private file_name() {}
+
+	public record OtherType() {}
+
+	/**
+	 * This is a comment.
+	 */
+	public record Person(
+		@JsonProperty("name") String name,
+		@JsonProperty("age") short age,
+		@JsonProperty("extraSpecialFieldOne") int extraSpecialFieldOne,
+		@JsonProperty("extraSpecialFieldTwo") List<String> extraSpecialFieldTwo,
+		@JsonProperty("nonStandardDataType") OtherType nonStandardDataType,
+		@JsonProperty("nonStandardDataTypeInArray") List<OtherType> nonStandardDataTypeInArray
+	) {}
+}
@@ -0,0 +1,17 @@
+import { z } from "zod";
+
+export const OtherTypeSchema = z.object({
+});
+export type OtherType = z.infer<typeof OtherTypeSchema>;
+
+/** This is a comment. */
+export const PersonSchema = z.object({
+	name: z.string(),
+	age: z.number().int().min(0).max(255),
+	extraSpecialFieldOne: z.number().int().min(-2147483648).max(2147483647),
+	extraSpecialFieldTwo: z.array(z.string()).nullish().transform((value) => value ?? undefined),
+	nonStandardDataType: OtherTypeSchema,
+	nonStandardDataTypeInArray: z.array(OtherTypeSchema).nullish().transform((value) => value ?? undefined),
+});
+export type Person = z.infer<typeof PersonSchema>;
+
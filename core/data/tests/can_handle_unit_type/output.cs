@@ -0,0 +1,28 @@
+#nullable enable
+
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace AgileBits.OnePassword;
+
+/// <summary>
+/// This struct has a unit field
+/// </summary>
+public record StructHasVoidType
+{
+	[JsonPropertyName("thisIsAUnit")]
+	public object? ThisIsAUnit { get; init; }
+}
+
+/// <summary>
+/// This enum has a variant associated with unit data
+/// </summary>
+[JsonPolymorphic(TypeDiscriminatorPropertyName = "type")]
+[JsonDerivedType(typeof(EnumHasVoidType.HasAUnit), "hasAUnit")]
+public abstract record EnumHasVoidType
+{
+	private EnumHasVoidType() {}
+
+	public sealed record HasAUnit([property: JsonPropertyName("content")] object? Content) : EnumHasVoidType;
+}
@@ -0,0 +1,56 @@
+/// This struct has a unit field
+class StructHasVoidType {
+  const StructHasVoidType({
+    required this.thisIsAUnit,
+  });
+
+  factory StructHasVoidType.fromJson(Map<String, dynamic> json) =>
+      StructHasVoidType(
+        thisIsAUnit: null,
+      );
+
+  final Null thisIsAUnit;
+
+  Map<String, dynamic> toJson() => {
+        'thisIsAUnit': thisIsAUnit,
+      };
+
+  StructHasVoidType copyWith({
+    Object? thisIsAUnit = _unset,
+  }) =>
+      StructHasVoidType(
+        thisIsAUnit: identical(thisIsAUnit, _unset) ? this.thisIsAUnit : thisIsAUnit as Null,
+      );
+}
+
+/// This enum has a variant associated with unit data
+sealed class EnumHasVoidType {
+  const EnumHasVoidType();
+
+  factory EnumHasVoidType.fromJson(Map<String, dynamic> json) =>
+      switch (json['type']) {
+        'hasAUnit' => EnumHasVoidTypeHasAUnit(null),
+        final tag => throw ArgumentError.value(tag, 'type', 'Unknown EnumHasVoidType variant'),
+      };
+
+  Map<String, dynamic> toJson();
+}
+
+class EnumHasVoidTypeHasAUnit extends EnumHasVoidType {
+  const EnumHasVoidTypeHasAUnit(this.content);
+
+  final Null content;
+
+  EnumHasVoidTypeHasAUnit copyWith({Null content}) =>
+      EnumHasVoidTypeHasAUnit(content ?? this.content);
+
+  @override
+  Map<String, dynamic> toJson() => {
+        'type': 'hasAUnit',
+        'content': content,
+      };
+}
+
+/// The default of nullable `copyWith` parameters, which tells an omitted
+/// argument apart from `null`.
+const Object _unset = Object();
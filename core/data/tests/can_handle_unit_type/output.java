@@ -0,0 +1,27 @@
+package com.agilebits.onepassword;
+
+import com.fasterxml.jackson.annotation.*;
+import java.util.List;
+import java.util.Map;
+
+public final class file_name {
+	private file_name() {}
+
+	/**
+	 * This struct has a unit field
+	 */
+	public record StructHasVoidType(
+		@JsonProperty("thisIsAUnit") Void thisIsAUnit
+	) {}
+
+	/**
+	 * This enum has a variant associated with unit data
+	 */
+	@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "type")
+	@JsonSubTypes({
+		@JsonSubTypes.Type(value = EnumHasVoidType.HasAUnit.class, name = "hasAUnit"),
+	})
+	public sealed interface EnumHasVoidType {
+		record HasAUnit(@JsonProperty("content") Void content) implements EnumHasVoidType {}
+	}
+}
@@ -0,0 +1,14 @@
+import { z } from "zod";
+
+/** This struct has a unit field */
+export const StructHasVoidTypeSchema = z.object({
+	thisIsAUnit: z.null(),
+});
+export type StructHasVoidType = z.infer<typeof StructHasVoidTypeSchema>;
+
+/** This enum has a variant associated with unit data */
+export const EnumHasVoidTypeSchema = z.discriminatedUnion("type", [
+	z.object({ type: z.literal("hasAUnit"), content: z.null() }),
+]);
+export type EnumHasVoidType = z.infer<typeof EnumHasVoidTypeSchema>;
+
@@ -0,0 +1,10 @@
+#[typeshare]
+pub struct Event {
+    pub id: String,
+    pub at: DateTime,
+    pub amounts: Vec<Decimal>,
+    pub link: Option<Url>,
+}
+
+#[typeshare]
+pub type Schedule = HashMap<String, DateTime>;
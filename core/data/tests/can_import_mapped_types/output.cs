@@ -0,0 +1,35 @@
+#nullable enable
+
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+using System;
+
+namespace AgileBits.OnePassword;
+
+[JsonConverter(typeof(ScheduleJsonConverter))]
+public sealed record Schedule(Dictionary<string, DateTimeOffset> Value);
+
+public sealed class ScheduleJsonConverter : JsonConverter<Schedule>
+{
+	public override Schedule Read(ref Utf8JsonReader reader, System.Type typeToConvert, JsonSerializerOptions options) =>
+		new(JsonSerializer.Deserialize<Dictionary<string, DateTimeOffset>>(ref reader, options)!);
+
+	public override void Write(Utf8JsonWriter writer, Schedule value, JsonSerializerOptions options) =>
+		JsonSerializer.Serialize(writer, value.Value, options);
+}
+
+public record Event
+{
+	[JsonPropertyName("id")]
+	public required string Id { get; init; }
+
+	[JsonPropertyName("at")]
+	public required DateTimeOffset At { get; init; }
+
+	[JsonPropertyName("amounts")]
+	public required List<decimal> Amounts { get; init; }
+
+	[JsonPropertyName("link")]
+	public Uri? Link { get; init; }
+}
@@ -0,0 +1,58 @@
+import 'package:decimal/decimal.dart';
+
+typedef Schedule = Map<String, DateTime>;
+
+Schedule scheduleFromJson(dynamic json) =>
+    (json as Map<String, dynamic>).map((k0, v0) => MapEntry(k0, DateTime.parse(v0)));
+
+Object? scheduleToJson(Schedule value) =>
+    value.map((k0, v0) => MapEntry(k0, dateTimeToJson(v0)));
+
+class Event {
+  const Event({
+    required this.id,
+    required this.at,
+    required this.amounts,
+    this.link,
+  });
+
+  factory Event.fromJson(Map<String, dynamic> json) =>
+      Event(
+        id: json['id'] as String,
+        at: DateTime.parse(json['at']),
+        amounts: (json['amounts'] as List<dynamic>).map((v0) => Decimal.fromJson(v0)).toList(),
+        link: json['link'] == null ? null : Uri.parse(json['link']),
+      );
+
+  final String id;
+
+  final DateTime at;
+
+  final List<Decimal> amounts;
+
+  final Uri? link;
+
+  Map<String, dynamic> toJson() => {
+        'id': id,
+        'at': dateTimeToJson(at),
+        'amounts': amounts.map((v0) => decimalToJson(v0)).toList(),
+        'link': link == null ? null : uriToJson(link!),
+      };
+
+  Event copyWith({
+    String? id,
+    DateTime? at,
+    List<Decimal>? amounts,
+    Object? link = _unset,
+  }) =>
+      Event(
+        id: id ?? this.id,
+        at: at ?? this.at,
+        amounts: amounts ?? this.amounts,
+        link: identical(link, _unset) ? this.link : link as Uri?,
+      );
+}
+
+/// The default of nullable `copyWith` parameters, which tells an omitted
+/// argument apart from `null`.
+const Object _unset = Object();
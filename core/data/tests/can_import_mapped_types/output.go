@@ -0,0 +1,15 @@
+package proto
+
+import "encoding/json"
+
+import "github.com/shopspring/decimal"
+import "time"
+
+type Schedule map[string]time.Time
+
+type Event struct {
+	Id string `json:"id"`
+	At time.Time `json:"at"`
+	Amounts []decimal.Decimal `json:"amounts"`
+	Link *string `json:"link,omitempty"`
+}
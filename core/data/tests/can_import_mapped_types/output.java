@@ -0,0 +1,24 @@
+package com.agilebits.onepassword;
+
+import com.fasterxml.jackson.annotation.*;
+import java.util.List;
+import java.util.Map;
+import java.math.BigDecimal;
+import java.net.URI;
+import java.time.Instant;
+
+public final class file_name {
+	private file_name() {}
+
+	public record Schedule(@JsonValue Map<String, Instant> value) {
+		@JsonCreator(mode = JsonCreator.Mode.DELEGATING)
+		public Schedule {}
+	}
+
+	public record Event(
+		@JsonProperty("id") String id,
+		@JsonProperty("at") Instant at,
+		@JsonProperty("amounts") List<BigDecimal> amounts,
+		@JsonProperty("link") URI link
+	) {}
+}
@@ -0,0 +1,18 @@
+package com.agilebits.onepassword
+
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerialName
+
+import java.math.BigDecimal
+import kotlinx.datetime.Instant
+
+typealias Schedule = HashMap<String, Instant>
+
+@Serializable
+data class Event (
+	val id: String,
+	val at: Instant,
+	val amounts: List<BigDecimal>,
+	val link: String? = null
+)
+
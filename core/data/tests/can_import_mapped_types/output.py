@@ -0,0 +1,16 @@
+from __future__ import annotations
+
+from datetime import datetime
+from decimal import Decimal
+from pydantic import AnyUrl, BaseModel, Field
+from typing import Dict, List, Optional
+
+
+Schedule = Dict[str, datetime]
+
+class Event(BaseModel):
+    id: str
+    at: datetime
+    amounts: List[Decimal]
+    link: Optional[AnyUrl] = Field(default=None)
+
@@ -0,0 +1,19 @@
+package com.agilebits
+
+import java.time.Instant
+
+package object onepassword {
+
+type Schedule = Map[String, Instant]
+
+}
+package onepassword {
+
+case class Event (
+	id: String,
+	at: Instant,
+	amounts: Vector[BigDecimal],
+	link: Option[String] = None
+)
+
+}
@@ -0,0 +1,18 @@
+import Foundation
+import BigDecimal
+
+public typealias Schedule = [String: Date]
+
+public struct Event: Codable {
+	public let id: String
+	public let at: Date
+	public let amounts: [BigDecimal]
+	public let link: URL?
+
+	public init(id: String, at: Date, amounts: [BigDecimal], link: URL?) {
+		self.id = id
+		self.at = at
+		self.amounts = amounts
+		self.link = link
+	}
+}
@@ -0,0 +1,11 @@
+import type { Decimal } from "decimal.js";
+
+export type Schedule = Record<string, Date>;
+
+export interface Event {
+	id: string;
+	at: Date;
+	amounts: Decimal[];
+	link?: URL;
+}
+
@@ -0,0 +1,15 @@
+import { z } from "zod";
+
+import type { Decimal } from "decimal.js";
+
+export const ScheduleSchema = z.record(z.string(), z.coerce.date());
+export type Schedule = z.infer<typeof ScheduleSchema>;
+
+export const EventSchema = z.object({
+	id: z.string(),
+	at: z.coerce.date(),
+	amounts: z.array(z.unknown()),
+	link: z.string().nullish().transform((value) => value ?? undefined),
+});
+export type Event = z.infer<typeof EventSchema>;
+
@@ -0,0 +1,11 @@
+#[typeshare]
+pub struct Product {
+    pub name: String,
+}
+
+#[typeshare]
+pub struct Catalog {
+    pub products: IndexMap<String, Product>,
+    pub tags: IndexSet<String>,
+    pub sections: Option<IndexMap<String, IndexSet<u32>>>,
+}
@@ -0,0 +1,12 @@
+package proto
+
+import "encoding/json"
+
+type Catalog struct {
+	Products map[string]Product `json:"products"`
+	Tags []string `json:"tags"`
+	Sections *map[string][]uint32 `json:"sections,omitempty"`
+}
+type Product struct {
+	Name string `json:"name"`
+}
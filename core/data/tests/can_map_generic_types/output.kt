@@ -0,0 +1,12 @@
+@Serializable
+data class Catalog (
+	val products: Map<String, Product>,
+	val tags: Set<String>,
+	val sections: Map<String, Set<UInt>>? = null
+)
+
+@Serializable
+data class Product (
+	val name: String
+)
+
@@ -0,0 +1,14 @@
+from __future__ import annotations
+
+from pydantic import BaseModel, Field
+from typing import Optional
+
+
+class Catalog(BaseModel):
+    products: Dict[str, Product]
+    tags: Set[str]
+    sections: Optional[Dict[str, Set[int]]] = Field(default=None)
+
+class Product(BaseModel):
+    name: str
+
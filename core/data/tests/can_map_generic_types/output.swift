@@ -0,0 +1,21 @@
+import Foundation
+
+public struct Catalog: Codable {
+	public let products: [String: Product]
+	public let tags: Set<String>
+	public let sections: [String: Set<UInt32>]?
+
+	public init(products: [String: Product], tags: Set<String>, sections: [String: Set<UInt32>]?) {
+		self.products = products
+		self.tags = tags
+		self.sections = sections
+	}
+}
+
+public struct Product: Codable {
+	public let name: String
+
+	public init(name: String) {
+		self.name = name
+	}
+}
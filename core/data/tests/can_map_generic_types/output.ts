@@ -0,0 +1,10 @@
+export interface Catalog {
+	products: Map<string, Product>;
+	tags: Set<string>;
+	sections?: Map<string, Set<number>>;
+}
+
+export interface Product {
+	name: string;
+}
+
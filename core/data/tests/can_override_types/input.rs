@@ -6,7 +6,10 @@ struct OverrideStruct {
         swift(type = "Int"),
         typescript(readonly, type = "any | undefined"),
         kotlin(type = "Int"), go(type = "uint"),
-        scala(type = "Short")
+        scala(type = "Short"), java(type = "Integer"),
+        csharp(type = "int"), dart(type = "int"),
+        jsonschema(type = "integer"), openapi(type = "integer"),
+        protobuf(type = "int32"), graphql(type = "Int")
     )]
     field_to_override: String,
 }
@@ -22,7 +25,10 @@ enum OverrideEnum {
             swift(type = "Int"),
             typescript(readonly, type = "any | undefined"),
             kotlin(type = "Int"), go(type = "uint"),
-            scala(type = "Short")
+            scala(type = "Short"), java(type = "Integer"),
+            csharp(type = "int"), dart(type = "int"),
+            jsonschema(type = "integer"), openapi(type = "integer"),
+            protobuf(type = "int32"), graphql(type = "Int")
         )]
         field_to_override: String
     }
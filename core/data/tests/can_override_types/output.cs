@@ -0,0 +1,37 @@
+#nullable enable
+
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace AgileBits.OnePassword;
+
+public record OverrideStruct
+{
+	[JsonPropertyName("fieldToOverride")]
+	public required int FieldToOverride { get; init; }
+}
+
+/// <summary>
+/// Generated type representing the anonymous struct variant `AnonymousStructVariant` of the `OverrideEnum` Rust enum
+/// </summary>
+public record OverrideEnumAnonymousStructVariantInner
+{
+	[JsonPropertyName("fieldToOverride")]
+	public required int FieldToOverride { get; init; }
+}
+
+[JsonPolymorphic(TypeDiscriminatorPropertyName = "type")]
+[JsonDerivedType(typeof(OverrideEnum.UnitVariant), "UnitVariant")]
+[JsonDerivedType(typeof(OverrideEnum.TupleVariant), "TupleVariant")]
+[JsonDerivedType(typeof(OverrideEnum.AnonymousStructVariant), "AnonymousStructVariant")]
+public abstract record OverrideEnum
+{
+	private OverrideEnum() {}
+
+	public sealed record UnitVariant() : OverrideEnum;
+
+	public sealed record TupleVariant([property: JsonPropertyName("content")] string Content) : OverrideEnum;
+
+	public sealed record AnonymousStructVariant([property: JsonPropertyName("content")] OverrideEnumAnonymousStructVariantInner Content) : OverrideEnum;
+}
@@ -0,0 +1,102 @@
+class OverrideStruct {
+  const OverrideStruct({
+    required this.fieldToOverride,
+  });
+
+  factory OverrideStruct.fromJson(Map<String, dynamic> json) =>
+      OverrideStruct(
+        fieldToOverride: json['fieldToOverride'] as int,
+      );
+
+  final int fieldToOverride;
+
+  Map<String, dynamic> toJson() => {
+        'fieldToOverride': fieldToOverride,
+      };
+
+  OverrideStruct copyWith({
+    int? fieldToOverride,
+  }) =>
+      OverrideStruct(
+        fieldToOverride: fieldToOverride ?? this.fieldToOverride,
+      );
+}
+
+/// Generated type representing the anonymous struct variant `AnonymousStructVariant` of the `OverrideEnum` Rust enum
+class OverrideEnumAnonymousStructVariantInner {
+  const OverrideEnumAnonymousStructVariantInner({
+    required this.fieldToOverride,
+  });
+
+  factory OverrideEnumAnonymousStructVariantInner.fromJson(Map<String, dynamic> json) =>
+      OverrideEnumAnonymousStructVariantInner(
+        fieldToOverride: json['fieldToOverride'] as int,
+      );
+
+  final int fieldToOverride;
+
+  Map<String, dynamic> toJson() => {
+        'fieldToOverride': fieldToOverride,
+      };
+
+  OverrideEnumAnonymousStructVariantInner copyWith({
+    int? fieldToOverride,
+  }) =>
+      OverrideEnumAnonymousStructVariantInner(
+        fieldToOverride: fieldToOverride ?? this.fieldToOverride,
+      );
+}
+
+sealed class OverrideEnum {
+  const OverrideEnum();
+
+  factory OverrideEnum.fromJson(Map<String, dynamic> json) =>
+      switch (json['type']) {
+        'UnitVariant' => OverrideEnumUnitVariant(),
+        'TupleVariant' => OverrideEnumTupleVariant(json['content'] as String),
+        'AnonymousStructVariant' => OverrideEnumAnonymousStructVariant(OverrideEnumAnonymousStructVariantInner.fromJson(json['content'])),
+        final tag => throw ArgumentError.value(tag, 'type', 'Unknown OverrideEnum variant'),
+      };
+
+  Map<String, dynamic> toJson();
+}
+
+class OverrideEnumUnitVariant extends OverrideEnum {
+  const OverrideEnumUnitVariant();
+
+  @override
+  Map<String, dynamic> toJson() => {
+        'type': 'UnitVariant',
+      };
+}
+
+class OverrideEnumTupleVariant extends OverrideEnum {
+  const OverrideEnumTupleVariant(this.content);
+
+  final String content;
+
+  OverrideEnumTupleVariant copyWith({String? content}) =>
+      OverrideEnumTupleVariant(content ?? this.content);
+
+  @override
+  Map<String, dynamic> toJson() => {
+        'type': 'TupleVariant',
+        'content': content,
+      };
+}
+
+class OverrideEnumAnonymousStructVariant extends OverrideEnum {
+  const OverrideEnumAnonymousStructVariant(this.content);
+
+  final OverrideEnumAnonymousStructVariantInner content;
+
+  OverrideEnumAnonymousStructVariant copyWith({OverrideEnumAnonymousStructVariantInner? content}) =>
+      OverrideEnumAnonymousStructVariant(content ?? this.content);
+
+  @override
+  Map<String, dynamic> toJson() => {
+        'type': 'AnonymousStructVariant',
+        'content': content.toJson(),
+      };
+}
+
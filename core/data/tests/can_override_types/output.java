@@ -0,0 +1,32 @@
+package com.agilebits.onepassword;
+
+import com.fasterxml.jackson.annotation.*;
+import java.util.List;
+import java.util.Map;
+
+public final class file_name {
+	private file_name() {}
+
+	public record OverrideStruct(
+		@JsonProperty("fieldToOverride") Integer fieldToOverride
+	) {}
+
+	/**
+	 * Generated type representing the anonymous struct variant `AnonymousStructVariant` of the `OverrideEnum` Rust enum
+	 */
+	public record OverrideEnumAnonymousStructVariantInner(
+		@JsonProperty("fieldToOverride") Integer fieldToOverride
+	) {}
+
+	@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "type")
+	@JsonSubTypes({
+		@JsonSubTypes.Type(value = OverrideEnum.UnitVariant.class, name = "UnitVariant"),
+		@JsonSubTypes.Type(value = OverrideEnum.TupleVariant.class, name = "TupleVariant"),
+		@JsonSubTypes.Type(value = OverrideEnum.AnonymousStructVariant.class, name = "AnonymousStructVariant"),
+	})
+	public sealed interface OverrideEnum {
+		record UnitVariant() implements OverrideEnum {}
+		record TupleVariant(@JsonProperty("content") String content) implements OverrideEnum {}
+		record AnonymousStructVariant(@JsonProperty("content") OverrideEnumAnonymousStructVariantInner content) implements OverrideEnum {}
+	}
+}
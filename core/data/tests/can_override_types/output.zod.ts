@@ -0,0 +1,16 @@
+import { z } from "zod";
+
+export const OverrideStructSchema = z.object({
+	fieldToOverride: z.unknown(),
+});
+export type OverrideStruct = z.infer<typeof OverrideStructSchema>;
+
+export const OverrideEnumSchema = z.discriminatedUnion("type", [
+	z.object({ type: z.literal("UnitVariant") }),
+	z.object({ type: z.literal("TupleVariant"), content: z.string() }),
+	z.object({ type: z.literal("AnonymousStructVariant"), content: z.object({
+		fieldToOverride: z.unknown(),
+	}) }),
+]);
+export type OverrideEnum = z.infer<typeof OverrideEnumSchema>;
+
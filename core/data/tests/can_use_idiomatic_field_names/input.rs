@@ -0,0 +1,18 @@
+/// A user, serialized with snake_case keys.
+#[typeshare]
+#[serde(rename_all = "snake_case")]
+pub struct User {
+    pub user_id: String,
+    pub display_name: Option<String>,
+    #[serde(rename = "e-mail")]
+    pub email: String,
+    pub age: u8,
+}
+
+/// Keys that are already idiomatic don't need to be kept separately.
+#[typeshare]
+#[serde(rename_all = "camelCase")]
+pub struct Session {
+    pub session_token: String,
+    pub expires_at: u32,
+}
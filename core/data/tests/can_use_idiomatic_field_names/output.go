@@ -0,0 +1,16 @@
+package proto
+
+import "encoding/json"
+
+// Keys that are already idiomatic don't need to be kept separately.
+type Session struct {
+	SessionToken string `json:"sessionToken"`
+	ExpiresAt uint32 `json:"expiresAt"`
+}
+// A user, serialized with snake_case keys.
+type User struct {
+	UserId string `json:"user_id"`
+	DisplayName *string `json:"display_name,omitempty"`
+	Email string `json:"e-mail"`
+	Age int `json:"age"`
+}
@@ -0,0 +1,20 @@
+/// Keys that are already idiomatic don't need to be kept separately.
+@Serializable
+data class Session (
+	val sessionToken: String,
+	val expiresAt: UInt
+)
+
+/// A user, serialized with snake_case keys.
+@Serializable
+data class User (
+	@SerialName("user_id")
+	val userId: String,
+	@SerialName("display_name")
+	val displayName: String? = null,
+	@SerialName("e-mail")
+	val eMail: String,
+	@SerialName("age")
+	val age: UByte
+)
+
@@ -0,0 +1,26 @@
+from __future__ import annotations
+
+from pydantic import BaseModel, ConfigDict, Field
+from typing import Optional
+
+
+class Session(BaseModel):
+    """
+    Keys that are already idiomatic don't need to be kept separately.
+    """
+    model_config = ConfigDict(populate_by_name=True)
+
+    session_token: str = Field(alias="sessionToken")
+    expires_at: int = Field(alias="expiresAt")
+
+class User(BaseModel):
+    """
+    A user, serialized with snake_case keys.
+    """
+    model_config = ConfigDict(populate_by_name=True)
+
+    user_id: str
+    display_name: Optional[str] = Field(default=None)
+    email: str = Field(alias="e-mail")
+    age: int
+
@@ -0,0 +1,34 @@
+import Foundation
+
+/// Keys that are already idiomatic don't need to be kept separately.
+public struct Session: Codable {
+	public let sessionToken: String
+	public let expiresAt: UInt32
+
+	public init(sessionToken: String, expiresAt: UInt32) {
+		self.sessionToken = sessionToken
+		self.expiresAt = expiresAt
+	}
+}
+
+/// A user, serialized with snake_case keys.
+public struct User: Codable {
+	public let userId: String
+	public let displayName: String?
+	public let eMail: String
+	public let age: UInt8
+
+	enum CodingKeys: String, CodingKey, Codable {
+		case userId = "user_id",
+			displayName = "display_name",
+			eMail = "e-mail",
+			age
+	}
+
+	public init(userId: String, displayName: String?, eMail: String, age: UInt8) {
+		self.userId = userId
+		self.displayName = displayName
+		self.eMail = eMail
+		self.age = age
+	}
+}
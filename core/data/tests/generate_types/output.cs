@@ -0,0 +1,42 @@
+#nullable enable
+
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace AgileBits.OnePassword;
+
+public record CustomType;
+
+public record Types
+{
+	[JsonPropertyName("s")]
+	public required string S { get; init; }
+
+	[JsonPropertyName("static_s")]
+	public required string StaticS { get; init; }
+
+	[JsonPropertyName("int8")]
+	public required sbyte Int8 { get; init; }
+
+	[JsonPropertyName("float")]
+	public required float Float { get; init; }
+
+	[JsonPropertyName("double")]
+	public required double Double { get; init; }
+
+	[JsonPropertyName("array")]
+	public required List<string> Array { get; init; }
+
+	[JsonPropertyName("fixed_length_array")]
+	public required List<string> FixedLengthArray { get; init; }
+
+	[JsonPropertyName("dictionary")]
+	public required Dictionary<string, int> Dictionary { get; init; }
+
+	[JsonPropertyName("optional_dictionary")]
+	public Dictionary<string, int>? OptionalDictionary { get; init; }
+
+	[JsonPropertyName("custom_type")]
+	public required CustomType CustomType { get; init; }
+}
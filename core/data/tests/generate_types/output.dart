@@ -0,0 +1,99 @@
+class CustomType {
+  const CustomType();
+
+  factory CustomType.fromJson(Map<String, dynamic> json) =>
+      const CustomType();
+
+  Map<String, dynamic> toJson() => {};
+}
+
+class Types {
+  const Types({
+    required this.s,
+    required this.staticS,
+    required this.int8,
+    required this.float,
+    required this.double,
+    required this.array,
+    required this.fixedLengthArray,
+    required this.dictionary,
+    this.optionalDictionary,
+    required this.customType,
+  });
+
+  factory Types.fromJson(Map<String, dynamic> json) =>
+      Types(
+        s: json['s'] as String,
+        staticS: json['static_s'] as String,
+        int8: (json['int8'] as num).toInt(),
+        float: (json['float'] as num).toDouble(),
+        double: (json['double'] as num).toDouble(),
+        array: (json['array'] as List<dynamic>).map((v0) => v0 as String).toList(),
+        fixedLengthArray: (json['fixed_length_array'] as List<dynamic>).map((v0) => v0 as String).toList(),
+        dictionary: (json['dictionary'] as Map<String, dynamic>).map((k0, v0) => MapEntry(k0, (v0 as num).toInt())),
+        optionalDictionary: json['optional_dictionary'] == null ? null : (json['optional_dictionary'] as Map<String, dynamic>).map((k0, v0) => MapEntry(k0, (v0 as num).toInt())),
+        customType: CustomType.fromJson(json['custom_type']),
+      );
+
+  final String s;
+
+  final String staticS;
+
+  final int int8;
+
+  final double float;
+
+  final double double;
+
+  final List<String> array;
+
+  final List<String> fixedLengthArray;
+
+  final Map<String, int> dictionary;
+
+  final Map<String, int>? optionalDictionary;
+
+  final CustomType customType;
+
+  Map<String, dynamic> toJson() => {
+        's': s,
+        'static_s': staticS,
+        'int8': int8,
+        'float': float,
+        'double': double,
+        'array': array,
+        'fixed_length_array': fixedLengthArray,
+        'dictionary': dictionary,
+        'optional_dictionary': optionalDictionary,
+        'custom_type': customType.toJson(),
+      };
+
+  Types copyWith({
+    String? s,
+    String? staticS,
+    int? int8,
+    double? float,
+    double? double,
+    List<String>? array,
+    List<String>? fixedLengthArray,
+    Map<String, int>? dictionary,
+    Object? optionalDictionary = _unset,
+    CustomType? customType,
+  }) =>
+      Types(
+        s: s ?? this.s,
+        staticS: staticS ?? this.staticS,
+        int8: int8 ?? this.int8,
+        float: float ?? this.float,
+        double: double ?? this.double,
+        array: array ?? this.array,
+        fixedLengthArray: fixedLengthArray ?? this.fixedLengthArray,
+        dictionary: dictionary ?? this.dictionary,
+        optionalDictionary: identical(optionalDictionary, _unset) ? this.optionalDictionary : optionalDictionary as Map<String, int>?,
+        customType: customType ?? this.customType,
+      );
+}
+
+/// The default of nullable `copyWith` parameters, which tells an omitted
+/// argument apart from `null`.
+const Object _unset = Object();
@@ -0,0 +1,24 @@
+package com.agilebits.onepassword;
+
+import com.fasterxml.jackson.annotation.*;
+import java.util.List;
+import java.util.Map;
+
+public final class file_name {
+	private file_name() {}
+
+	public record CustomType() {}
+
+	public record Types(
+		@JsonProperty("s") String s,
+		@JsonProperty("static_s") String staticS,
+		@JsonProperty("int8") byte int8,
+		@JsonProperty("float") float float_,
+		@JsonProperty("double") double double_,
+		@JsonProperty("array") List<String> array,
+		@JsonProperty("fixed_length_array") List<String> fixedLengthArray,
+		@JsonProperty("dictionary") Map<String, Integer> dictionary,
+		@JsonProperty("optional_dictionary") Map<String, Integer> optionalDictionary,
+		@JsonProperty("custom_type") CustomType customType
+	) {}
+}
@@ -0,0 +1,20 @@
+import { z } from "zod";
+
+export const CustomTypeSchema = z.object({
+});
+export type CustomType = z.infer<typeof CustomTypeSchema>;
+
+export const TypesSchema = z.object({
+	s: z.string(),
+	static_s: z.string(),
+	int8: z.number().int().min(-128).max(127),
+	float: z.number(),
+	double: z.number(),
+	array: z.array(z.string()),
+	fixed_length_array: z.tuple([z.string(), z.string(), z.string(), z.string()]),
+	dictionary: z.record(z.string(), z.number().int().min(-2147483648).max(2147483647)),
+	optional_dictionary: z.record(z.string(), z.number().int().min(-2147483648).max(2147483647)).nullish().transform((value) => value ?? undefined),
+	custom_type: CustomTypeSchema,
+});
+export type Types = z.infer<typeof TypesSchema>;
+
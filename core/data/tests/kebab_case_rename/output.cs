@@ -0,0 +1,22 @@
+#nullable enable
+
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace AgileBits.OnePassword;
+
+/// <summary>
+/// This is a comment.
+/// </summary>
+public record Things
+{
+	[JsonPropertyName("bla")]
+	public required string Bla { get; init; }
+
+	[JsonPropertyName("label")]
+	public string? Label { get; init; }
+
+	[JsonPropertyName("label-left")]
+	public string? LabelLeft { get; init; }
+}
@@ -0,0 +1,42 @@
+/// This is a comment.
+class Things {
+  const Things({
+    required this.bla,
+    this.label,
+    this.labelLeft,
+  });
+
+  factory Things.fromJson(Map<String, dynamic> json) =>
+      Things(
+        bla: json['bla'] as String,
+        label: json['label'] as String?,
+        labelLeft: json['label-left'] as String?,
+      );
+
+  final String bla;
+
+  final String? label;
+
+  final String? labelLeft;
+
+  Map<String, dynamic> toJson() => {
+        'bla': bla,
+        'label': label,
+        'label-left': labelLeft,
+      };
+
+  Things copyWith({
+    String? bla,
+    Object? label = _unset,
+    Object? labelLeft = _unset,
+  }) =>
+      Things(
+        bla: bla ?? this.bla,
+        label: identical(label, _unset) ? this.label : label as String?,
+        labelLeft: identical(labelLeft, _unset) ? this.labelLeft : labelLeft as String?,
+      );
+}
+
+/// The default of nullable `copyWith` parameters, which tells an omitted
+/// argument apart from `null`.
+const Object _unset = Object();
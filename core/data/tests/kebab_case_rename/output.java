@@ -0,0 +1,18 @@
+package com.agilebits.onepassword;
+
+import com.fasterxml.jackson.annotation.*;
+import java.util.List;
+import java.util.Map;
+
+public final class file_name {
+	private file_name() {}
+
+	/**
+	 * This is a comment.
+	 */
+	public record Things(
+		@JsonProperty("bla") String bla,
+		@JsonProperty("label") String label,
+		@JsonProperty("label-left") String labelLeft
+	) {}
+}
@@ -0,0 +1,10 @@
+import { z } from "zod";
+
+/** This is a comment. */
+export const ThingsSchema = z.object({
+	bla: z.string(),
+	label: z.string().nullish().transform((value) => value ?? undefined),
+	"label-left": z.string().nullish().transform((value) => value ?? undefined),
+});
+export type Things = z.infer<typeof ThingsSchema>;
+
@@ -0,0 +1,32 @@
+//! Test references to generic types that have been renamed via serde(rename)
+//!
+
+#[derive(Serialize)]
+#[serde(rename = "Person")]
+#[typeshare]
+pub struct User {
+    name: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename = "Paged")]
+#[typeshare]
+pub struct Page<T> {
+    items: Vec<T>,
+    next: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename = "Outcome", tag = "type", content = "content")]
+#[typeshare]
+pub enum Response<T> {
+    Done(T),
+    Failed(String),
+}
+
+#[derive(Serialize)]
+#[typeshare]
+pub struct Listing {
+    users: Page<User>,
+    last: Response<User>,
+}
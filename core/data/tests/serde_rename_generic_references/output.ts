@@ -0,0 +1,18 @@
+export interface Listing {
+	users: Paged<Person>;
+	last: Outcome<Person>;
+}
+
+export interface Paged<T> {
+	items: T[];
+	next?: string;
+}
+
+export interface Person {
+	name: string;
+}
+
+export type Outcome<T> = 
+	| { type: "Done", content: T }
+	| { type: "Failed", content: string };
+
@@ -0,0 +1,24 @@
+class MyType {
+  const MyType({
+    required this.field,
+  });
+
+  factory MyType.fromJson(Map<String, dynamic> json) =>
+      MyType(
+        field: json['field'] as String,
+      );
+
+  final String field;
+
+  Map<String, dynamic> toJson() => {
+        'field': field,
+      };
+
+  MyType copyWith({
+    String? field,
+  }) =>
+      MyType(
+        field: field ?? this.field,
+      );
+}
+
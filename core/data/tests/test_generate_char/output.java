@@ -0,0 +1,13 @@
+package com.agilebits.onepassword;
+
+import com.fasterxml.jackson.annotation.*;
+import java.util.List;
+import java.util.Map;
+
+public final class file_name {
+	private file_name() {}
+
+	public record MyType(
+		@JsonProperty("field") String field
+	) {}
+}
@@ -0,0 +1,7 @@
+import { z } from "zod";
+
+export const MyTypeSchema = z.object({
+	field: z.string(),
+});
+export type MyType = z.infer<typeof MyTypeSchema>;
+
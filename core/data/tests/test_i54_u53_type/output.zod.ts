@@ -0,0 +1,8 @@
+import { z } from "zod";
+
+export const FooSchema = z.object({
+	a: z.number().int().safe(),
+	b: z.number().int().nonnegative().safe(),
+});
+export type Foo = z.infer<typeof FooSchema>;
+
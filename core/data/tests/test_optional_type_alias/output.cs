@@ -0,0 +1,44 @@
+#nullable enable
+
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace AgileBits.OnePassword;
+
+[JsonConverter(typeof(OptionalU16JsonConverter))]
+public sealed record OptionalU16(ushort? Value);
+
+public sealed class OptionalU16JsonConverter : JsonConverter<OptionalU16>
+{
+	public override bool HandleNull => true;
+
+	public override OptionalU16 Read(ref Utf8JsonReader reader, System.Type typeToConvert, JsonSerializerOptions options) =>
+		new(JsonSerializer.Deserialize<ushort?>(ref reader, options)!);
+
+	public override void Write(Utf8JsonWriter writer, OptionalU16 value, JsonSerializerOptions options) =>
+		JsonSerializer.Serialize(writer, value?.Value, options);
+}
+
+[JsonConverter(typeof(OptionalU32JsonConverter))]
+public sealed record OptionalU32(uint? Value);
+
+public sealed class OptionalU32JsonConverter : JsonConverter<OptionalU32>
+{
+	public override bool HandleNull => true;
+
+	public override OptionalU32 Read(ref Utf8JsonReader reader, System.Type typeToConvert, JsonSerializerOptions options) =>
+		new(JsonSerializer.Deserialize<uint?>(ref reader, options)!);
+
+	public override void Write(Utf8JsonWriter writer, OptionalU32 value, JsonSerializerOptions options) =>
+		JsonSerializer.Serialize(writer, value?.Value, options);
+}
+
+public record FooBar
+{
+	[JsonPropertyName("foo")]
+	public required OptionalU32 Foo { get; init; }
+
+	[JsonPropertyName("bar")]
+	public required OptionalU16 Bar { get; init; }
+}
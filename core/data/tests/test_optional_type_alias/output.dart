@@ -0,0 +1,50 @@
+typedef OptionalU16 = int?;
+
+OptionalU16 optionalU16FromJson(dynamic json) =>
+    (json as num?)?.toInt();
+
+Object? optionalU16ToJson(OptionalU16 value) =>
+    value;
+
+typedef OptionalU32 = int?;
+
+OptionalU32 optionalU32FromJson(dynamic json) =>
+    (json as num?)?.toInt();
+
+Object? optionalU32ToJson(OptionalU32 value) =>
+    value;
+
+class FooBar {
+  const FooBar({
+    required this.foo,
+    required this.bar,
+  });
+
+  factory FooBar.fromJson(Map<String, dynamic> json) =>
+      FooBar(
+        foo: optionalU32FromJson(json['foo']),
+        bar: optionalU16FromJson(json['bar']),
+      );
+
+  final OptionalU32 foo;
+
+  final OptionalU16 bar;
+
+  Map<String, dynamic> toJson() => {
+        'foo': optionalU32ToJson(foo),
+        'bar': optionalU16ToJson(bar),
+      };
+
+  FooBar copyWith({
+    Object? foo = _unset,
+    Object? bar = _unset,
+  }) =>
+      FooBar(
+        foo: identical(foo, _unset) ? this.foo : foo as OptionalU32,
+        bar: identical(bar, _unset) ? this.bar : bar as OptionalU16,
+      );
+}
+
+/// The default of nullable `copyWith` parameters, which tells an omitted
+/// argument apart from `null`.
+const Object _unset = Object();
@@ -0,0 +1,24 @@
+package com.agilebits.onepassword;
+
+import com.fasterxml.jackson.annotation.*;
+import java.util.List;
+import java.util.Map;
+
+public final class file_name {
+	private file_name() {}
+
+	public record OptionalU16(@JsonValue Integer value) {
+		@JsonCreator(mode = JsonCreator.Mode.DELEGATING)
+		public OptionalU16 {}
+	}
+
+	public record OptionalU32(@JsonValue Long value) {
+		@JsonCreator(mode = JsonCreator.Mode.DELEGATING)
+		public OptionalU32 {}
+	}
+
+	public record FooBar(
+		@JsonProperty("foo") OptionalU32 foo,
+		@JsonProperty("bar") OptionalU16 bar
+	) {}
+}
@@ -0,0 +1,14 @@
+import { z } from "zod";
+
+export const OptionalU16Schema = z.number().int().min(0).max(65535).nullish().transform((value) => value ?? undefined);
+export type OptionalU16 = z.infer<typeof OptionalU16Schema>;
+
+export const OptionalU32Schema = z.number().int().min(0).max(4294967295).nullish().transform((value) => value ?? undefined);
+export type OptionalU32 = z.infer<typeof OptionalU32Schema>;
+
+export const FooBarSchema = z.object({
+	foo: OptionalU32Schema,
+	bar: OptionalU16Schema,
+});
+export type FooBar = z.infer<typeof FooBarSchema>;
+
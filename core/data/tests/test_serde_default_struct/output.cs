@@ -0,0 +1,13 @@
+#nullable enable
+
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace AgileBits.OnePassword;
+
+public record Foo
+{
+	[JsonPropertyName("bar")]
+	public bool? Bar { get; init; }
+}
@@ -0,0 +1,27 @@
+class Foo {
+  const Foo({
+    this.bar,
+  });
+
+  factory Foo.fromJson(Map<String, dynamic> json) =>
+      Foo(
+        bar: json['bar'] as bool?,
+      );
+
+  final bool? bar;
+
+  Map<String, dynamic> toJson() => {
+        'bar': bar,
+      };
+
+  Foo copyWith({
+    Object? bar = _unset,
+  }) =>
+      Foo(
+        bar: identical(bar, _unset) ? this.bar : bar as bool?,
+      );
+}
+
+/// The default of nullable `copyWith` parameters, which tells an omitted
+/// argument apart from `null`.
+const Object _unset = Object();
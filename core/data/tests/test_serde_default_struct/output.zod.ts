@@ -0,0 +1,7 @@
+import { z } from "zod";
+
+export const FooSchema = z.object({
+	bar: z.boolean().optional(),
+});
+export type Foo = z.infer<typeof FooSchema>;
+
@@ -0,0 +1,25 @@
+#nullable enable
+
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace AgileBits.OnePassword;
+
+[JsonConverter(typeof(BarJsonConverter))]
+public sealed record Bar(string Value);
+
+public sealed class BarJsonConverter : JsonConverter<Bar>
+{
+	public override Bar Read(ref Utf8JsonReader reader, System.Type typeToConvert, JsonSerializerOptions options) =>
+		new(JsonSerializer.Deserialize<string>(ref reader, options)!);
+
+	public override void Write(Utf8JsonWriter writer, Bar value, JsonSerializerOptions options) =>
+		JsonSerializer.Serialize(writer, value.Value, options);
+}
+
+public record Foo
+{
+	[JsonPropertyName("bar")]
+	public required Bar Bar { get; init; }
+}
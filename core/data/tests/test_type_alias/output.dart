@@ -0,0 +1,32 @@
+typedef Bar = String;
+
+Bar barFromJson(dynamic json) =>
+    json as String;
+
+Object? barToJson(Bar value) =>
+    value;
+
+class Foo {
+  const Foo({
+    required this.bar,
+  });
+
+  factory Foo.fromJson(Map<String, dynamic> json) =>
+      Foo(
+        bar: barFromJson(json['bar']),
+      );
+
+  final Bar bar;
+
+  Map<String, dynamic> toJson() => {
+        'bar': barToJson(bar),
+      };
+
+  Foo copyWith({
+    Bar? bar,
+  }) =>
+      Foo(
+        bar: bar ?? this.bar,
+      );
+}
+
@@ -0,0 +1,18 @@
+package com.agilebits.onepassword;
+
+import com.fasterxml.jackson.annotation.*;
+import java.util.List;
+import java.util.Map;
+
+public final class file_name {
+	private file_name() {}
+
+	public record Bar(@JsonValue String value) {
+		@JsonCreator(mode = JsonCreator.Mode.DELEGATING)
+		public Bar {}
+	}
+
+	public record Foo(
+		@JsonProperty("bar") Bar bar
+	) {}
+}
@@ -0,0 +1,10 @@
+import { z } from "zod";
+
+export const BarSchema = z.string();
+export type Bar = z.infer<typeof BarSchema>;
+
+export const FooSchema = z.object({
+	bar: BarSchema,
+});
+export type Foo = z.infer<typeof FooSchema>;
+
@@ -0,0 +1,31 @@
+#nullable enable
+
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace AgileBits.OnePassword;
+
+/// <summary>
+/// This is a comment.
+/// </summary>
+public record Foo
+{
+	[JsonPropertyName("a")]
+	public required sbyte A { get; init; }
+
+	[JsonPropertyName("b")]
+	public required short B { get; init; }
+
+	[JsonPropertyName("c")]
+	public required int C { get; init; }
+
+	[JsonPropertyName("e")]
+	public required byte E { get; init; }
+
+	[JsonPropertyName("f")]
+	public required ushort F { get; init; }
+
+	[JsonPropertyName("g")]
+	public required uint G { get; init; }
+}
@@ -0,0 +1,60 @@
+/// This is a comment.
+class Foo {
+  const Foo({
+    required this.a,
+    required this.b,
+    required this.c,
+    required this.e,
+    required this.f,
+    required this.g,
+  });
+
+  factory Foo.fromJson(Map<String, dynamic> json) =>
+      Foo(
+        a: (json['a'] as num).toInt(),
+        b: (json['b'] as num).toInt(),
+        c: (json['c'] as num).toInt(),
+        e: (json['e'] as num).toInt(),
+        f: (json['f'] as num).toInt(),
+        g: (json['g'] as num).toInt(),
+      );
+
+  final int a;
+
+  final int b;
+
+  final int c;
+
+  final int e;
+
+  final int f;
+
+  final int g;
+
+  Map<String, dynamic> toJson() => {
+        'a': a,
+        'b': b,
+        'c': c,
+        'e': e,
+        'f': f,
+        'g': g,
+      };
+
+  Foo copyWith({
+    int? a,
+    int? b,
+    int? c,
+    int? e,
+    int? f,
+    int? g,
+  }) =>
+      Foo(
+        a: a ?? this.a,
+        b: b ?? this.b,
+        c: c ?? this.c,
+        e: e ?? this.e,
+        f: f ?? this.f,
+        g: g ?? this.g,
+      );
+}
+
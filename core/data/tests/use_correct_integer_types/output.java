@@ -0,0 +1,21 @@
+package com.agilebits.onepassword;
+
+import com.fasterxml.jackson.annotation.*;
+import java.util.List;
+import java.util.Map;
+
+public final class file_name {
+	private file_name() {}
+
+	/**
+	 * This is a comment.
+	 */
+	public record Foo(
+		@JsonProperty("a") byte a,
+		@JsonProperty("b") short b,
+		@JsonProperty("c") int c,
+		@JsonProperty("e") short e,
+		@JsonProperty("f") int f,
+		@JsonProperty("g") long g
+	) {}
+}
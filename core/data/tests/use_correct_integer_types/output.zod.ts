@@ -0,0 +1,13 @@
+import { z } from "zod";
+
+/** This is a comment. */
+export const FooSchema = z.object({
+	a: z.number().int().min(-128).max(127),
+	b: z.number().int().min(-32768).max(32767),
+	c: z.number().int().min(-2147483648).max(2147483647),
+	e: z.number().int().min(0).max(255),
+	f: z.number().int().min(0).max(65535),
+	g: z.number().int().min(0).max(4294967295),
+});
+export type Foo = z.infer<typeof FooSchema>;
+
@@ -0,0 +1,322 @@
+//! Crate discovery from `Cargo.toml` manifests.
+//!
+//! By default the crate a source file belongs to is the name of the directory
+//! containing its `src` folder. Reading the manifests instead gives the real
+//! library name of each package, regardless of where its sources live, and lets
+//! imports through renamed dependencies resolve to the crate they refer to.
+//!
+//! Only manifests on disk are read; nothing is fetched or resolved over the network.
+use crate::language::CrateName;
+use ignore::WalkBuilder;
+use std::{
+    collections::{btree_map::Entry, BTreeMap, HashMap},
+    fs, io,
+    path::{Path, PathBuf},
+};
+use thiserror::Error;
+use toml::{Table, Value};
+
+const MANIFEST: &str = "Cargo.toml";
+
+/// Sections of a manifest that declare dependencies.
+const DEPENDENCY_SECTIONS: &[&str] = &["dependencies", "dev-dependencies", "build-dependencies"];
+
+/// Errors that can occur while reading manifests.
+#[derive(Debug, Error)]
+#[allow(missing_docs)]
+pub enum ManifestError {
+    #[error("failed to read {path:?}: {source}")]
+    Read { path: PathBuf, source: io::Error },
+    #[error("failed to parse {path:?}: {source}")]
+    Parse {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+    #[error("failed traversing input: {0}")]
+    Walk(#[from] ignore::Error),
+}
+
+/// A package found in a manifest.
+#[derive(Debug)]
+struct Package {
+    /// The `package.name` of the manifest.
+    name: String,
+    /// The name the library target is referred to by in code.
+    lib_name: CrateName,
+    /// Dependency names as used in code, mapped to their package names.
+    dependencies: HashMap<String, String>,
+}
+
+/// The packages found in the `Cargo.toml` manifests of one or more directories.
+#[derive(Debug, Default)]
+pub struct CrateManifests {
+    /// Packages by their canonical directory.
+    packages: BTreeMap<PathBuf, Package>,
+}
+
+impl CrateManifests {
+    /// Read the manifests of all packages inside of the given directories, and of
+    /// the packages the directories themselves are part of.
+    pub fn discover(roots: &[PathBuf]) -> Result<Self, ManifestError> {
+        let mut loader = Loader::default();
+        let mut manifests = Self::default();
+
+        for root in roots {
+            let root = canonicalize(root)?;
+            let ancestors = root.ancestors().skip(1).map(Path::to_path_buf);
+            let descendants = WalkBuilder::new(&root)
+                .build()
+                .filter_map(|entry| {
+                    entry
+                        .map(|entry| {
+                            (entry.file_name() == MANIFEST)
+                                .then(|| entry.path().parent().map(Path::to_path_buf))
+                                .flatten()
+                        })
+                        .transpose()
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            for dir in ancestors.chain(descendants) {
+                if let Entry::Vacant(entry) = manifests.packages.entry(dir) {
+                    if let Some(package) = loader.package(entry.key())? {
+                        entry.insert(package);
+                    }
+                }
+            }
+        }
+
+        Ok(manifests)
+    }
+
+    /// The package containing the given source file.
+    fn package(&self, file: &Path) -> Option<&Package> {
+        let file = fs::canonicalize(file).unwrap_or_else(|_| file.to_path_buf());
+        // Nested packages sort after the packages containing them.
+        self.packages
+            .iter()
+            .rev()
+            .find(|(dir, _)| file.starts_with(dir))
+            .map(|(_, package)| package)
+    }
+
+    /// The library name of the package containing the given source file. Files
+    /// in examples, benches, tests and binaries belong to their package's library.
+    pub fn crate_name(&self, file: &Path) -> Option<CrateName> {
+        self.package(file).map(|package| package.lib_name.clone())
+    }
+
+    /// Resolve a crate name used in the given source file, as in `use other_crate::Type`,
+    /// to the library name of the dependency it refers to. Returns `None` if the
+    /// name is not a dependency of the file's package.
+    pub fn resolve_dependency(&self, file: &Path, crate_name: &CrateName) -> Option<CrateName> {
+        let package_name = self.package(file)?.dependencies.get(crate_name.as_str())?;
+
+        Some(
+            self.packages
+                .values()
+                .find(|package| &package.name == package_name)
+                .map(|package| package.lib_name.clone())
+                .unwrap_or_else(|| package_name.replace('-', "_").into()),
+        )
+    }
+}
+
+/// Reads and caches manifests while discovering packages.
+#[derive(Default)]
+struct Loader {
+    manifests: HashMap<PathBuf, Option<Table>>,
+}
+
+impl Loader {
+    /// The parsed manifest in `dir`, if there is one.
+    fn manifest(&mut self, dir: &Path) -> Result<Option<&Table>, ManifestError> {
+        if !self.manifests.contains_key(dir) {
+            let path = dir.join(MANIFEST);
+            let manifest = match fs::read_to_string(&path) {
+                Ok(contents) => Some(
+                    contents
+                        .parse::<Table>()
+                        .map_err(|source| ManifestError::Parse { path, source })?,
+                ),
+                Err(err) if err.kind() == io::ErrorKind::NotFound => None,
+                Err(source) => return Err(ManifestError::Read { path, source }),
+            };
+            self.manifests.insert(dir.to_path_buf(), manifest);
+        }
+        Ok(self.manifests[dir].as_ref())
+    }
+
+    /// The `workspace.dependencies` of the workspace containing `dir`.
+    fn workspace_dependencies(&mut self, dir: &Path) -> Result<Table, ManifestError> {
+        for ancestor in dir.ancestors() {
+            if let Some(workspace) = self
+                .manifest(ancestor)?
+                .and_then(|manifest| manifest.get("workspace"))
+            {
+                return Ok(workspace
+                    .get("dependencies")
+                    .and_then(Value::as_table)
+                    .cloned()
+                    .unwrap_or_default());
+            }
+        }
+        Ok(Table::new())
+    }
+
+    /// The package declared by the manifest in `dir`, if there is one.
+    fn package(&mut self, dir: &Path) -> Result<Option<Package>, ManifestError> {
+        let Some(manifest) = self.manifest(dir)?.cloned() else {
+            return Ok(None);
+        };
+        let Some(name) = manifest
+            .get("package")
+            .and_then(|package| package.get("name"))
+            .and_then(Value::as_str)
+        else {
+            return Ok(None);
+        };
+
+        let lib_name = manifest
+            .get("lib")
+            .and_then(|lib| lib.get("name"))
+            .and_then(Value::as_str)
+            .unwrap_or(name)
+            .replace('-', "_");
+
+        let targets = manifest
+            .get("target")
+            .and_then(Value::as_table)
+            .into_iter()
+            .flat_map(|targets| targets.values().filter_map(Value::as_table));
+        let sections = [&manifest]
+            .into_iter()
+            .chain(targets)
+            .flat_map(|table| {
+                DEPENDENCY_SECTIONS
+                    .iter()
+                    .filter_map(|section| table.get(*section).and_then(Value::as_table))
+            })
+            .collect::<Vec<_>>();
+
+        let mut workspace_dependencies = None;
+        let mut dependencies = HashMap::new();
+        for (alias, spec) in sections.into_iter().flatten() {
+            let inherited = spec.get("workspace").and_then(Value::as_bool) == Some(true);
+            let spec = if inherited {
+                if workspace_dependencies.is_none() {
+                    workspace_dependencies = Some(self.workspace_dependencies(dir)?);
+                }
+                workspace_dependencies
+                    .as_ref()
+                    .and_then(|dependencies| dependencies.get(alias))
+                    .unwrap_or(spec)
+            } else {
+                spec
+            };
+            let package_name = spec.get("package").and_then(Value::as_str).unwrap_or(alias);
+            dependencies.insert(alias.replace('-', "_"), package_name.to_owned());
+        }
+
+        Ok(Some(Package {
+            name: name.to_owned(),
+            lib_name: lib_name.into(),
+            dependencies,
+        }))
+    }
+}
+
+fn canonicalize(path: &Path) -> Result<PathBuf, ManifestError> {
+    fs::canonicalize(path).map_err(|source| ManifestError::Read {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Lay out a small workspace in a scratch directory.
+    fn workspace() -> PathBuf {
+        let root = std::env::temp_dir().join(format!("typeshare-cargo-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+
+        let files = [
+            (
+                "Cargo.toml",
+                r#"
+                [workspace]
+                members = ["app", "shared"]
+
+                [workspace.dependencies]
+                common = { package = "shared-types", path = "shared" }
+                "#,
+            ),
+            (
+                "app/Cargo.toml",
+                r#"
+                [package]
+                name = "my-app"
+
+                [lib]
+                name = "app_core"
+                path = "lib.rs"
+
+                [dependencies]
+                types = { package = "shared-types", path = "../shared" }
+                common.workspace = true
+                serde = "1"
+
+                [target.'cfg(unix)'.dev-dependencies]
+                unix-only = { package = "other-crate", version = "1" }
+                "#,
+            ),
+            ("app/lib.rs", ""),
+            ("app/examples/demo.rs", ""),
+            (
+                "shared/Cargo.toml",
+                r#"
+                [package]
+                name = "shared-types"
+                "#,
+            ),
+            ("shared/src/lib.rs", ""),
+        ];
+        for (path, contents) in files {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, contents).unwrap();
+        }
+        root
+    }
+
+    #[test]
+    fn crate_names_and_dependencies() {
+        let root = workspace();
+        let manifests = CrateManifests::discover(std::slice::from_ref(&root)).unwrap();
+
+        let crate_name = |path: &str| manifests.crate_name(&root.join(path));
+        assert_eq!(crate_name("app/lib.rs"), Some("app_core".into()));
+        assert_eq!(crate_name("app/examples/demo.rs"), Some("app_core".into()));
+        assert_eq!(crate_name("shared/src/lib.rs"), Some("shared_types".into()));
+
+        let resolve = |crate_name: &str| {
+            manifests.resolve_dependency(&root.join("app/lib.rs"), &crate_name.into())
+        };
+        assert_eq!(resolve("types"), Some("shared_types".into()));
+        assert_eq!(resolve("common"), Some("shared_types".into()));
+        assert_eq!(resolve("serde"), Some("serde".into()));
+        assert_eq!(resolve("unix_only"), Some("other_crate".into()));
+        assert_eq!(resolve("app_core"), None);
+
+        // A root inside of a package still finds that package.
+        let manifests = CrateManifests::discover(&[root.join("shared/src")]).unwrap();
+        assert_eq!(
+            manifests.crate_name(&root.join("shared/src/lib.rs")),
+            Some("shared_types".into())
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
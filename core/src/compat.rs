@@ -0,0 +1,936 @@
+//! Compatibility checks between two versions of the same set of typeshared types.
+//!
+//! Changes are classified with respect to serde's JSON semantics. A change is
+//! *backward compatible* when readers using the new types can still deserialize
+//! data written with the old types, and *forward compatible* when readers still
+//! using the old types can deserialize data written with the new types.
+//!
+//! Types are matched by their Rust name, fields and variants by their serialized
+//! name, since that is what ends up on the wire.
+use crate::{
+    parser::ParsedData,
+    rust_types::{
+        RustEnum, RustEnumVariant, RustField, RustStruct, RustType, RustTypeAlias, SpecialRustType,
+    },
+};
+use itertools::Itertools;
+use std::{collections::BTreeMap, fmt};
+
+/// How a change affects readers and writers on either side of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Compatibility {
+    /// Old and new readers can both deserialize data written by either version.
+    Full,
+    /// New readers can deserialize old data, but old readers may reject new data.
+    Backward,
+    /// Old readers can deserialize new data, but new readers may reject old data.
+    Forward,
+    /// Neither version is guaranteed to read data written by the other.
+    Breaking,
+}
+
+impl Compatibility {
+    /// Combine two classifications, keeping only the guarantees both provide.
+    pub fn and(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Full, other) | (other, Self::Full) => other,
+            (a, b) if a == b => a,
+            _ => Self::Breaking,
+        }
+    }
+
+    /// Whether data written by the old types can be read by the new types.
+    pub fn is_backward_compatible(self) -> bool {
+        matches!(self, Self::Full | Self::Backward)
+    }
+
+    /// Whether data written by the new types can be read by the old types.
+    pub fn is_forward_compatible(self) -> bool {
+        matches!(self, Self::Full | Self::Forward)
+    }
+
+    /// Whether this classification provides every guarantee of `required`.
+    pub fn satisfies(self, required: Self) -> bool {
+        required.and(self) == required
+    }
+}
+
+impl fmt::Display for Compatibility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Full => "compatible",
+            Self::Backward => "backward compatible",
+            Self::Forward => "forward compatible",
+            Self::Breaking => "breaking",
+        })
+    }
+}
+
+/// A single difference between the old and new version of a type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChangeKind {
+    /// A new type was added.
+    TypeAdded,
+    /// A type was removed.
+    TypeRemoved,
+    /// A type changed between being a struct, an enum, or an alias.
+    TypeKindChanged {
+        #[allow(missing_docs)]
+        old: &'static str,
+        #[allow(missing_docs)]
+        new: &'static str,
+    },
+    /// The number of generic parameters of a type changed.
+    GenericsChanged {
+        #[allow(missing_docs)]
+        old: usize,
+        #[allow(missing_docs)]
+        new: usize,
+    },
+    /// A field was added.
+    FieldAdded {
+        #[allow(missing_docs)]
+        field: String,
+        /// The field has neither an `Option` type nor `serde(default)`.
+        required: bool,
+    },
+    /// A field was removed.
+    FieldRemoved {
+        #[allow(missing_docs)]
+        field: String,
+        /// The field had neither an `Option` type nor `serde(default)`.
+        required: bool,
+    },
+    /// The serialized name of a field changed.
+    FieldRenamed {
+        #[allow(missing_docs)]
+        old: String,
+        #[allow(missing_docs)]
+        new: String,
+    },
+    /// The type of a field changed.
+    FieldTypeChanged {
+        #[allow(missing_docs)]
+        field: String,
+        #[allow(missing_docs)]
+        old: String,
+        #[allow(missing_docs)]
+        new: String,
+    },
+    /// A field gained `serde(default)`.
+    FieldDefaultAdded {
+        #[allow(missing_docs)]
+        field: String,
+    },
+    /// A field lost `serde(default)`.
+    FieldDefaultRemoved {
+        #[allow(missing_docs)]
+        field: String,
+    },
+    /// An enum variant was added.
+    VariantAdded {
+        #[allow(missing_docs)]
+        variant: String,
+    },
+    /// An enum variant was removed.
+    VariantRemoved {
+        #[allow(missing_docs)]
+        variant: String,
+    },
+    /// The serialized name of an enum variant changed.
+    VariantRenamed {
+        #[allow(missing_docs)]
+        old: String,
+        #[allow(missing_docs)]
+        new: String,
+    },
+    /// A variant changed between unit, tuple and struct form.
+    VariantKindChanged {
+        #[allow(missing_docs)]
+        variant: String,
+    },
+    /// The type held by a tuple variant changed.
+    VariantTypeChanged {
+        #[allow(missing_docs)]
+        variant: String,
+        #[allow(missing_docs)]
+        old: String,
+        #[allow(missing_docs)]
+        new: String,
+    },
+    /// The `serde(tag)` or `serde(content)` key of an algebraic enum changed.
+    EnumTaggingChanged,
+    /// The aliased type changed.
+    AliasTypeChanged {
+        #[allow(missing_docs)]
+        old: String,
+        #[allow(missing_docs)]
+        new: String,
+    },
+}
+
+impl fmt::Display for ChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let requirement = |required: &bool| if *required { "required" } else { "optional" };
+        match self {
+            Self::TypeAdded => write!(f, "type added"),
+            Self::TypeRemoved => write!(f, "type removed"),
+            Self::TypeKindChanged { old, new } => write!(f, "changed from {old} to {new}"),
+            Self::GenericsChanged { old, new } => {
+                write!(f, "generic parameter count changed from {old} to {new}")
+            }
+            Self::FieldAdded { field, required } => {
+                write!(f, "{} field `{field}` added", requirement(required))
+            }
+            Self::FieldRemoved { field, required } => {
+                write!(f, "{} field `{field}` removed", requirement(required))
+            }
+            Self::FieldRenamed { old, new } => write!(f, "field `{old}` renamed to `{new}`"),
+            Self::FieldTypeChanged { field, old, new } => {
+                write!(f, "field `{field}` changed type from `{old}` to `{new}`")
+            }
+            Self::FieldDefaultAdded { field } => {
+                write!(f, "field `{field}` gained `serde(default)`")
+            }
+            Self::FieldDefaultRemoved { field } => {
+                write!(f, "field `{field}` lost `serde(default)`")
+            }
+            Self::VariantAdded { variant } => write!(f, "variant `{variant}` added"),
+            Self::VariantRemoved { variant } => write!(f, "variant `{variant}` removed"),
+            Self::VariantRenamed { old, new } => write!(f, "variant `{old}` renamed to `{new}`"),
+            Self::VariantKindChanged { variant } => {
+                write!(
+                    f,
+                    "variant `{variant}` changed between unit, tuple and struct form"
+                )
+            }
+            Self::VariantTypeChanged { variant, old, new } => {
+                write!(
+                    f,
+                    "variant `{variant}` changed type from `{old}` to `{new}`"
+                )
+            }
+            Self::EnumTaggingChanged => write!(f, "serde tag or content key changed"),
+            Self::AliasTypeChanged { old, new } => {
+                write!(f, "aliased type changed from `{old}` to `{new}`")
+            }
+        }
+    }
+}
+
+/// A classified difference between two versions of a type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    /// The Rust name of the changed type. Changes inside struct variants are
+    /// reported as `Enum::Variant`.
+    pub type_name: String,
+    /// What changed.
+    pub kind: ChangeKind,
+    /// How the change affects old and new readers.
+    pub compatibility: Compatibility,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: `{}`: {}",
+            self.compatibility, self.type_name, self.kind
+        )
+    }
+}
+
+/// A struct, enum or alias as seen by the compatibility check.
+enum Item<'a> {
+    Struct(&'a RustStruct),
+    Enum(&'a RustEnum),
+    Alias(&'a RustTypeAlias),
+}
+
+impl Item<'_> {
+    fn kind(&self) -> &'static str {
+        match self {
+            Item::Struct(_) => "struct",
+            Item::Enum(_) => "enum",
+            Item::Alias(_) => "alias",
+        }
+    }
+
+    fn generic_count(&self) -> usize {
+        match self {
+            Item::Struct(s) => s.generic_types.len(),
+            Item::Enum(e) => e.shared().generic_types.len(),
+            Item::Alias(a) => a.generic_types.len(),
+        }
+    }
+}
+
+fn items(data: &ParsedData) -> BTreeMap<&str, Item<'_>> {
+    let structs = data
+        .structs
+        .iter()
+        .map(|s| (s.id.original.as_str(), Item::Struct(s)));
+    let enums = data
+        .enums
+        .iter()
+        .map(|e| (e.shared().id.original.as_str(), Item::Enum(e)));
+    let aliases = data
+        .aliases
+        .iter()
+        .map(|a| (a.id.original.as_str(), Item::Alias(a)));
+    structs.chain(enums).chain(aliases).collect()
+}
+
+/// Compare two versions of parsed types and classify every change between them.
+///
+/// Both sides should be parsed the same way; renamed type references should not
+/// be reconciled, since type names never appear in the serialized data.
+pub fn compare(old: &ParsedData, new: &ParsedData) -> Vec<Change> {
+    let old_items = items(old);
+    let new_items = items(new);
+    let mut changes = Changes::default();
+
+    for name in old_items.keys().chain(new_items.keys()).sorted().dedup() {
+        changes.type_name = (*name).to_owned();
+        match (old_items.get(name), new_items.get(name)) {
+            (Some(old), Some(new)) => compare_items(old, new, &mut changes),
+            (Some(_), None) => changes.push(ChangeKind::TypeRemoved, Compatibility::Breaking),
+            (None, Some(_)) => changes.push(ChangeKind::TypeAdded, Compatibility::Full),
+            (None, None) => unreachable!(),
+        }
+    }
+
+    changes.changes
+}
+
+#[derive(Default)]
+struct Changes {
+    type_name: String,
+    changes: Vec<Change>,
+}
+
+impl Changes {
+    fn push(&mut self, kind: ChangeKind, compatibility: Compatibility) {
+        self.changes.push(Change {
+            type_name: self.type_name.clone(),
+            kind,
+            compatibility,
+        });
+    }
+}
+
+fn compare_items(old: &Item, new: &Item, changes: &mut Changes) {
+    if old.generic_count() != new.generic_count() {
+        changes.push(
+            ChangeKind::GenericsChanged {
+                old: old.generic_count(),
+                new: new.generic_count(),
+            },
+            Compatibility::Breaking,
+        );
+    }
+
+    match (old, new) {
+        (Item::Struct(old), Item::Struct(new)) => compare_fields(&old.fields, &new.fields, changes),
+        (Item::Enum(old), Item::Enum(new)) => compare_enums(old, new, changes),
+        (Item::Alias(old), Item::Alias(new)) => {
+            if old.r#type != new.r#type {
+                changes.push(
+                    ChangeKind::AliasTypeChanged {
+                        old: display_type(&old.r#type),
+                        new: display_type(&new.r#type),
+                    },
+                    type_compatibility(&old.r#type, &new.r#type),
+                );
+            }
+        }
+        _ => changes.push(
+            ChangeKind::TypeKindChanged {
+                old: old.kind(),
+                new: new.kind(),
+            },
+            Compatibility::Breaking,
+        ),
+    }
+}
+
+/// A field that must be present in the serialized data.
+fn is_required(field: &RustField) -> bool {
+    !field.ty.is_optional() && !field.has_default
+}
+
+fn compare_fields(old: &[RustField], new: &[RustField], changes: &mut Changes) {
+    let mut added = new
+        .iter()
+        .filter(|f| !old.iter().any(|o| o.id.renamed == f.id.renamed))
+        .collect::<Vec<_>>();
+
+    for old_field in old {
+        let Some(new_field) = new.iter().find(|f| f.id.renamed == old_field.id.renamed) else {
+            // A field with the same Rust name under a different serialized name is a rename.
+            if let Some(index) = added
+                .iter()
+                .position(|f| f.id.original == old_field.id.original)
+            {
+                let new_field = added.remove(index);
+                changes.push(
+                    ChangeKind::FieldRenamed {
+                        old: old_field.id.renamed.clone(),
+                        new: new_field.id.renamed.clone(),
+                    },
+                    Compatibility::Breaking,
+                );
+                continue;
+            }
+
+            // Unknown fields are ignored by serde, so only old readers care.
+            let required = is_required(old_field);
+            changes.push(
+                ChangeKind::FieldRemoved {
+                    field: old_field.id.renamed.clone(),
+                    required,
+                },
+                if required {
+                    Compatibility::Backward
+                } else {
+                    Compatibility::Full
+                },
+            );
+            continue;
+        };
+
+        if old_field.ty != new_field.ty {
+            changes.push(
+                ChangeKind::FieldTypeChanged {
+                    field: new_field.id.renamed.clone(),
+                    old: display_type(&old_field.ty),
+                    new: display_type(&new_field.ty),
+                },
+                type_compatibility(&old_field.ty, &new_field.ty),
+            );
+        }
+
+        match (old_field.has_default, new_field.has_default) {
+            (false, true) => changes.push(
+                ChangeKind::FieldDefaultAdded {
+                    field: new_field.id.renamed.clone(),
+                },
+                Compatibility::Full,
+            ),
+            (true, false) => changes.push(
+                ChangeKind::FieldDefaultRemoved {
+                    field: new_field.id.renamed.clone(),
+                },
+                if new_field.ty.is_optional() {
+                    Compatibility::Full
+                } else {
+                    Compatibility::Forward
+                },
+            ),
+            _ => (),
+        }
+    }
+
+    for new_field in added {
+        // Old readers ignore the new key; new readers need it unless it can be defaulted.
+        let required = is_required(new_field);
+        changes.push(
+            ChangeKind::FieldAdded {
+                field: new_field.id.renamed.clone(),
+                required,
+            },
+            if required {
+                Compatibility::Forward
+            } else {
+                Compatibility::Full
+            },
+        );
+    }
+}
+
+fn compare_enums(old: &RustEnum, new: &RustEnum, changes: &mut Changes) {
+    match (old, new) {
+        (RustEnum::Unit(_), RustEnum::Unit(_)) => (),
+        (
+            RustEnum::Algebraic {
+                tag_key: old_tag,
+                content_key: old_content,
+                ..
+            },
+            RustEnum::Algebraic {
+                tag_key: new_tag,
+                content_key: new_content,
+                ..
+            },
+        ) => {
+            if old_tag != new_tag || old_content != new_content {
+                changes.push(ChangeKind::EnumTaggingChanged, Compatibility::Breaking);
+            }
+        }
+        _ => {
+            changes.push(
+                ChangeKind::TypeKindChanged {
+                    old: enum_kind(old),
+                    new: enum_kind(new),
+                },
+                Compatibility::Breaking,
+            );
+            return;
+        }
+    }
+
+    let old_variants = &old.shared().variants;
+    let new_variants = &new.shared().variants;
+    let serialized_name = |v: &RustEnumVariant| v.shared().id.renamed.clone();
+
+    let mut added = new_variants
+        .iter()
+        .filter(|v| {
+            !old_variants
+                .iter()
+                .any(|o| o.shared().id.renamed == v.shared().id.renamed)
+        })
+        .collect::<Vec<_>>();
+
+    for old_variant in old_variants {
+        let Some(new_variant) = new_variants
+            .iter()
+            .find(|v| v.shared().id.renamed == old_variant.shared().id.renamed)
+        else {
+            if let Some(index) = added
+                .iter()
+                .position(|v| v.shared().id.original == old_variant.shared().id.original)
+            {
+                let new_variant = added.remove(index);
+                changes.push(
+                    ChangeKind::VariantRenamed {
+                        old: serialized_name(old_variant),
+                        new: serialized_name(new_variant),
+                    },
+                    Compatibility::Breaking,
+                );
+                continue;
+            }
+
+            // Old data may still contain the variant, new data never will.
+            changes.push(
+                ChangeKind::VariantRemoved {
+                    variant: serialized_name(old_variant),
+                },
+                Compatibility::Forward,
+            );
+            continue;
+        };
+
+        match (old_variant, new_variant) {
+            (RustEnumVariant::Unit(_), RustEnumVariant::Unit(_)) => (),
+            (
+                RustEnumVariant::Tuple { ty: old_ty, .. },
+                RustEnumVariant::Tuple { ty: new_ty, .. },
+            ) => {
+                if old_ty != new_ty {
+                    changes.push(
+                        ChangeKind::VariantTypeChanged {
+                            variant: serialized_name(new_variant),
+                            old: display_type(old_ty),
+                            new: display_type(new_ty),
+                        },
+                        type_compatibility(old_ty, new_ty),
+                    );
+                }
+            }
+            (
+                RustEnumVariant::AnonymousStruct {
+                    fields: old_fields, ..
+                },
+                RustEnumVariant::AnonymousStruct {
+                    fields: new_fields, ..
+                },
+            ) => {
+                let type_name = changes.type_name.clone();
+                changes.type_name = format!("{type_name}::{}", new_variant.shared().id.original);
+                compare_fields(old_fields, new_fields, changes);
+                changes.type_name = type_name;
+            }
+            _ => changes.push(
+                ChangeKind::VariantKindChanged {
+                    variant: serialized_name(new_variant),
+                },
+                Compatibility::Breaking,
+            ),
+        }
+    }
+
+    for new_variant in added {
+        // New data may contain the variant, which old readers reject.
+        changes.push(
+            ChangeKind::VariantAdded {
+                variant: serialized_name(new_variant),
+            },
+            Compatibility::Backward,
+        );
+    }
+}
+
+fn enum_kind(e: &RustEnum) -> &'static str {
+    match e {
+        RustEnum::Unit(_) => "unit enum",
+        RustEnum::Algebraic { .. } => "algebraic enum",
+    }
+}
+
+/// Classify replacing the type `old` with `new` in a field, variant or alias.
+pub fn type_compatibility(old: &RustType, new: &RustType) -> Compatibility {
+    use SpecialRustType::*;
+
+    if old == new {
+        return Compatibility::Full;
+    }
+
+    match (old, new) {
+        (RustType::Special(Option(old)), RustType::Special(Option(new))) => {
+            type_compatibility(old, new)
+        }
+        // Old readers reject `null`.
+        (old, RustType::Special(Option(new))) => {
+            type_compatibility(old, new).and(Compatibility::Backward)
+        }
+        // New readers reject `null`.
+        (RustType::Special(Option(old)), new) => {
+            type_compatibility(old, new).and(Compatibility::Forward)
+        }
+        (RustType::Special(Vec(old) | Slice(old)), RustType::Special(Vec(new) | Slice(new))) => {
+            type_compatibility(old, new)
+        }
+        (RustType::Special(Array(old, old_len)), RustType::Special(Array(new, new_len))) => {
+            if old_len == new_len {
+                type_compatibility(old, new)
+            } else {
+                Compatibility::Breaking
+            }
+        }
+        // Arrays only accept sequences of their exact length.
+        (RustType::Special(Array(old, _)), RustType::Special(Vec(new) | Slice(new))) => {
+            type_compatibility(old, new).and(Compatibility::Backward)
+        }
+        (RustType::Special(Vec(old) | Slice(old)), RustType::Special(Array(new, _))) => {
+            type_compatibility(old, new).and(Compatibility::Forward)
+        }
+        (
+            RustType::Special(HashMap(old_key, old_value)),
+            RustType::Special(HashMap(new_key, new_value)),
+        ) => type_compatibility(old_key, new_key).and(type_compatibility(old_value, new_value)),
+        (RustType::Special(old), RustType::Special(new)) => {
+            match (contains(old, new), contains(new, old)) {
+                (true, true) => Compatibility::Full,
+                (true, false) => Compatibility::Backward,
+                (false, true) => Compatibility::Forward,
+                (false, false) => Compatibility::Breaking,
+            }
+        }
+        (
+            RustType::Generic {
+                id: old_id,
+                parameters: old_parameters,
+            },
+            RustType::Generic {
+                id: new_id,
+                parameters: new_parameters,
+            },
+        ) if old_id == new_id && old_parameters.len() == new_parameters.len() => old_parameters
+            .iter()
+            .zip(new_parameters)
+            .fold(Compatibility::Full, |compatibility, (old, new)| {
+                compatibility.and(type_compatibility(old, new))
+            }),
+        _ => Compatibility::Breaking,
+    }
+}
+
+/// The inclusive range of values an integer type can hold.
+fn integer_range(ty: &SpecialRustType) -> Option<(i128, i128)> {
+    use SpecialRustType::*;
+
+    const MAX_SAFE_INTEGER: i128 = (1 << 53) - 1;
+
+    Some(match ty {
+        I8 => (i8::MIN.into(), i8::MAX.into()),
+        I16 => (i16::MIN.into(), i16::MAX.into()),
+        I32 => (i32::MIN.into(), i32::MAX.into()),
+        I64 | ISize => (i64::MIN.into(), i64::MAX.into()),
+        I54 => (-MAX_SAFE_INTEGER, MAX_SAFE_INTEGER),
+        U8 => (0, u8::MAX.into()),
+        U16 => (0, u16::MAX.into()),
+        U32 => (0, u32::MAX.into()),
+        U64 | USize => (0, u64::MAX.into()),
+        U53 => (0, MAX_SAFE_INTEGER),
+        _ => return None,
+    })
+}
+
+/// Whether every JSON value accepted by `inner` is also accepted by `outer`.
+fn contains(inner: &SpecialRustType, outer: &SpecialRustType) -> bool {
+    use SpecialRustType::*;
+
+    // The largest integers floats of each width can represent exactly.
+    const F32_EXACT: i128 = 1 << 24;
+    const F64_EXACT: i128 = 1 << 53;
+
+    if inner == outer {
+        return true;
+    }
+
+    match (integer_range(inner), integer_range(outer)) {
+        (Some((inner_min, inner_max)), Some((outer_min, outer_max))) => {
+            return outer_min <= inner_min && inner_max <= outer_max
+        }
+        (Some((min, max)), None) => {
+            let exact = match outer {
+                F32 => F32_EXACT,
+                F64 => F64_EXACT,
+                _ => return false,
+            };
+            return -exact <= min && max <= exact;
+        }
+        _ => (),
+    }
+
+    matches!((inner, outer), (F32, F64) | (Char, String))
+}
+
+/// Render a type the way it is written in Rust.
+fn display_type(ty: &RustType) -> String {
+    use SpecialRustType::*;
+
+    match ty {
+        RustType::Simple { id } => id.clone(),
+        RustType::Generic { id, parameters } => {
+            format!("{id}<{}>", parameters.iter().map(display_type).join(", "))
+        }
+        RustType::Special(special) => match special {
+            Vec(ty) => format!("Vec<{}>", display_type(ty)),
+            Array(ty, len) => format!("[{}; {len}]", display_type(ty)),
+            Slice(ty) => format!("[{}]", display_type(ty)),
+            HashMap(key, value) => {
+                format!("HashMap<{}, {}>", display_type(key), display_type(value))
+            }
+            Option(ty) => format!("Option<{}>", display_type(ty)),
+            Unit => "()".to_owned(),
+            String => "String".to_owned(),
+            Char => "char".to_owned(),
+            I8 => "i8".to_owned(),
+            I16 => "i16".to_owned(),
+            I32 => "i32".to_owned(),
+            I64 => "i64".to_owned(),
+            U8 => "u8".to_owned(),
+            U16 => "u16".to_owned(),
+            U32 => "u32".to_owned(),
+            U64 => "u64".to_owned(),
+            ISize => "isize".to_owned(),
+            USize => "usize".to_owned(),
+            Bool => "bool".to_owned(),
+            F32 => "f32".to_owned(),
+            F64 => "f64".to_owned(),
+            I54 => "I54".to_owned(),
+            U53 => "U53".to_owned(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        context::{ParseContext, ParseFileContext},
+        parser::parse,
+    };
+
+    fn parsed(source: &str) -> ParsedData {
+        let parsed = parse(
+            &ParseContext::default(),
+            ParseFileContext {
+                source_code: source.to_owned(),
+                crate_name: crate::language::SINGLE_FILE_CRATE_NAME,
+                file_name: "input.rs".to_owned(),
+                file_path: "input.rs".into(),
+            },
+        )
+        .unwrap()
+        .unwrap();
+        assert!(parsed.errors.is_empty());
+        parsed
+    }
+
+    fn changes(old: &str, new: &str) -> Vec<String> {
+        compare(&parsed(old), &parsed(new))
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn unchanged_types_have_no_changes() {
+        let source = r#"
+            #[typeshare]
+            struct Foo { a: String, b: Option<u32> }
+        "#;
+        assert!(changes(source, source).is_empty());
+    }
+
+    #[test]
+    fn struct_fields() {
+        let old = r#"
+            #[typeshare]
+            struct Foo { kept: u16, required: String, optional: Option<String>, defaulted: bool }
+        "#;
+        let new = r#"
+            #[typeshare]
+            struct Foo {
+                kept: u32,
+                added_required: String,
+                added_optional: Option<String>,
+                #[serde(default)]
+                added_default: bool,
+                #[serde(default)]
+                defaulted: bool,
+            }
+        "#;
+        assert_eq!(
+            changes(old, new),
+            [
+                "backward compatible: `Foo`: field `kept` changed type from `u16` to `u32`",
+                "backward compatible: `Foo`: required field `required` removed",
+                "compatible: `Foo`: optional field `optional` removed",
+                "compatible: `Foo`: field `defaulted` gained `serde(default)`",
+                "forward compatible: `Foo`: required field `added_required` added",
+                "compatible: `Foo`: optional field `added_optional` added",
+                "compatible: `Foo`: optional field `added_default` added",
+            ]
+        );
+    }
+
+    #[test]
+    fn renamed_fields_and_variants_are_breaking() {
+        let old = r#"
+            #[typeshare]
+            struct Foo { field: String }
+            #[typeshare]
+            enum Bar { Variant }
+        "#;
+        let new = r#"
+            #[typeshare]
+            #[serde(rename_all = "camelCase")]
+            struct Foo { #[serde(rename = "renamed")] field: String }
+            #[typeshare]
+            #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+            enum Bar { Variant }
+        "#;
+        assert_eq!(
+            changes(old, new),
+            [
+                "breaking: `Bar`: variant `Variant` renamed to `VARIANT`",
+                "breaking: `Foo`: field `field` renamed to `renamed`",
+            ]
+        );
+    }
+
+    #[test]
+    fn enum_variants() {
+        let old = r#"
+            #[typeshare]
+            #[serde(tag = "type", content = "content")]
+            enum Foo {
+                Removed,
+                Tuple(u8),
+                Struct { a: String },
+                Kind(String),
+            }
+        "#;
+        let new = r#"
+            #[typeshare]
+            #[serde(tag = "type", content = "content")]
+            enum Foo {
+                Tuple(u16),
+                Struct { a: String, b: i32 },
+                Kind { value: String },
+                Added,
+            }
+        "#;
+        assert_eq!(
+            changes(old, new),
+            [
+                "forward compatible: `Foo`: variant `Removed` removed",
+                "backward compatible: `Foo`: variant `Tuple` changed type from `u8` to `u16`",
+                "forward compatible: `Foo::Struct`: required field `b` added",
+                "breaking: `Foo`: variant `Kind` changed between unit, tuple and struct form",
+                "backward compatible: `Foo`: variant `Added` added",
+            ]
+        );
+    }
+
+    #[test]
+    fn types_and_aliases() {
+        let old = r#"
+            #[typeshare]
+            type Alias = Vec<u32>;
+            #[typeshare]
+            struct Removed { a: String }
+            #[typeshare]
+            struct Kind { a: String }
+        "#;
+        let new = r#"
+            #[typeshare]
+            type Alias = Vec<u16>;
+            #[typeshare]
+            struct Added { a: String }
+            #[typeshare]
+            enum Kind { A }
+        "#;
+        assert_eq!(
+            changes(old, new),
+            [
+                "compatible: `Added`: type added",
+                "forward compatible: `Alias`: aliased type changed from `Vec<u32>` to `Vec<u16>`",
+                "breaking: `Kind`: changed from struct to enum",
+                "breaking: `Removed`: type removed",
+            ]
+        );
+    }
+
+    #[test]
+    fn type_narrowing_and_widening() {
+        let ty = |source: &str| RustType::try_from(&syn::parse_str::<syn::Type>(source).unwrap());
+        let check = |old: &str, new: &str| type_compatibility(&ty(old).unwrap(), &ty(new).unwrap());
+
+        assert_eq!(check("u8", "i16"), Compatibility::Backward);
+        assert_eq!(check("I54", "i32"), Compatibility::Forward);
+        assert_eq!(check("u32", "i32"), Compatibility::Breaking);
+        assert_eq!(check("u32", "f64"), Compatibility::Backward);
+        assert_eq!(check("U53", "f64"), Compatibility::Backward);
+        assert_eq!(check("U53", "f32"), Compatibility::Breaking);
+        assert_eq!(check("char", "String"), Compatibility::Backward);
+        assert_eq!(check("String", "Option<String>"), Compatibility::Backward);
+        assert_eq!(check("Option<u16>", "u8"), Compatibility::Forward);
+        assert_eq!(check("Option<u8>", "u16"), Compatibility::Breaking);
+        assert_eq!(check("Vec<u8>", "[u8; 4]"), Compatibility::Forward);
+        assert_eq!(
+            check("HashMap<String, u8>", "HashMap<String, u16>"),
+            Compatibility::Backward
+        );
+        assert_eq!(check("Foo<u8>", "Foo<i8>"), Compatibility::Breaking);
+        assert_eq!(check("Foo", "Bar"), Compatibility::Breaking);
+    }
+
+    #[test]
+    fn combining_compatibility() {
+        use Compatibility::*;
+
+        assert_eq!(Full.and(Backward), Backward);
+        assert_eq!(Backward.and(Backward), Backward);
+        assert_eq!(Backward.and(Forward), Breaking);
+        assert!(Full.satisfies(Backward));
+        assert!(Backward.satisfies(Backward));
+        assert!(!Forward.satisfies(Backward));
+        assert!(!Backward.satisfies(Full));
+    }
+}
@@ -1,6 +1,6 @@
 //! Context types for parsing.
 //!
-use crate::language::CrateName;
+use crate::{cargo::CrateManifests, filter::Filter, generator::ParseCache, language::CrateName};
 use std::path::PathBuf;
 
 /// Context for parsing rust source files.
@@ -12,6 +12,13 @@ pub struct ParseContext<'a> {
     pub multi_file: bool,
     /// `target_os` filtering.
     pub target_os: Vec<String>,
+    /// Manifests to look up crate names in for multi file output, instead of
+    /// using the directory containing `src`.
+    pub crate_manifests: Option<&'a CrateManifests>,
+    /// Cache of previously parsed files to reuse.
+    pub parse_cache: Option<&'a ParseCache>,
+    /// Source files and crates to skip.
+    pub filter: Option<&'a Filter>,
 }
 
 /// Parsing context for a single rust source file.
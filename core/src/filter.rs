@@ -0,0 +1,370 @@
+//! Selecting the files, crates and types to generate with glob patterns.
+//!
+//! Every kind of filter has an include and an exclude list. When an include list
+//! is given only matching items are generated, and matching an exclude list always
+//! removes an item. Including a type also includes every type it references, so
+//! selecting `ApiResponse` generates everything needed to use it.
+use crate::{
+    language::CrateName,
+    parser::ParsedData,
+    rust_types::{Id, RustEnumVariant, RustType},
+};
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    path::Path,
+};
+use thiserror::Error;
+
+/// An invalid glob pattern.
+#[derive(Debug, Error)]
+#[error(transparent)]
+pub struct FilterError(#[from] globset::Error);
+
+/// The glob patterns of a [`Filter`], as written in a configuration file.
+///
+/// Lists that are not set are taken from the configuration this one overrides.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(default, deny_unknown_fields)]
+pub struct FilterConfig {
+    /// Only parse source files matching one of these paths, relative to their input directory.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_paths: Option<Vec<String>>,
+    /// Never parse source files matching one of these paths, relative to their input directory.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclude_paths: Option<Vec<String>>,
+    /// Only parse source files of crates matching one of these names.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_crates: Option<Vec<String>>,
+    /// Never parse source files of crates matching one of these names.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclude_crates: Option<Vec<String>>,
+    /// Only generate types matching one of these names, and the types they reference.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_types: Option<Vec<String>>,
+    /// Never generate types matching one of these names.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclude_types: Option<Vec<String>>,
+}
+
+impl FilterConfig {
+    /// Replace the lists of this configuration with those set in `overrides`.
+    pub fn with_overrides(&self, overrides: &FilterConfig) -> FilterConfig {
+        let pick = |base: &Option<Vec<String>>, over: &Option<Vec<String>>| {
+            over.as_ref().or(base.as_ref()).cloned()
+        };
+        FilterConfig {
+            include_paths: pick(&self.include_paths, &overrides.include_paths),
+            exclude_paths: pick(&self.exclude_paths, &overrides.exclude_paths),
+            include_crates: pick(&self.include_crates, &overrides.include_crates),
+            exclude_crates: pick(&self.exclude_crates, &overrides.exclude_crates),
+            include_types: pick(&self.include_types, &overrides.include_types),
+            exclude_types: pick(&self.exclude_types, &overrides.exclude_types),
+        }
+    }
+
+    /// Whether no filters are set.
+    pub fn is_empty(&self) -> bool {
+        self == &FilterConfig::default()
+    }
+}
+
+/// An include and an exclude list of glob patterns.
+#[derive(Debug, Clone, Default)]
+struct Patterns {
+    include: Option<GlobSet>,
+    exclude: Option<GlobSet>,
+}
+
+impl Patterns {
+    fn new(
+        include: &Option<Vec<String>>,
+        exclude: &Option<Vec<String>>,
+    ) -> Result<Self, FilterError> {
+        let build = |patterns: &Option<Vec<String>>| {
+            patterns
+                .as_ref()
+                .map(|patterns| {
+                    patterns
+                        .iter()
+                        .try_fold(GlobSetBuilder::new(), |mut builder, pattern| {
+                            builder.add(Glob::new(pattern)?);
+                            Ok::<_, globset::Error>(builder)
+                        })?
+                        .build()
+                })
+                .transpose()
+        };
+        Ok(Self {
+            include: build(include)?,
+            exclude: build(exclude)?,
+        })
+    }
+
+    fn is_included(&self, path: impl AsRef<Path>) -> bool {
+        self.include
+            .as_ref()
+            .is_none_or(|include| include.is_match(path.as_ref()))
+            && !self.is_excluded(path)
+    }
+
+    fn is_excluded(&self, path: impl AsRef<Path>) -> bool {
+        self.exclude
+            .as_ref()
+            .is_some_and(|exclude| exclude.is_match(path))
+    }
+}
+
+/// Selects the source files, crates and types to generate.
+#[derive(Debug, Clone, Default)]
+pub struct Filter {
+    paths: Patterns,
+    crates: Patterns,
+    types: Patterns,
+}
+
+impl Filter {
+    /// Compile the glob patterns of a configuration.
+    pub fn new(config: &FilterConfig) -> Result<Self, FilterError> {
+        Ok(Self {
+            paths: Patterns::new(&config.include_paths, &config.exclude_paths)?,
+            crates: Patterns::new(&config.include_crates, &config.exclude_crates)?,
+            types: Patterns::new(&config.include_types, &config.exclude_types)?,
+        })
+    }
+
+    /// Whether a source file should be parsed, given its path relative to the
+    /// input directory and the crate it belongs to, if known.
+    ///
+    /// Files of unknown crates are only excluded when crates are explicitly included.
+    pub fn includes_file(&self, relative_path: &Path, crate_name: Option<&CrateName>) -> bool {
+        self.paths.is_included(relative_path)
+            && match crate_name {
+                Some(crate_name) => self.crates.is_included(crate_name.as_str()),
+                None => self.crates.include.is_none(),
+            }
+    }
+
+    /// Remove the types that should not be generated. Types referenced by an
+    /// included type are kept unless they are explicitly excluded.
+    pub fn retain_types(&self, crate_parsed_data: &mut BTreeMap<CrateName, ParsedData>) {
+        if self.types.include.is_none() && self.types.exclude.is_none() {
+            return;
+        }
+
+        // The names each type references, by the name it is referenced by.
+        let mut references = HashMap::<&str, Vec<&str>>::new();
+        let mut selected = Vec::new();
+
+        for parsed_data in crate_parsed_data.values() {
+            let types = parsed_data
+                .structs
+                .iter()
+                .map(|s| {
+                    let referenced = s
+                        .fields
+                        .iter()
+                        .flat_map(|f| f.ty.all_reference_type_names());
+                    (&s.id, referenced.collect::<Vec<_>>())
+                })
+                .chain(parsed_data.enums.iter().map(|e| {
+                    let referenced = e
+                        .shared()
+                        .variants
+                        .iter()
+                        .flat_map(variant_types)
+                        .flat_map(RustType::all_reference_type_names);
+                    (&e.shared().id, referenced.collect())
+                }))
+                .chain(
+                    parsed_data
+                        .aliases
+                        .iter()
+                        .map(|a| (&a.id, a.r#type.all_reference_type_names().collect())),
+                );
+
+            for (id, referenced) in types {
+                if self.matches_include(id) {
+                    selected.push(id.renamed.as_str());
+                }
+                references
+                    .entry(id.renamed.as_str())
+                    .or_default()
+                    .extend(referenced);
+            }
+        }
+
+        let mut included = HashSet::new();
+        while let Some(name) = selected.pop() {
+            if included.insert(name) {
+                selected.extend(references.get(name).into_iter().flatten());
+            }
+        }
+        let included = included
+            .into_iter()
+            .map(ToOwned::to_owned)
+            .collect::<HashSet<_>>();
+
+        let keep = |id: &Id| included.contains(&id.renamed) && !self.matches_exclude(id);
+        for parsed_data in crate_parsed_data.values_mut() {
+            parsed_data.structs.retain(|s| keep(&s.id));
+            parsed_data.enums.retain(|e| keep(&e.shared().id));
+            parsed_data.aliases.retain(|a| keep(&a.id));
+            parsed_data.type_names = parsed_data
+                .structs
+                .iter()
+                .map(|s| &s.id)
+                .chain(parsed_data.enums.iter().map(|e| &e.shared().id))
+                .chain(parsed_data.aliases.iter().map(|a| &a.id))
+                .map(|id| id.renamed.clone())
+                .collect();
+        }
+    }
+
+    fn matches_include(&self, id: &Id) -> bool {
+        self.types
+            .include
+            .as_ref()
+            .is_none_or(|include| include.is_match(&id.original) || include.is_match(&id.renamed))
+    }
+
+    fn matches_exclude(&self, id: &Id) -> bool {
+        self.types.is_excluded(&id.original) || self.types.is_excluded(&id.renamed)
+    }
+}
+
+/// The types contained in an enum variant.
+fn variant_types(variant: &RustEnumVariant) -> Vec<&RustType> {
+    match variant {
+        RustEnumVariant::Unit(_) => Vec::new(),
+        RustEnumVariant::Tuple { ty, .. } => vec![ty],
+        RustEnumVariant::AnonymousStruct { fields, .. } => {
+            fields.iter().map(|field| &field.ty).collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        context::{ParseContext, ParseFileContext},
+        parser::parse,
+    };
+
+    fn parsed(source_code: &str) -> BTreeMap<CrateName, ParsedData> {
+        let parsed_data = parse(
+            &ParseContext::default(),
+            ParseFileContext {
+                source_code: source_code.to_owned(),
+                crate_name: "my_crate".into(),
+                file_name: "my_crate.ts".into(),
+                file_path: "my_crate/src/lib.rs".into(),
+            },
+        )
+        .unwrap()
+        .unwrap();
+        assert!(parsed_data.errors.is_empty());
+        BTreeMap::from([("my_crate".into(), parsed_data)])
+    }
+
+    fn type_names(crate_parsed_data: &BTreeMap<CrateName, ParsedData>) -> Vec<&str> {
+        let mut names = crate_parsed_data
+            .values()
+            .flat_map(|data| data.type_names.iter().map(String::as_str))
+            .collect::<Vec<_>>();
+        names.sort();
+        names
+    }
+
+    const SOURCE: &str = r#"
+        #[typeshare]
+        pub struct ApiResponse { data: Vec<Payload>, status: Status }
+
+        #[typeshare]
+        #[serde(tag = "type", content = "content")]
+        pub enum Payload { User(UserId), Empty }
+
+        #[typeshare]
+        pub type UserId = String;
+
+        #[typeshare]
+        pub enum Status { Ok, Failed }
+
+        #[typeshare]
+        #[serde(rename = "RenamedInternal")]
+        pub struct Internal { secret: String }
+    "#;
+
+    #[test]
+    fn includes_referenced_types() {
+        let filter = Filter::new(&FilterConfig {
+            include_types: Some(vec!["Api*".to_owned()]),
+            exclude_types: Some(vec!["Status".to_owned()]),
+            ..Default::default()
+        })
+        .unwrap();
+
+        let mut crate_parsed_data = parsed(SOURCE);
+        filter.retain_types(&mut crate_parsed_data);
+        assert_eq!(
+            type_names(&crate_parsed_data),
+            ["ApiResponse", "Payload", "UserId"]
+        );
+    }
+
+    #[test]
+    fn excludes_by_original_or_renamed_name() {
+        for pattern in ["Internal", "Renamed*"] {
+            let filter = Filter::new(&FilterConfig {
+                exclude_types: Some(vec![pattern.to_owned()]),
+                ..Default::default()
+            })
+            .unwrap();
+
+            let mut crate_parsed_data = parsed(SOURCE);
+            filter.retain_types(&mut crate_parsed_data);
+            assert_eq!(
+                type_names(&crate_parsed_data),
+                ["ApiResponse", "Payload", "Status", "UserId"]
+            );
+        }
+    }
+
+    #[test]
+    fn filters_files_by_path_and_crate() {
+        let global = FilterConfig {
+            exclude_paths: Some(vec!["**/internal/**".to_owned()]),
+            include_crates: Some(vec!["api_*".to_owned()]),
+            ..Default::default()
+        };
+        let filter = Filter::new(&global).unwrap();
+
+        let api = CrateName::from("api_types");
+        assert!(filter.includes_file(Path::new("api/src/lib.rs"), Some(&api)));
+        assert!(!filter.includes_file(Path::new("api/src/internal/mod.rs"), Some(&api)));
+        assert!(!filter.includes_file(Path::new("other/src/lib.rs"), Some(&"other".into())));
+        assert!(!filter.includes_file(Path::new("lib.rs"), None));
+
+        // Per target overrides replace whole lists.
+        let filter = Filter::new(&global.with_overrides(&FilterConfig {
+            include_crates: Some(Vec::new()),
+            exclude_crates: Some(vec!["other".to_owned()]),
+            ..Default::default()
+        }))
+        .unwrap();
+        assert!(!filter.includes_file(Path::new("api/src/lib.rs"), Some(&api)));
+        assert!(!filter.includes_file(Path::new("api/src/internal/mod.rs"), Some(&api)));
+    }
+
+    #[test]
+    fn rejects_invalid_globs() {
+        assert!(Filter::new(&FilterConfig {
+            include_types: Some(vec!["[".to_owned()]),
+            ..Default::default()
+        })
+        .is_err());
+    }
+}
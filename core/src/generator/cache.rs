@@ -0,0 +1,268 @@
+//! On-disk cache of parsed source files.
+//!
+//! Entries are keyed by a SHA-256 hash of the typeshare version, the parse context,
+//! the file path and the file contents, so any change to one of them is a cache miss.
+//! Stale entries are never invalidated in place; they stop being looked up and are
+//! removed by [`ParseCache::prune`] once they have not been used for a while.
+use crate::{
+    context::{ParseContext, ParseFileContext},
+    parser::{parse, ParseError, ParsedData},
+};
+use log::{debug, warn};
+use sha2::{Digest, Sha256};
+use std::{
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+/// How long an entry is kept after it was last used.
+const MAX_AGE: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// A directory of cached parse results.
+#[derive(Debug, Clone)]
+pub struct ParseCache {
+    dir: PathBuf,
+}
+
+impl ParseCache {
+    /// Use `dir` to store cached parse results. It is created when first written to.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// The directory cached parse results are stored in.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Parse a file, reusing the cached result if the file was parsed the same way before.
+    ///
+    /// Files with parse errors are never cached, so their errors are reported on every run.
+    pub fn parse(
+        &self,
+        parse_context: &ParseContext,
+        parse_file_context: ParseFileContext,
+    ) -> Result<Option<ParsedData>, ParseError> {
+        // Files without typeshare annotations are cheaper to skip than to look up.
+        if !parse_file_context.source_code.contains("#[typeshare") {
+            return Ok(None);
+        }
+
+        let entry = self.dir.join(format!(
+            "{}.json",
+            cache_key(parse_context, &parse_file_context)
+        ));
+
+        if let Some(parsed_data) = fs::read(&entry)
+            .ok()
+            .and_then(|contents| serde_json::from_slice(&contents).ok())
+        {
+            debug!("cache hit for {:?}", parse_file_context.file_path);
+            // Entries are pruned by age, so mark this one as recently used.
+            if let Err(err) = fs::File::options()
+                .write(true)
+                .open(&entry)
+                .and_then(|file| file.set_modified(SystemTime::now()))
+            {
+                warn!("failed to refresh parse cache entry {entry:?}: {err}");
+            }
+            return Ok(Some(parsed_data));
+        }
+
+        let parsed_data = parse(parse_context, parse_file_context)?;
+        if let Some(parsed_data) = parsed_data.as_ref().filter(|data| data.errors.is_empty()) {
+            if let Err(err) = self.store(&entry, parsed_data) {
+                warn!("failed to write parse cache entry {entry:?}: {err}");
+            }
+        }
+        Ok(parsed_data)
+    }
+
+    fn store(&self, entry: &Path, parsed_data: &ParsedData) -> std::io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        // Write to a temporary file first so concurrent runs never read a partial entry.
+        let temporary = entry.with_extension(format!("{}.tmp", std::process::id()));
+        fs::write(&temporary, serde_json::to_vec(parsed_data)?)?;
+        fs::rename(temporary, entry)
+    }
+
+    /// Remove entries, and temporary files left behind by interrupted runs, that
+    /// have not been used for a week. Returns the number of removed files.
+    pub fn prune(&self) -> std::io::Result<usize> {
+        self.prune_older_than(MAX_AGE)
+    }
+
+    fn prune_older_than(&self, max_age: Duration) -> std::io::Result<usize> {
+        let dir = match fs::read_dir(&self.dir) {
+            Ok(dir) => dir,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+            Err(err) => return Err(err),
+        };
+
+        let now = SystemTime::now();
+        let mut removed = 0;
+        for entry in dir {
+            let entry = entry?;
+            let path = entry.path();
+            // Only touch files this cache could have written.
+            let is_cache_file = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.ends_with(".json") || name.ends_with(".tmp"));
+            if !is_cache_file {
+                continue;
+            }
+
+            let age = entry
+                .metadata()?
+                .modified()
+                .map(|modified| now.duration_since(modified).unwrap_or_default())?;
+            if age > max_age {
+                fs::remove_file(&path)?;
+                removed += 1;
+            }
+        }
+        if removed > 0 {
+            debug!("pruned {removed} parse cache entries from {:?}", self.dir);
+        }
+        Ok(removed)
+    }
+}
+
+/// A SHA-256 hash of everything that influences the result of parsing a file.
+fn cache_key(parse_context: &ParseContext, parse_file_context: &ParseFileContext) -> String {
+    let mut hasher = Sha256::new();
+    // Prefix every value with its length, so adjacent values can't run into each other.
+    let mut update = |value: &[u8]| {
+        hasher.update((value.len() as u64).to_le_bytes());
+        hasher.update(value);
+    };
+    update(env!("CARGO_PKG_VERSION").as_bytes());
+    update(&(parse_context.ignored_types.len() as u64).to_le_bytes());
+    for ignored_type in &parse_context.ignored_types {
+        update(ignored_type.as_bytes());
+    }
+    update(&[parse_context.multi_file as u8]);
+    update(&(parse_context.target_os.len() as u64).to_le_bytes());
+    for target_os in &parse_context.target_os {
+        update(target_os.as_bytes());
+    }
+    update(parse_file_context.crate_name.as_str().as_bytes());
+    update(parse_file_context.file_name.as_bytes());
+    update(parse_file_context.file_path.as_os_str().as_encoded_bytes());
+    update(parse_file_context.source_code.as_bytes());
+
+    hasher
+        .finalize()
+        .iter()
+        .fold(String::new(), |mut key, byte| {
+            let _ = write!(key, "{byte:02x}");
+            key
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn file_context(source_code: &str) -> ParseFileContext {
+        ParseFileContext {
+            source_code: source_code.to_owned(),
+            crate_name: "my_crate".into(),
+            file_name: "my_crate.ts".into(),
+            file_path: "my_crate/src/lib.rs".into(),
+        }
+    }
+
+    fn entries(cache: &ParseCache) -> usize {
+        fs::read_dir(cache.dir()).map_or(0, |dir| dir.count())
+    }
+
+    /// A cache in a fresh temporary directory, which is removed again when dropped.
+    struct TemporaryCache(ParseCache);
+
+    impl TemporaryCache {
+        fn new(name: &str) -> Self {
+            let cache = ParseCache::new(
+                std::env::temp_dir().join(format!("typeshare-{name}-{}", std::process::id())),
+            );
+            let _ = fs::remove_dir_all(cache.dir());
+            Self(cache)
+        }
+    }
+
+    impl Drop for TemporaryCache {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(self.0.dir());
+        }
+    }
+
+    #[test]
+    fn reuses_parsed_files() {
+        let cache = TemporaryCache::new("reuses-parsed-files");
+        let cache = &cache.0;
+        let parse_context = ParseContext::default();
+        let source = "#[typeshare]\nstruct Foo { bar: String }";
+
+        let parsed = cache.parse(&parse_context, file_context(source)).unwrap();
+        assert_eq!(entries(cache), 1);
+
+        let cached = cache.parse(&parse_context, file_context(source)).unwrap();
+        assert_eq!(entries(cache), 1);
+        assert_eq!(
+            serde_json::to_string(&parsed).unwrap(),
+            serde_json::to_string(&cached).unwrap()
+        );
+
+        // A different parse context is a different entry.
+        let parse_context = ParseContext {
+            target_os: vec!["android".to_owned()],
+            ..Default::default()
+        };
+        cache.parse(&parse_context, file_context(source)).unwrap();
+        assert_eq!(entries(cache), 2);
+
+        // Files without annotations and files with errors are not cached.
+        cache
+            .parse(&parse_context, file_context("struct Foo;"))
+            .unwrap();
+        let errors = cache
+            .parse(
+                &parse_context,
+                file_context("#[typeshare]\nstruct Foo(u8, u8);"),
+            )
+            .unwrap();
+        assert!(!errors.unwrap().errors.is_empty());
+        assert_eq!(entries(cache), 2);
+    }
+
+    #[test]
+    fn prunes_unused_entries() {
+        let cache = TemporaryCache::new("prunes-unused-entries");
+        let cache = &cache.0;
+        let parse_context = ParseContext::default();
+        let old = file_context("#[typeshare]\nstruct Old { bar: String }");
+        let used = file_context("#[typeshare]\nstruct Used { bar: String }");
+        cache.parse(&parse_context, old).unwrap();
+        cache.parse(&parse_context, used).unwrap();
+        assert_eq!(entries(cache), 2);
+
+        // Backdate both entries, then use one of them again.
+        let last_month = SystemTime::now() - Duration::from_secs(30 * 24 * 60 * 60);
+        for entry in fs::read_dir(cache.dir()).unwrap() {
+            fs::File::options()
+                .write(true)
+                .open(entry.unwrap().path())
+                .and_then(|file| file.set_modified(last_month))
+                .unwrap();
+        }
+        let used = file_context("#[typeshare]\nstruct Used { bar: String }");
+        cache.parse(&parse_context, used).unwrap();
+
+        assert_eq!(cache.prune().unwrap(), 1);
+        assert_eq!(entries(cache), 1);
+        assert_eq!(cache.prune().unwrap(), 0);
+    }
+}
@@ -0,0 +1,215 @@
+//! Where the file of each crate goes when writing one file per crate.
+use crate::{
+    language::{CrateName, SupportedLanguage},
+    RenameExt,
+};
+use std::path::{Component, Path, PathBuf};
+use thiserror::Error;
+
+/// The placeholders for the crate name.
+const CRATE_PLACEHOLDERS: [&str; 4] = ["{crate}", "{Crate}", "{crate_snake}", "{crate_camel}"];
+
+/// Errors in a file layout template.
+#[derive(Debug, Error)]
+#[allow(missing_docs)]
+pub enum FileLayoutError {
+    #[error("file layout {0:?} doesn't contain the crate name, so every crate would be written to the same file")]
+    MissingCrateName(String),
+    #[error("file layout {0:?} isn't a relative path inside of the output folder")]
+    OutsideOutputFolder(String),
+}
+
+/// A template for the path of each crate's generated file, relative to the output
+/// folder, like `{crate}/index.ts` or `Sources/{Crate}/{Crate}.swift`.
+///
+/// The placeholders are:
+/// - `{crate}`: the crate name as is
+/// - `{Crate}`: the crate name in PascalCase
+/// - `{crate_snake}`: the crate name in snake_case
+/// - `{crate_camel}`: the crate name in camelCase
+/// - `{ext}`: the file extension of the language
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileLayout {
+    template: String,
+}
+
+impl FileLayout {
+    /// Check that `template` names one file per crate inside of the output folder.
+    pub fn new(template: impl Into<String>) -> Result<Self, FileLayoutError> {
+        let template = template.into();
+        if !CRATE_PLACEHOLDERS
+            .iter()
+            .any(|placeholder| template.contains(placeholder))
+        {
+            return Err(FileLayoutError::MissingCrateName(template));
+        }
+        if !Path::new(&template)
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+        {
+            return Err(FileLayoutError::OutsideOutputFolder(template));
+        }
+        Ok(Self { template })
+    }
+
+    /// The layout used when none is configured: `{Crate}.{ext}` for Swift, Java and C#,
+    /// and `{crate}.{ext}` for every other language.
+    pub fn default_for(language_type: SupportedLanguage) -> Self {
+        let template = match language_type {
+            SupportedLanguage::Swift | SupportedLanguage::Java | SupportedLanguage::CSharp => {
+                "{Crate}.{ext}"
+            }
+            SupportedLanguage::Go
+            | SupportedLanguage::Kotlin
+            | SupportedLanguage::Scala
+            | SupportedLanguage::TypeScript
+            | SupportedLanguage::Python
+            | SupportedLanguage::Dart
+            | SupportedLanguage::JsonSchema
+            | SupportedLanguage::OpenApi
+            | SupportedLanguage::Protobuf
+            | SupportedLanguage::GraphQl => "{crate}.{ext}",
+        };
+        Self {
+            template: template.to_owned(),
+        }
+    }
+
+    /// The path of the file to write the types of `crate_name` to.
+    pub fn file_name(&self, crate_name: &CrateName, language_type: SupportedLanguage) -> String {
+        let name = crate_name.to_string();
+        let snake_case = || name.replace('-', "_");
+        let mut file_name = self
+            .template
+            .replace("{ext}", language_type.language_extension());
+        let placeholders: [(&str, &dyn Fn() -> String); 4] = [
+            ("{crate}", &|| name.clone()),
+            ("{Crate}", &|| name.to_pascal_case()),
+            ("{crate_snake}", &|| snake_case().to_snake_case()),
+            ("{crate_camel}", &|| snake_case().to_camel_case()),
+        ];
+        // Only convert the name for the placeholders in use, as the case conversions
+        // don't handle the empty name of a single output file.
+        for (placeholder, value) in placeholders {
+            if file_name.contains(placeholder) {
+                file_name = file_name.replace(placeholder, &value());
+            }
+        }
+        file_name
+    }
+}
+
+/// The path of the file `to` without its extension, relative to the directory of
+/// the file `from`, like `./other` or `../other/index`. Both files are relative
+/// to the output folder.
+pub fn relative_import_path(from: &str, to: &str) -> String {
+    let mut from_dir = from.split('/').collect::<Vec<_>>();
+    from_dir.pop();
+    let to = Path::new(to).with_extension("");
+    let to = to.to_string_lossy();
+    let to_parts = to.split('/').collect::<Vec<_>>();
+
+    let common = from_dir
+        .iter()
+        .zip(&to_parts[..to_parts.len() - 1])
+        .take_while(|(from, to)| from == to)
+        .count();
+
+    let up = match from_dir.len() - common {
+        0 => vec!["."],
+        levels => vec![".."; levels],
+    };
+    up.into_iter()
+        .chain(to_parts[common..].iter().copied())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// The path of another crate's file inside of the package, from its path relative
+/// to the file being generated.
+pub fn package_path(file_name: &str, relative_path: &str) -> String {
+    let mut path = PathBuf::new();
+    for component in Path::new(file_name)
+        .parent()
+        .unwrap_or(Path::new(""))
+        .join(relative_path)
+        .components()
+    {
+        match component {
+            Component::ParentDir => {
+                path.pop();
+            }
+            Component::Normal(part) => path.push(part),
+            _ => {}
+        }
+    }
+    path.iter()
+        .map(|part| part.to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod test {
+    use super::{package_path, relative_import_path, FileLayout};
+    use crate::language::{CrateName, SupportedLanguage};
+
+    #[test]
+    fn fills_in_crate_name() {
+        let crate_name = CrateName::from("user_accounts");
+        let file_name = |template: &str, language_type| {
+            FileLayout::new(template)
+                .unwrap()
+                .file_name(&crate_name, language_type)
+        };
+
+        assert_eq!(
+            file_name("{crate}/index.{ext}", SupportedLanguage::TypeScript),
+            "user_accounts/index.ts"
+        );
+        assert_eq!(
+            file_name("Sources/{Crate}/{Crate}.swift", SupportedLanguage::Swift),
+            "Sources/UserAccounts/UserAccounts.swift"
+        );
+        assert_eq!(
+            file_name("{crate_camel}.kt", SupportedLanguage::Kotlin),
+            "userAccounts.kt"
+        );
+        assert_eq!(
+            FileLayout::default_for(SupportedLanguage::Swift)
+                .file_name(&crate_name, SupportedLanguage::Swift),
+            "UserAccounts.swift"
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_templates() {
+        assert!(FileLayout::new("types.ts").is_err());
+        assert!(FileLayout::new("../{crate}.ts").is_err());
+        assert!(FileLayout::new("/tmp/{crate}.ts").is_err());
+    }
+
+    #[test]
+    fn imports_relative_to_file() {
+        assert_eq!(relative_import_path("first.ts", "second.ts"), "./second");
+        assert_eq!(
+            relative_import_path("first/index.ts", "second/index.ts"),
+            "../second/index"
+        );
+        assert_eq!(
+            relative_import_path("a/b/first.py", "a/second.py"),
+            "../second"
+        );
+        assert_eq!(
+            relative_import_path("a/first.py", "a/b/second.py"),
+            "./b/second"
+        );
+        assert_eq!(relative_import_path("a/first.py", "a.py"), "../a");
+    }
+
+    #[test]
+    fn resolves_package_paths() {
+        assert_eq!(package_path("first.dart", "./second"), "second");
+        assert_eq!(package_path("a/first.dart", "../b/second"), "b/second");
+    }
+}
@@ -0,0 +1,334 @@
+//! The full typeshare pipeline: find rust sources, parse them, reconcile renamed
+//! types and write the generated code.
+//!
+//! This is what the `typeshare` command line tool runs, and can be called directly
+//! from a build script:
+//!
+//! ```no_run
+//! use typeshare_core::{
+//!     generator::{Generator, Output},
+//!     language::{SupportedLanguage, TypeScript},
+//! };
+//!
+//! Generator::new()
+//!     .lang(SupportedLanguage::TypeScript, Box::new(TypeScript::default()))
+//!     .input("src")
+//!     .output(Output::File("bindings/types.ts".into()))
+//!     .run()
+//!     .expect("typeshare failed");
+//! ```
+mod cache;
+mod layout;
+mod parse;
+mod templates;
+mod writer;
+
+use crate::{
+    cargo::{CrateManifests, ManifestError},
+    context::ParseContext,
+    filter::Filter,
+    language::{Language, SupportedLanguage},
+    parser::{ErrorInfo, ParseError},
+    reconcile::reconcile_aliases,
+    GenerationError, TypeOrdering,
+};
+use ignore::{overrides::OverrideBuilder, types::TypesBuilder, WalkBuilder};
+use log::{error, info, warn};
+use std::{collections::HashMap, io, path::Path, path::PathBuf};
+use thiserror::Error;
+
+pub use cache::ParseCache;
+pub use layout::{package_path, relative_import_path, FileLayout, FileLayoutError};
+pub use parse::{all_types, parallel_parse, parse_source};
+pub use templates::FileTemplates;
+pub use writer::{write_generated, MANIFEST_FILE_NAME};
+
+/// Where generated code is written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Output {
+    /// Write all types to a single file.
+    File(PathBuf),
+    /// Write one file per crate into a folder.
+    Folder(PathBuf),
+    /// Write all types to standard output. There is nothing to compare against in
+    /// check mode, so the generated code is always written.
+    Stdout,
+}
+
+/// Errors that can occur while running the generator.
+#[derive(Debug, Error)]
+#[allow(missing_docs)]
+pub enum GeneratorError {
+    #[error("no language was specified")]
+    MissingLanguage,
+    #[error("no input directories were specified")]
+    MissingInput,
+    #[error("source code can only be generated into a single file")]
+    SourceToFolder,
+    #[error("no output was specified")]
+    MissingOutput,
+    #[error("failed traversing input: {0}")]
+    Walk(#[from] ignore::Error),
+    #[error(transparent)]
+    Manifest(#[from] ManifestError),
+    #[error("parsing failed: {path:?}: {source}")]
+    Parse { path: PathBuf, source: ParseError },
+    #[error("errors encountered during parsing")]
+    ParseErrors(Vec<ErrorInfo>),
+    #[error("could not get parsed data for single file output")]
+    MissingParsedData,
+    #[error("failed to generate types: {0}")]
+    Generate(#[from] io::Error),
+    #[error("failed to write output {path:?}: {source}")]
+    Write { path: PathBuf, source: io::Error },
+    #[error(transparent)]
+    PostGeneration(#[from] GenerationError),
+    #[error("generated files are out of date")]
+    OutOfDate {
+        changed: Vec<PathBuf>,
+        orphaned: Vec<PathBuf>,
+    },
+}
+
+/// Builder for a complete typeshare run.
+#[derive(Default)]
+pub struct Generator<'a> {
+    language: Option<(SupportedLanguage, Box<dyn Language + 'a>)>,
+    inputs: Vec<PathBuf>,
+    source: Option<String>,
+    output: Option<Output>,
+    target_os: Vec<String>,
+    follow_links: bool,
+    cargo_manifests: bool,
+    parse_cache: Option<ParseCache>,
+    filter: Option<Filter>,
+    file_layout: Option<FileLayout>,
+    templates: FileTemplates,
+    type_ordering: TypeOrdering,
+    check: bool,
+}
+
+impl<'a> Generator<'a> {
+    /// Create a generator with no language, inputs or output set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The language to generate and its configured implementation.
+    ///
+    /// Languages with their own multi file setting, like `Swift::multi_file`,
+    /// should have it match the [`Output`] given to [`Generator::output`].
+    pub fn lang(
+        mut self,
+        language_type: SupportedLanguage,
+        language: Box<dyn Language + 'a>,
+    ) -> Self {
+        self.language = Some((language_type, language));
+        self
+    }
+
+    /// Add a directory to recursively search for rust source files.
+    pub fn input(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.inputs.push(directory.into());
+        self
+    }
+
+    /// Add several directories to recursively search for rust source files.
+    pub fn inputs(mut self, directories: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+        self.inputs.extend(directories.into_iter().map(Into::into));
+        self
+    }
+
+    /// Parse this source code instead of searching input directories, like a snippet
+    /// read from standard input. It can only be generated into a single file.
+    pub fn source(mut self, source_code: impl Into<String>) -> Self {
+        self.source = Some(source_code.into());
+        self
+    }
+
+    /// Where to write the generated code.
+    pub fn output(mut self, output: Output) -> Self {
+        self.output = Some(output);
+        self
+    }
+
+    /// Only generate types, variants and fields available on these `target_os` values.
+    pub fn target_os(mut self, target_os: Vec<String>) -> Self {
+        self.target_os = target_os;
+        self
+    }
+
+    /// Follow symbolic links to directories instead of ignoring them.
+    pub fn follow_links(mut self, follow_links: bool) -> Self {
+        self.follow_links = follow_links;
+        self
+    }
+
+    /// Name crates after the packages in their `Cargo.toml` manifests when writing
+    /// one file per crate, instead of after the directory containing `src`.
+    pub fn cargo_manifests(mut self, cargo_manifests: bool) -> Self {
+        self.cargo_manifests = cargo_manifests;
+        self
+    }
+
+    /// Cache parsed files in `dir`, so unchanged files are not parsed again on later runs.
+    pub fn cache_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.parse_cache = Some(ParseCache::new(dir));
+        self
+    }
+
+    /// Only generate the source files, crates and types selected by `filter`.
+    pub fn filter(mut self, filter: Filter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Where to write the file of each crate when writing one file per crate,
+    /// instead of [`FileLayout::default_for`] the language.
+    pub fn file_layout(mut self, file_layout: FileLayout) -> Self {
+        self.file_layout = Some(file_layout);
+        self
+    }
+
+    /// Write a header and footer around the generated code of each file. The header
+    /// is written in addition to the version header of the language, which can be
+    /// turned off with its `no_version_header` option.
+    pub fn file_templates(mut self, templates: FileTemplates) -> Self {
+        self.templates = templates;
+        self
+    }
+
+    /// The order to write the types of each file in, instead of
+    /// [`TypeOrdering::Legacy`].
+    pub fn type_ordering(mut self, type_ordering: TypeOrdering) -> Self {
+        self.type_ordering = type_ordering;
+        self
+    }
+
+    /// Only check that the generated code on disk is up to date, without writing or
+    /// removing anything. Out of date files are reported as [`GeneratorError::OutOfDate`].
+    pub fn check(mut self, check: bool) -> Self {
+        self.check = check;
+        self
+    }
+
+    /// Parse all inputs and write the generated code.
+    pub fn run(self) -> Result<(), GeneratorError> {
+        let (language_type, mut lang) = self.language.ok_or(GeneratorError::MissingLanguage)?;
+        let output = self.output.ok_or(GeneratorError::MissingOutput)?;
+        if self.inputs.is_empty() && self.source.is_none() {
+            return Err(GeneratorError::MissingInput);
+        }
+
+        let multi_file = matches!(output, Output::Folder(_));
+        if multi_file && self.source.is_some() {
+            return Err(GeneratorError::SourceToFolder);
+        }
+
+        let crate_manifests = (multi_file && self.cargo_manifests)
+            .then(|| CrateManifests::discover(&self.inputs))
+            .transpose()?;
+
+        let parse_context = ParseContext {
+            ignored_types: lang.ignored_reference_types(),
+            multi_file,
+            target_os: self.target_os,
+            crate_manifests: crate_manifests.as_ref(),
+            parse_cache: self.parse_cache.as_ref(),
+            filter: self.filter.as_ref(),
+        };
+
+        let mut parsed_data = match self.source {
+            Some(source_code) => parse_source(&parse_context, source_code, language_type)?,
+            None => parallel_parse(
+                &parse_context,
+                walker_builder(&self.inputs, self.follow_links)?,
+                language_type,
+            )?,
+        };
+
+        if let Some(cache) = &self.parse_cache {
+            if let Err(err) = cache.prune() {
+                warn!("failed to prune parse cache {:?}: {err}", cache.dir());
+            }
+        }
+
+        reconcile_aliases(&mut parsed_data);
+
+        if let Some(filter) = &self.filter {
+            filter.retain_types(&mut parsed_data);
+        }
+
+        if let Some(file_layout) = self.file_layout.as_ref().filter(|_| multi_file) {
+            for (crate_name, data) in parsed_data.iter_mut() {
+                data.file_name = file_layout.file_name(crate_name, language_type);
+            }
+        }
+
+        for data in parsed_data.values_mut() {
+            data.type_ordering = self.type_ordering;
+        }
+
+        // Collect all the types into a map of the file name they
+        // belong too and the list of type names. Used for generating
+        // imports in generated files.
+        let import_candidates = if multi_file {
+            all_types(&mut parsed_data)
+        } else {
+            HashMap::new()
+        };
+
+        let errors = parsed_data
+            .values_mut()
+            .flat_map(|data| data.errors.drain(..))
+            .collect::<Vec<_>>();
+        if !errors.is_empty() {
+            for error in &errors {
+                error!(
+                    "Parsing error: \"{}\" in file \"{}\"",
+                    error.error, error.file_name
+                );
+            }
+            return Err(GeneratorError::ParseErrors(errors));
+        }
+
+        info!("typeshare started writing generated types");
+
+        write_generated(
+            &output,
+            lang.as_mut(),
+            parsed_data,
+            import_candidates,
+            &self.templates,
+            self.check,
+        )
+    }
+}
+
+/// Build a walker over all rust source files in the given directories.
+pub fn walker_builder(
+    directories: &[PathBuf],
+    follow_links: bool,
+) -> Result<WalkBuilder, GeneratorError> {
+    let mut types = TypesBuilder::new();
+    types.add("rust", "*.rs")?;
+    types.select("rust");
+
+    let Some(first_root) = directories.first() else {
+        return Err(GeneratorError::MissingInput);
+    };
+    let overrides = OverrideBuilder::new(first_root)
+        // Don't process files inside of tools/typeshare/
+        .add("!**/tools/typeshare/**")?
+        .build()?;
+    let mut walker_builder = WalkBuilder::new(first_root);
+    walker_builder
+        .sort_by_file_path(Path::cmp)
+        .types(types.build()?)
+        .overrides(overrides)
+        .follow_links(follow_links);
+    for root in directories.iter().skip(1) {
+        walker_builder.add(root);
+    }
+    Ok(walker_builder)
+}
@@ -0,0 +1,223 @@
+//! Source file parsing.
+use super::{FileLayout, GeneratorError};
+use crate::{
+    context::{ParseContext, ParseFileContext},
+    language::{CrateName, CrateTypes, SupportedLanguage, SINGLE_FILE_CRATE_NAME},
+    parser::{ParseError, ParsedData},
+};
+use crossbeam::channel::bounded;
+use ignore::{DirEntry, WalkBuilder, WalkState};
+use std::{
+    collections::{BTreeMap, HashMap},
+    mem,
+    path::PathBuf,
+    thread,
+};
+
+/// The path reported for source code that wasn't read from a file.
+const SOURCE_PATH: &str = "<source>";
+
+fn parse_file_context(
+    parse_context: &ParseContext,
+    language_type: SupportedLanguage,
+    dir_entry: &DirEntry,
+) -> Result<Option<ParseFileContext>, ParseError> {
+    let crate_name = match parse_context.crate_manifests {
+        Some(manifests) => manifests.crate_name(dir_entry.path()),
+        None => CrateName::find_crate_name(dir_entry.path()),
+    };
+
+    if let Some(filter) = parse_context.filter {
+        // The walker's root is as many levels up as the entry is deep.
+        let relative_path = dir_entry
+            .path()
+            .ancestors()
+            .nth(dir_entry.depth())
+            .and_then(|root| dir_entry.path().strip_prefix(root).ok())
+            .unwrap_or(dir_entry.path());
+        if !filter.includes_file(relative_path, crate_name.as_ref()) {
+            return Ok(None);
+        }
+    }
+
+    let crate_name = if parse_context.multi_file {
+        let Some(crate_name) = crate_name else {
+            return Ok(None);
+        };
+        crate_name
+    } else {
+        SINGLE_FILE_CRATE_NAME
+    };
+    let file_path = dir_entry.path().to_path_buf();
+    let out_file_name = output_file_name(language_type, &crate_name);
+
+    let input_file = file_path
+        .to_str()
+        .map(ToOwned::to_owned)
+        .unwrap_or_default();
+
+    let parse_file_context = ParseFileContext {
+        source_code: std::fs::read_to_string(&file_path).map_err(|err| {
+            ParseError::IOError(format!("Failed to read input: {input_file}: {err}"))
+        })?,
+        crate_name,
+        file_name: out_file_name,
+        file_path,
+    };
+
+    Ok(Some(parse_file_context))
+}
+
+/// The output file name to write to.
+fn output_file_name(language_type: SupportedLanguage, crate_name: &CrateName) -> String {
+    FileLayout::default_for(language_type).file_name(crate_name, language_type)
+}
+
+/// Collect all the typeshared types into a mapping of crate names to typeshared types. This
+/// mapping is used to lookup and generated import statements for generated files.
+pub fn all_types(file_mappings: &mut BTreeMap<CrateName, ParsedData>) -> CrateTypes {
+    file_mappings
+        .iter_mut()
+        .map(|(crate_name, parsed_data)| (crate_name, mem::take(&mut parsed_data.type_names)))
+        .fold(
+            HashMap::new(),
+            |mut import_map: CrateTypes, (crate_name, type_names)| {
+                import_map
+                    .entry(crate_name.clone())
+                    .or_default()
+                    .extend(type_names);
+                import_map
+            },
+        )
+}
+
+fn parse_dir_entry(
+    parse_context: &ParseContext,
+    language_type: SupportedLanguage,
+    dir_entry: &DirEntry,
+) -> Result<Option<ParsedData>, ParseError> {
+    if dir_entry.path().is_dir() {
+        return Ok(None);
+    }
+
+    let Some(parse_file_context) = parse_file_context(parse_context, language_type, dir_entry)?
+    else {
+        return Ok(None);
+    };
+
+    let source_crate = if parse_context.multi_file {
+        Some(parse_file_context.crate_name.clone())
+    } else {
+        CrateName::find_crate_name(dir_entry.path())
+    };
+
+    let mut parsed_data = match parse_context.parse_cache {
+        Some(cache) => cache.parse(parse_context, parse_file_context)?,
+        None => crate::parser::parse(parse_context, parse_file_context)?,
+    };
+
+    if let Some(parsed_data) = parsed_data.as_mut() {
+        parsed_data.source_crates.extend(source_crate);
+    }
+
+    // Resolve imports through renamed dependencies to the crates they refer to.
+    if let (Some(manifests), Some(parsed_data)) =
+        (parse_context.crate_manifests, parsed_data.as_mut())
+    {
+        parsed_data.import_types = mem::take(&mut parsed_data.import_types)
+            .into_iter()
+            .map(|mut import| {
+                if let Some(crate_name) =
+                    manifests.resolve_dependency(dir_entry.path(), &import.base_crate)
+                {
+                    import.base_crate = crate_name;
+                }
+                import
+            })
+            .collect();
+    }
+
+    Ok(parsed_data)
+}
+
+/// Parse source code that wasn't read from a file, as the only crate of single file output.
+///
+/// Source code without any typeshared types generates an empty file.
+pub fn parse_source(
+    parse_context: &ParseContext,
+    source_code: String,
+    language_type: SupportedLanguage,
+) -> Result<BTreeMap<CrateName, ParsedData>, GeneratorError> {
+    let file_path = PathBuf::from(SOURCE_PATH);
+    let file_name = output_file_name(language_type, &SINGLE_FILE_CRATE_NAME);
+    let parse_file_context = ParseFileContext {
+        source_code,
+        crate_name: SINGLE_FILE_CRATE_NAME,
+        file_name: file_name.clone(),
+        file_path: file_path.clone(),
+    };
+
+    let parsed_data = crate::parser::parse(parse_context, parse_file_context)
+        .map_err(|source| GeneratorError::Parse {
+            path: file_path,
+            source,
+        })?
+        .unwrap_or_else(|| ParsedData {
+            crate_name: SINGLE_FILE_CRATE_NAME,
+            file_name,
+            ..Default::default()
+        });
+
+    Ok(BTreeMap::from([(SINGLE_FILE_CRATE_NAME, parsed_data)]))
+}
+
+/// Use parallel builder to walk all source directories concurrently.
+pub fn parallel_parse(
+    parse_context: &ParseContext,
+    walker_builder: WalkBuilder,
+    language_type: SupportedLanguage,
+) -> Result<BTreeMap<CrateName, ParsedData>, GeneratorError> {
+    let (tx, rx) = bounded::<Result<ParsedData, GeneratorError>>(100);
+
+    let collector_thread = thread::spawn(move || {
+        let mut crate_parsed_data: BTreeMap<CrateName, ParsedData> = BTreeMap::new();
+
+        for result in rx {
+            let parsed_data = result?;
+            let crate_name = parsed_data.crate_name.clone();
+            // Append each yielded parsed data by its respective crate.
+            *crate_parsed_data.entry(crate_name).or_default() += parsed_data;
+        }
+
+        Ok(crate_parsed_data)
+    });
+
+    walker_builder.build_parallel().run(|| {
+        let tx = tx.clone();
+
+        Box::new(move |result| {
+            let result = result.map_err(GeneratorError::from).and_then(|dir_entry| {
+                parse_dir_entry(parse_context, language_type, &dir_entry).map_err(|source| {
+                    GeneratorError::Parse {
+                        path: dir_entry.path().to_path_buf(),
+                        source,
+                    }
+                })
+            });
+            match result {
+                Ok(Some(parsed_data)) => {
+                    tx.send(Ok(parsed_data)).unwrap();
+                    WalkState::Continue
+                }
+                Ok(None) => WalkState::Continue,
+                Err(err) => {
+                    tx.send(Err(err)).unwrap();
+                    WalkState::Quit
+                }
+            }
+        })
+    });
+
+    drop(tx);
+    collector_thread.join().unwrap()
+}
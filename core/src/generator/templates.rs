@@ -0,0 +1,100 @@
+//! Configurable text around the generated code of each file.
+use crate::language::CrateName;
+use sha2::{Digest, Sha256};
+use std::{collections::BTreeSet, fmt::Write};
+
+/// A header and footer written around the generated code of each file, like a
+/// license or lint suppressions such as `/* eslint-disable */`.
+///
+/// The templates can contain these placeholders:
+/// - `{version}`: the version of typeshare
+/// - `{crates}`: the crates the types of the file come from, separated by commas
+/// - `{hash}`: a hash of the generated code, which only changes when the code does
+/// - `{license}`: the [`license`](Self::license) text
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FileTemplates {
+    /// Written before the generated code.
+    pub header: Option<String>,
+    /// Written after the generated code.
+    pub footer: Option<String>,
+    /// The text of the `{license}` placeholder.
+    pub license: String,
+}
+
+impl FileTemplates {
+    /// Surround the generated code of a file with the header and footer. Empty files
+    /// stay empty, as they are never written.
+    pub(crate) fn apply(&self, code: Vec<u8>, source_crates: &BTreeSet<CrateName>) -> Vec<u8> {
+        if code.is_empty() || (self.header.is_none() && self.footer.is_none()) {
+            return code;
+        }
+
+        let hash = Sha256::digest(&code)
+            .iter()
+            .take(8)
+            .fold(String::new(), |mut hash, byte| {
+                let _ = write!(hash, "{byte:02x}");
+                hash
+            });
+        let crates = source_crates
+            .iter()
+            .map(CrateName::as_str)
+            .collect::<Vec<_>>();
+        let render = |template: &str| {
+            let text = template
+                .replace("{version}", env!("CARGO_PKG_VERSION"))
+                .replace("{crates}", &crates.join(", "))
+                .replace("{hash}", &hash)
+                .replace("{license}", self.license.trim_end());
+            if text.ends_with('\n') {
+                text
+            } else {
+                text + "\n"
+            }
+        };
+
+        let mut output = Vec::with_capacity(code.len());
+        if let Some(header) = &self.header {
+            output.extend(render(header).into_bytes());
+        }
+        output.extend(&code);
+        if let Some(footer) = &self.footer {
+            output.extend(render(footer).into_bytes());
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::FileTemplates;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn fills_in_placeholders() {
+        let templates = FileTemplates {
+            header: Some("/* eslint-disable */\n// {crates} {version} {hash}".to_owned()),
+            footer: Some("// {license}\n".to_owned()),
+            license: "MIT\n".to_owned(),
+        };
+        let crates = BTreeSet::from(["first".into(), "second".into()]);
+
+        let output = String::from_utf8(templates.apply(b"code\n".to_vec(), &crates)).unwrap();
+        assert_eq!(
+            output,
+            format!(
+                "/* eslint-disable */\n// first, second {} b57b236c9bcd2a61\ncode\n// MIT\n",
+                env!("CARGO_PKG_VERSION")
+            )
+        );
+    }
+
+    #[test]
+    fn keeps_empty_files_empty() {
+        let templates = FileTemplates {
+            header: Some("# ruff: noqa".to_owned()),
+            ..Default::default()
+        };
+        assert!(templates.apply(Vec::new(), &BTreeSet::new()).is_empty());
+    }
+}
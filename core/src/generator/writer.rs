@@ -0,0 +1,287 @@
+//! Generated source file output.
+use super::{relative_import_path, FileTemplates, GeneratorError, Output};
+use crate::{
+    language::{CrateName, CrateTypes, Language, SINGLE_FILE_CRATE_NAME},
+    parser::ParsedData,
+};
+use log::{error, info};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fs,
+    io::{self, Write},
+    path::{Component, Path, PathBuf},
+};
+
+/// Name of the file listing the files typeshare generated in an output folder.
+pub const MANIFEST_FILE_NAME: &str = ".typeshare-manifest";
+
+const MANIFEST_HEADER: &str = "# Files generated by typeshare. Files listed here are removed once they are no longer generated.";
+
+/// Write the parsed data to the one or more files depending on the output, with the
+/// `templates` around the generated code of each file.
+///
+/// In `check` mode nothing is written or removed. Instead, an error lists every file
+/// that would change and every previously generated file that would be removed.
+pub fn write_generated(
+    destination: &Output,
+    lang: &mut (impl Language + ?Sized),
+    crate_parsed_data: BTreeMap<CrateName, ParsedData>,
+    import_candidates: CrateTypes,
+    templates: &FileTemplates,
+    check: bool,
+) -> Result<(), GeneratorError> {
+    let mut writer = Writer {
+        check,
+        templates,
+        changed: Vec::new(),
+        orphaned: Vec::new(),
+    };
+
+    lang.prepare_crates(&crate_parsed_data);
+    match destination {
+        Output::File(path) => writer.write_single_file(lang, path, crate_parsed_data)?,
+        Output::Stdout => write_stdout(lang, crate_parsed_data, templates)?,
+        Output::Folder(path) => {
+            writer.write_multiple_files(lang, path, crate_parsed_data, import_candidates)?
+        }
+    }
+
+    if writer.changed.is_empty() && writer.orphaned.is_empty() {
+        return Ok(());
+    }
+    for path in &writer.changed {
+        error!("{path:?} is out of date");
+    }
+    for path in &writer.orphaned {
+        error!("{path:?} is no longer generated");
+    }
+    Err(GeneratorError::OutOfDate {
+        changed: writer.changed,
+        orphaned: writer.orphaned,
+    })
+}
+
+struct Writer<'a> {
+    /// Only compare output against what is on disk.
+    check: bool,
+    /// The header and footer of each file.
+    templates: &'a FileTemplates,
+    /// Files whose contents differ from the generated output, in check mode.
+    changed: Vec<PathBuf>,
+    /// Previously generated files that are no longer generated, in check mode.
+    orphaned: Vec<PathBuf>,
+}
+
+impl Writer<'_> {
+    /// Write multiple module files.
+    fn write_multiple_files(
+        &mut self,
+        lang: &mut (impl Language + ?Sized),
+        output_folder: &Path,
+        crate_parsed_data: BTreeMap<CrateName, ParsedData>,
+        import_candidates: CrateTypes,
+    ) -> Result<(), GeneratorError> {
+        let mut generated = BTreeSet::new();
+        let crate_files = crate_parsed_data
+            .iter()
+            .map(|(crate_name, parsed_data)| (crate_name.clone(), parsed_data.file_name.clone()))
+            .collect::<Vec<_>>();
+
+        for (_crate_name, parsed_data) in crate_parsed_data {
+            let outfile = Path::new(output_folder).join(&parsed_data.file_name);
+            let file_name = parsed_data.file_name.clone();
+            lang.set_import_paths(
+                crate_files
+                    .iter()
+                    .map(|(crate_name, crate_file)| {
+                        (
+                            crate_name.clone(),
+                            relative_import_path(&file_name, crate_file),
+                        )
+                    })
+                    .collect(),
+            );
+            let source_crates = parsed_data.source_crates.clone();
+            let mut generated_contents = Vec::new();
+            lang.generate_types(&mut generated_contents, &import_candidates, parsed_data)?;
+            let generated_contents = self.templates.apply(generated_contents, &source_crates);
+            if !generated_contents.is_empty() {
+                generated.insert(file_name);
+            }
+            self.check_write_file(&outfile, generated_contents)?;
+        }
+
+        if !self.check {
+            lang.post_generation(&output_folder.as_os_str().to_string_lossy())?;
+        }
+
+        self.remove_orphans(output_folder, &generated)
+    }
+
+    /// Remove the files listed in the output folder's manifest that were not generated
+    /// this time, and record the files that were.
+    fn remove_orphans(
+        &mut self,
+        output_folder: &Path,
+        generated: &BTreeSet<String>,
+    ) -> Result<(), GeneratorError> {
+        let manifest_file = output_folder.join(MANIFEST_FILE_NAME);
+        let previous = match fs::read_to_string(&manifest_file) {
+            Ok(contents) => parse_manifest(&contents),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => BTreeSet::new(),
+            Err(source) => {
+                return Err(GeneratorError::Write {
+                    path: manifest_file,
+                    source,
+                })
+            }
+        };
+
+        for orphan in previous.difference(generated) {
+            let path = output_folder.join(orphan);
+            if !path.is_file() {
+                continue;
+            }
+            if self.check {
+                self.orphaned.push(path);
+                continue;
+            }
+            info!("Removing {path:?} which is no longer generated");
+            fs::remove_file(&path).map_err(|source| GeneratorError::Write { path, source })?;
+        }
+
+        let manifest = [MANIFEST_HEADER.to_owned()]
+            .into_iter()
+            .chain(generated.iter().cloned())
+            .map(|line| line + "\n")
+            .collect::<String>();
+        if self.check {
+            return Ok(());
+        }
+        self.check_write_file(&manifest_file, manifest.into_bytes())
+    }
+
+    /// Write the file if the contents have changed.
+    fn check_write_file(&mut self, outfile: &Path, output: Vec<u8>) -> Result<(), GeneratorError> {
+        match fs::read(outfile) {
+            Ok(buf) if buf == output => {
+                // avoid writing the file to leave the mtime intact
+                // for tools which might use it to know when to
+                // rebuild.
+                info!("Skipping writing to {outfile:?} no changes");
+                return Ok(());
+            }
+            _ => {}
+        }
+
+        if !output.is_empty() {
+            if self.check {
+                self.changed.push(outfile.to_path_buf());
+                return Ok(());
+            }
+
+            let write_error = |source| GeneratorError::Write {
+                path: outfile.to_path_buf(),
+                source,
+            };
+            // If the output directory doesn't already exist, create it.
+            if let Some(out_dir) = outfile.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+                if !out_dir.exists() {
+                    fs::create_dir_all(out_dir).map_err(write_error)?;
+                }
+            }
+
+            fs::write(outfile, output).map_err(write_error)?;
+        }
+        Ok(())
+    }
+
+    /// Write all types to a single file.
+    fn write_single_file(
+        &mut self,
+        lang: &mut (impl Language + ?Sized),
+        file_name: &Path,
+        crate_parsed_data: BTreeMap<CrateName, ParsedData>,
+    ) -> Result<(), GeneratorError> {
+        let name = file_name
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let output = generate_single_file(lang, &name, crate_parsed_data, self.templates)?;
+
+        let outfile = Path::new(file_name).to_path_buf();
+        self.check_write_file(&outfile, output)?;
+        if !self.check {
+            let output_folder = outfile.parent().unwrap_or(Path::new(""));
+            lang.post_generation(&output_folder.as_os_str().to_string_lossy())?;
+        }
+        Ok(())
+    }
+}
+
+/// Write all types to standard output.
+fn write_stdout(
+    lang: &mut (impl Language + ?Sized),
+    crate_parsed_data: BTreeMap<CrateName, ParsedData>,
+    templates: &FileTemplates,
+) -> Result<(), GeneratorError> {
+    let output = generate_single_file(lang, "", crate_parsed_data, templates)?;
+    io::stdout()
+        .lock()
+        .write_all(&output)
+        .map_err(|source| GeneratorError::Write {
+            path: PathBuf::from("-"),
+            source,
+        })?;
+    lang.post_generation(".")?;
+    Ok(())
+}
+
+/// Generate the code for all types, as a single file named `file_name`, which is
+/// empty for standard output.
+fn generate_single_file(
+    lang: &mut (impl Language + ?Sized),
+    file_name: &str,
+    mut crate_parsed_data: BTreeMap<CrateName, ParsedData>,
+    templates: &FileTemplates,
+) -> Result<Vec<u8>, GeneratorError> {
+    let mut parsed_data = crate_parsed_data
+        .remove(&SINGLE_FILE_CRATE_NAME)
+        .ok_or(GeneratorError::MissingParsedData)?;
+    parsed_data.file_name = file_name.to_owned();
+
+    let source_crates = parsed_data.source_crates.clone();
+    let mut output = Vec::new();
+    lang.generate_types(&mut output, &HashMap::new(), parsed_data)?;
+    Ok(templates.apply(output, &source_crates))
+}
+
+/// The files listed in a manifest. Anything that could point outside of the
+/// output folder is ignored, so a tampered manifest can't remove other files.
+fn parse_manifest(contents: &str) -> BTreeSet<String> {
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter(|line| {
+            Path::new(line)
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)))
+        })
+        .map(ToOwned::to_owned)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_manifest;
+
+    #[test]
+    fn manifest_stays_inside_output_folder() {
+        let manifest = "# comment\nfirst.ts\n\nnested/second.ts\n../outside.ts\n/etc/passwd\n";
+        assert_eq!(
+            parse_manifest(manifest).into_iter().collect::<Vec<_>>(),
+            ["first.ts", "nested/second.ts"]
+        );
+    }
+}
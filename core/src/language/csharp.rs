@@ -0,0 +1,691 @@
+use super::{
+    used_imports, used_type_map_imports, CrateName, CrateTypes, Language, MappedImport,
+    ScopedCrateTypes, TypeMapping,
+};
+use crate::{
+    language::SupportedLanguage,
+    parser::ParsedData,
+    rename::{FieldNaming, RenameExt},
+    rust_types::{
+        RustEnum, RustEnumVariant, RustField, RustItem, RustStruct, RustTypeAlias,
+        RustTypeFormatError, SpecialRustType,
+    },
+    topsort::sort_items,
+};
+use itertools::Itertools;
+use std::{
+    collections::{BTreeSet, HashMap},
+    io::Write,
+};
+
+/// Words that can't be used as identifiers without an `@` in front of them.
+const CSHARP_KEYWORDS: &[&str] = &[
+    "abstract",
+    "as",
+    "base",
+    "bool",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "checked",
+    "class",
+    "const",
+    "continue",
+    "decimal",
+    "default",
+    "delegate",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "event",
+    "explicit",
+    "extern",
+    "false",
+    "finally",
+    "fixed",
+    "float",
+    "for",
+    "foreach",
+    "goto",
+    "if",
+    "implicit",
+    "in",
+    "int",
+    "interface",
+    "internal",
+    "is",
+    "lock",
+    "long",
+    "namespace",
+    "new",
+    "null",
+    "object",
+    "operator",
+    "out",
+    "override",
+    "params",
+    "private",
+    "protected",
+    "public",
+    "readonly",
+    "ref",
+    "return",
+    "sbyte",
+    "sealed",
+    "short",
+    "sizeof",
+    "stackalloc",
+    "static",
+    "string",
+    "struct",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "uint",
+    "ulong",
+    "unchecked",
+    "unsafe",
+    "ushort",
+    "using",
+    "virtual",
+    "void",
+    "volatile",
+    "while",
+];
+
+/// All information needed to generate C# records for `System.Text.Json`.
+#[derive(Default)]
+pub struct CSharp {
+    /// The namespace of the generated types. When writing one file per crate, each
+    /// crate gets a namespace inside of it named after the crate.
+    pub namespace: String,
+    /// Conversions from Rust type names to C# type names.
+    pub type_mappings: HashMap<String, TypeMapping>,
+    /// Imports needed by the mapped types, from Rust type names to the namespace
+    /// of the type they map to.
+    pub type_mapping_imports: HashMap<String, String>,
+    /// Whether or not to exclude the version header that normally appears at the top of generated code.
+    /// If you aren't generating a snapshot test, this setting can just be left as a default (false)
+    pub no_version_header: bool,
+    /// The namespace of the file being generated.
+    pub file_namespace: String,
+}
+
+impl Language for CSharp {
+    fn generate_types(
+        &mut self,
+        w: &mut dyn Write,
+        all_types: &CrateTypes,
+        data: ParsedData,
+    ) -> std::io::Result<()> {
+        self.begin_file(w, &data)?;
+
+        let mapped_imports = used_type_map_imports(self, &data);
+        if !mapped_imports.is_empty() {
+            self.write_type_map_imports(w, &mapped_imports)?;
+        }
+
+        if data.multi_file {
+            self.write_imports(w, used_imports(&data, all_types))?;
+        }
+
+        // The namespace has to come after all using directives.
+        self.file_namespace = if data.multi_file {
+            self.crate_namespace(&data.crate_name)
+        } else {
+            self.namespace.clone()
+        };
+        if !self.file_namespace.is_empty() {
+            writeln!(w)?;
+            writeln!(w, "namespace {};", self.file_namespace)?;
+        }
+
+        let ParsedData {
+            structs,
+            enums,
+            aliases,
+            source_positions,
+            type_ordering,
+            ..
+        } = data;
+
+        let mut items = Vec::from_iter(
+            aliases
+                .into_iter()
+                .map(RustItem::Alias)
+                .chain(structs.into_iter().map(RustItem::Struct))
+                .chain(enums.into_iter().map(RustItem::Enum)),
+        );
+
+        sort_items(&mut items, type_ordering, &source_positions, true);
+
+        for thing in &items {
+            match thing {
+                RustItem::Enum(e) => self.write_enum(w, e)?,
+                RustItem::Struct(s) => self.write_struct(w, s)?,
+                RustItem::Alias(a) => self.write_type_alias(w, a)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    fn type_map(&mut self) -> &HashMap<String, TypeMapping> {
+        &self.type_mappings
+    }
+
+    fn type_map_imports(&mut self) -> Option<&HashMap<String, String>> {
+        Some(&self.type_mapping_imports)
+    }
+
+    fn format_simple_type(
+        &mut self,
+        base: &String,
+        _generic_types: &[String],
+    ) -> Result<String, RustTypeFormatError> {
+        Ok(if let Some(mapped) = self.type_map().get(base) {
+            mapped.apply(base, &[])?
+        } else {
+            base.into()
+        })
+    }
+
+    fn format_special_type(
+        &mut self,
+        special_ty: &SpecialRustType,
+        generic_types: &[String],
+    ) -> Result<String, RustTypeFormatError> {
+        Ok(match special_ty {
+            SpecialRustType::Vec(rtype)
+            | SpecialRustType::Array(rtype, _)
+            | SpecialRustType::Slice(rtype) => {
+                format!("List<{}>", self.format_type(rtype, generic_types)?)
+            }
+            SpecialRustType::Option(rtype) => {
+                let ty = self.format_type(rtype, generic_types)?;
+                if ty.ends_with('?') {
+                    ty
+                } else {
+                    format!("{ty}?")
+                }
+            }
+            SpecialRustType::HashMap(rtype1, rtype2) => {
+                format!(
+                    "Dictionary<{}, {}>",
+                    self.format_type(rtype1, generic_types)?,
+                    self.format_type(rtype2, generic_types)?
+                )
+            }
+            // The unit type is serialized as null
+            SpecialRustType::Unit => "object?".into(),
+            // Char in C# is 16 bits long, so we need to use string
+            SpecialRustType::String | SpecialRustType::Char => "string".into(),
+            SpecialRustType::I8 => "sbyte".into(),
+            SpecialRustType::I16 => "short".into(),
+            SpecialRustType::I32 => "int".into(),
+            SpecialRustType::I54 | SpecialRustType::I64 | SpecialRustType::ISize => "long".into(),
+            SpecialRustType::U8 => "byte".into(),
+            SpecialRustType::U16 => "ushort".into(),
+            SpecialRustType::U32 => "uint".into(),
+            SpecialRustType::U53 | SpecialRustType::U64 | SpecialRustType::USize => "ulong".into(),
+            SpecialRustType::Bool => "bool".into(),
+            SpecialRustType::F32 => "float".into(),
+            SpecialRustType::F64 => "double".into(),
+        })
+    }
+
+    fn begin_file(&mut self, w: &mut dyn Write, _parsed_data: &ParsedData) -> std::io::Result<()> {
+        if !self.no_version_header {
+            writeln!(w, "// <auto-generated>")?;
+            writeln!(
+                w,
+                "//     Generated by typeshare {}",
+                env!("CARGO_PKG_VERSION")
+            )?;
+            writeln!(w, "// </auto-generated>")?;
+        }
+        // Generated code is outside of the nullable context unless it's enabled again.
+        writeln!(w, "#nullable enable")?;
+        writeln!(w)?;
+        writeln!(w, "using System.Collections.Generic;")?;
+        writeln!(w, "using System.Text.Json;")?;
+        writeln!(w, "using System.Text.Json.Serialization;")
+    }
+
+    fn write_imports(
+        &mut self,
+        w: &mut dyn Write,
+        imports: ScopedCrateTypes<'_>,
+    ) -> std::io::Result<()> {
+        for crate_name in imports.keys() {
+            writeln!(w, "using {};", self.crate_namespace(crate_name))?;
+        }
+        Ok(())
+    }
+
+    fn write_type_map_imports(
+        &mut self,
+        w: &mut dyn Write,
+        imports: &BTreeSet<MappedImport>,
+    ) -> std::io::Result<()> {
+        for import in imports.iter().map(|import| &import.import).dedup() {
+            writeln!(w, "using {import};")?;
+        }
+        Ok(())
+    }
+
+    fn write_type_alias(&mut self, w: &mut dyn Write, ty: &RustTypeAlias) -> std::io::Result<()> {
+        let name = &ty.id.renamed;
+        let generics = generic_parameters(&ty.generic_types);
+        let value_type = self
+            .format_type(&ty.r#type, &ty.generic_types)
+            .map_err(std::io::Error::other)?;
+
+        // A record can't be serialized as the value it wraps without a converter.
+        writeln!(w)?;
+        self.write_comments(w, 0, &ty.comments)?;
+        if ty.generic_types.is_empty() {
+            writeln!(w, "[JsonConverter(typeof({name}JsonConverter))]")?;
+        } else {
+            writeln!(w, "[JsonConverter(typeof({name}JsonConverterFactory))]")?;
+        }
+        write!(
+            w,
+            "public sealed record {name}{generics}({value_type} Value)"
+        )?;
+        if ty.is_redacted {
+            writeln!(w)?;
+            writeln!(w, "{{")?;
+            writeln!(w, "\tpublic override string ToString() => \"***\";")?;
+            writeln!(w, "}}")?;
+        } else {
+            writeln!(w, ";")?;
+        }
+
+        writeln!(w)?;
+        writeln!(
+            w,
+            "public sealed class {name}JsonConverter{generics} : JsonConverter<{name}{generics}>"
+        )?;
+        writeln!(w, "{{")?;
+        // Without this, `null` reads as a `null` record instead of one wrapping `null`.
+        let handle_null = ty.r#type.is_optional() || value_type.ends_with('?');
+        if handle_null {
+            writeln!(w, "\tpublic override bool HandleNull => true;")?;
+            writeln!(w)?;
+        }
+        writeln!(w, "\tpublic override {name}{generics} Read(ref Utf8JsonReader reader, System.Type typeToConvert, JsonSerializerOptions options) =>")?;
+        writeln!(
+            w,
+            "\t\tnew(JsonSerializer.Deserialize<{value_type}>(ref reader, options)!);"
+        )?;
+        writeln!(w)?;
+        writeln!(w, "\tpublic override void Write(Utf8JsonWriter writer, {name}{generics} value, JsonSerializerOptions options) =>")?;
+        // A `null` record is written too when the converter handles `null`.
+        let value = if handle_null {
+            "value?.Value"
+        } else {
+            "value.Value"
+        };
+        writeln!(w, "\t\tJsonSerializer.Serialize(writer, {value}, options);")?;
+        writeln!(w, "}}")?;
+
+        if !ty.generic_types.is_empty() {
+            // An attribute can't name a generic converter for each instantiation, so a
+            // factory creates them.
+            let unbound = format!("<{}>", ",".repeat(ty.generic_types.len() - 1));
+            writeln!(w)?;
+            writeln!(
+                w,
+                "public sealed class {name}JsonConverterFactory : JsonConverterFactory"
+            )?;
+            writeln!(w, "{{")?;
+            writeln!(
+                w,
+                "\tpublic override bool CanConvert(System.Type typeToConvert) =>"
+            )?;
+            writeln!(w, "\t\ttypeToConvert.IsGenericType && typeToConvert.GetGenericTypeDefinition() == typeof({name}{unbound});")?;
+            writeln!(w)?;
+            writeln!(w, "\tpublic override JsonConverter? CreateConverter(System.Type typeToConvert, JsonSerializerOptions options) =>")?;
+            writeln!(w, "\t\t(JsonConverter?)System.Activator.CreateInstance(typeof({name}JsonConverter{unbound}).MakeGenericType(typeToConvert.GetGenericArguments()));")?;
+            writeln!(w, "}}")?;
+        }
+        Ok(())
+    }
+
+    fn write_struct(&mut self, w: &mut dyn Write, rs: &RustStruct) -> std::io::Result<()> {
+        writeln!(w)?;
+        self.write_comments(w, 0, &rs.comments)?;
+        write!(
+            w,
+            "public record {}{}",
+            rs.id.renamed,
+            generic_parameters(&rs.generic_types)
+        )?;
+
+        if rs.fields.is_empty() && !rs.is_redacted {
+            return writeln!(w, ";");
+        }
+
+        writeln!(w)?;
+        writeln!(w, "{{")?;
+        for (i, field) in rs.fields.iter().enumerate() {
+            if i > 0 {
+                writeln!(w)?;
+            }
+            self.write_property(w, field, &rs.id.renamed, &rs.generic_types)?;
+        }
+        if rs.is_redacted {
+            if !rs.fields.is_empty() {
+                writeln!(w)?;
+            }
+            writeln!(
+                w,
+                "\tpublic override string ToString() => {:?};",
+                rs.id.renamed
+            )?;
+        }
+        writeln!(w, "}}")
+    }
+
+    fn write_enum(&mut self, w: &mut dyn Write, e: &RustEnum) -> std::io::Result<()> {
+        // Generate named types for any anonymous struct variants of this enum
+        self.write_types_for_anonymous_structs(w, e, &|variant_name| {
+            anonymous_struct_name(e, variant_name)
+        })?;
+
+        match e {
+            RustEnum::Unit(shared) => {
+                let name = &shared.id.renamed;
+                writeln!(w)?;
+                self.write_comments(w, 0, &shared.comments)?;
+                writeln!(w, "[JsonConverter(typeof({name}JsonConverter))]")?;
+                writeln!(w, "public enum {name}")?;
+                writeln!(w, "{{")?;
+                for v in &shared.variants {
+                    self.write_comments(w, 1, &v.shared().comments)?;
+                    writeln!(w, "\t{},", identifier(&v.shared().id.original))?;
+                }
+                writeln!(w, "}}")?;
+
+                // Enums are serialized as numbers by default, and the built in string
+                // converter doesn't know about renamed variants.
+                writeln!(w)?;
+                writeln!(
+                    w,
+                    "public sealed class {name}JsonConverter : JsonConverter<{name}>"
+                )?;
+                writeln!(w, "{{")?;
+                writeln!(w, "\tpublic override {name} Read(ref Utf8JsonReader reader, System.Type typeToConvert, JsonSerializerOptions options) =>")?;
+                writeln!(w, "\t\treader.GetString() switch")?;
+                writeln!(w, "\t\t{{")?;
+                for v in &shared.variants {
+                    writeln!(
+                        w,
+                        "\t\t\t{:?} => {name}.{},",
+                        v.shared().id.renamed,
+                        identifier(&v.shared().id.original)
+                    )?;
+                }
+                writeln!(
+                    w,
+                    "\t\t\tvar value => throw new JsonException(\"Unknown {name} value: \" + value),"
+                )?;
+                writeln!(w, "\t\t}};")?;
+                writeln!(w)?;
+                writeln!(w, "\tpublic override void Write(Utf8JsonWriter writer, {name} value, JsonSerializerOptions options) =>")?;
+                writeln!(w, "\t\twriter.WriteStringValue(value switch")?;
+                writeln!(w, "\t\t{{")?;
+                for v in &shared.variants {
+                    writeln!(
+                        w,
+                        "\t\t\t{name}.{} => {:?},",
+                        identifier(&v.shared().id.original),
+                        v.shared().id.renamed
+                    )?;
+                }
+                writeln!(
+                    w,
+                    "\t\t\t_ => throw new JsonException(\"Unknown {name} value: \" + value),"
+                )?;
+                writeln!(w, "\t\t}});")?;
+                writeln!(w, "}}")
+            }
+            RustEnum::Algebraic {
+                tag_key,
+                content_key,
+                shared,
+            } => {
+                let name = &shared.id.renamed;
+                let generics = generic_parameters(&shared.generic_types);
+
+                writeln!(w)?;
+                self.write_comments(w, 0, &shared.comments)?;
+                if shared.generic_types.is_empty() {
+                    writeln!(
+                        w,
+                        "[JsonPolymorphic(TypeDiscriminatorPropertyName = {tag_key:?})]"
+                    )?;
+                    for v in &shared.variants {
+                        writeln!(
+                            w,
+                            "[JsonDerivedType(typeof({name}.{}), {:?})]",
+                            variant_name(v),
+                            v.shared().id.renamed
+                        )?;
+                    }
+                } else {
+                    // Attributes can't refer to the generic parameters of a type.
+                    writeln!(
+                        w,
+                        "// Register the derived types of each closed type with JsonPolymorphismOptions."
+                    )?;
+                }
+                writeln!(w, "public abstract record {name}{generics}")?;
+                writeln!(w, "{{")?;
+                writeln!(w, "\tprivate {name}() {{}}")?;
+
+                let variant_names = shared.variants.iter().map(variant_name).collect_vec();
+                for v in &shared.variants {
+                    writeln!(w)?;
+                    self.write_comments(w, 1, &v.shared().comments)?;
+                    write!(w, "\tpublic sealed record {}(", variant_name(v))?;
+                    let content_name = property_name(content_key, &variant_name(v));
+                    match v {
+                        RustEnumVariant::Unit(_) => {}
+                        RustEnumVariant::Tuple { ty, .. } => {
+                            let content_type = self
+                                .format_type(ty, &shared.generic_types)
+                                .map_err(std::io::Error::other)?;
+                            write!(
+                                w,
+                                "[property: JsonPropertyName({content_key:?})] {} {content_name}",
+                                self.qualify(&content_type, &variant_names)
+                            )?;
+                        }
+                        RustEnumVariant::AnonymousStruct {
+                            shared: variant,
+                            fields,
+                        } => {
+                            // Only the generic parameters of the enum used by the fields
+                            // are parameters of the generated record.
+                            let variant_generics = shared
+                                .generic_types
+                                .iter()
+                                .filter(|g| fields.iter().any(|f| f.ty.contains_type(g)))
+                                .cloned()
+                                .collect_vec();
+                            write!(
+                                w,
+                                "[property: JsonPropertyName({content_key:?})] {}{} {content_name}",
+                                anonymous_struct_name(e, &variant.id.original),
+                                generic_parameters(&variant_generics)
+                            )?;
+                        }
+                    }
+                    writeln!(w, ") : {name}{generics};")?;
+                }
+                writeln!(w, "}}")
+            }
+        }
+    }
+
+    fn ignored_reference_types(&self) -> Vec<&str> {
+        self.type_mappings.keys().map(|s| s.as_str()).collect()
+    }
+}
+
+impl CSharp {
+    /// The namespace of the file of a crate when writing one file per crate.
+    fn crate_namespace(&self, crate_name: &CrateName) -> String {
+        let crate_namespace = crate_name.to_string().to_pascal_case();
+        if self.namespace.is_empty() {
+            crate_namespace
+        } else {
+            format!("{}.{crate_namespace}", self.namespace)
+        }
+    }
+
+    /// Qualify the names in a type that are hidden by the records of the variants
+    /// of the enum the type is used in, like the `Circle` in `record Circle(Circle Content)`.
+    fn qualify(&self, ty: &str, variant_names: &[String]) -> String {
+        let mut qualified = String::with_capacity(ty.len());
+        let mut rest = ty;
+        while let Some(start) = rest.find(is_identifier_char) {
+            let end = rest[start..]
+                .find(|c| !is_identifier_char(c))
+                .map_or(rest.len(), |end| start + end);
+            let name = &rest[start..end];
+            qualified.push_str(&rest[..start]);
+            if !rest[..start].ends_with('.') && variant_names.iter().any(|v| v == name) {
+                qualified.push_str("global::");
+                match name {
+                    "List" | "Dictionary" => qualified.push_str("System.Collections.Generic."),
+                    _ if self.file_namespace.is_empty() => {}
+                    _ => {
+                        qualified.push_str(&self.file_namespace);
+                        qualified.push('.');
+                    }
+                }
+            }
+            qualified.push_str(name);
+            rest = &rest[end..];
+        }
+        qualified.push_str(rest);
+        qualified
+    }
+
+    fn write_property(
+        &mut self,
+        w: &mut dyn Write,
+        f: &RustField,
+        type_name: &str,
+        generic_types: &[String],
+    ) -> std::io::Result<()> {
+        self.write_comments(w, 1, &f.comments)?;
+        let ty = match f.type_override(SupportedLanguage::CSharp) {
+            Some(type_override) => type_override.to_owned(),
+            None => self
+                .format_type(&f.ty, generic_types)
+                .map_err(std::io::Error::other)?,
+        };
+        // Missing fields with a default are null, like missing optional fields.
+        let (required, ty) = if f.ty.is_optional() || ty.ends_with('?') {
+            ("", ty)
+        } else if f.has_default {
+            ("", format!("{ty}?"))
+        } else {
+            ("required ", ty)
+        };
+
+        writeln!(w, "\t[JsonPropertyName({:?})]", f.id.renamed)?;
+        writeln!(
+            w,
+            "\tpublic {required}{ty} {} {{ get; init; }}",
+            property_name(&f.id.renamed, type_name)
+        )
+    }
+
+    fn write_comments(
+        &self,
+        w: &mut dyn Write,
+        indent: usize,
+        comments: &[String],
+    ) -> std::io::Result<()> {
+        if comments.is_empty() {
+            return Ok(());
+        }
+        let indent = "\t".repeat(indent);
+        writeln!(w, "{indent}/// <summary>")?;
+        for comment in comments {
+            let comment = comment
+                .replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;");
+            writeln!(w, "{indent}/// {comment}")?;
+        }
+        writeln!(w, "{indent}/// </summary>")
+    }
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Escape a name that is a keyword.
+fn identifier(name: &str) -> String {
+    if CSHARP_KEYWORDS.contains(&name) {
+        format!("@{name}")
+    } else {
+        name.to_owned()
+    }
+}
+
+/// The name of the property for the serialized key `key`, in a type named `type_name`.
+fn property_name(key: &str, type_name: &str) -> String {
+    let name = FieldNaming::PascalCase
+        .apply(key)
+        .chars()
+        .filter(|c| is_identifier_char(*c))
+        .collect::<String>();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{name}")
+    } else if name == type_name {
+        // Members can't have the name of the type they're in.
+        format!("{name}_")
+    } else {
+        identifier(&name)
+    }
+}
+
+/// The name of the record of an algebraic enum variant.
+fn variant_name(v: &RustEnumVariant) -> String {
+    let name = v.shared().id.original.to_pascal_case();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        // If the name starts with a digit just add an underscore
+        // to the front and make it valid
+        format!("_{name}")
+    } else {
+        name
+    }
+}
+
+/// The name of the record generated for an anonymous struct variant.
+fn anonymous_struct_name(e: &RustEnum, variant_name: &str) -> String {
+    format!("{}{}Inner", e.shared().id.renamed, variant_name)
+}
+
+/// Format the generic parameters of a type declaration, like `<T, U>`.
+fn generic_parameters(generic_types: &[String]) -> String {
+    if generic_types.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", generic_types.join(", "))
+    }
+}
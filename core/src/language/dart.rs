@@ -0,0 +1,987 @@
+use super::{CrateName, Language, MappedImport, ScopedCrateTypes, TypeMapping};
+use crate::{
+    generator::package_path,
+    language::SupportedLanguage,
+    parser::ParsedData,
+    rename::{FieldNaming, RenameExt},
+    rust_types::{
+        RustEnum, RustEnumVariant, RustField, RustStruct, RustType, RustTypeAlias,
+        RustTypeFormatError, SpecialRustType,
+    },
+};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    io::Write,
+};
+
+/// Words that can't be used as identifiers.
+const DART_KEYWORDS: &[&str] = &[
+    "assert", "break", "case", "catch", "class", "const", "continue", "default", "do", "else",
+    "enum", "extends", "false", "final", "finally", "for", "if", "in", "is", "new", "null",
+    "rethrow", "return", "super", "switch", "this", "throw", "true", "try", "var", "void", "while",
+    "with",
+];
+
+/// Members every enum or generated class already has.
+const RESERVED_MEMBERS: &[&str] = &[
+    "copyWith",
+    "fromJson",
+    "hashCode",
+    "index",
+    "name",
+    "runtimeType",
+    "toJson",
+    "toString",
+    "value",
+    "values",
+];
+
+/// Mapped types that JSON values can be cast to as they are.
+const JSON_TYPES: &[&str] = &[
+    "bool",
+    "double",
+    "dynamic",
+    "int",
+    "List<dynamic>",
+    "Map<String, dynamic>",
+    "num",
+    "Object",
+    "Object?",
+    "String",
+];
+
+/// The functions converting a mapped type from and to its JSON value, like
+/// `DateTime.parse` and `dateTimeToJson`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct JsonConverter {
+    /// Called with the JSON value, which is `dynamic`, and returns the mapped type.
+    pub from_json: String,
+    /// Called with the mapped type and returns its JSON value.
+    pub to_json: String,
+}
+
+/// All information needed to generate Dart classes with `fromJson` and `toJson`.
+#[derive(Default)]
+pub struct Dart {
+    /// The `package:` path the generated files are in, like `my_app/models`, used
+    /// to import the files of other crates. Files import each other by their
+    /// relative path if this is empty.
+    pub package: String,
+    /// Conversions from Rust type names to Dart type names.
+    pub type_mappings: HashMap<String, TypeMapping>,
+    /// Imports needed by the mapped types, from Rust type names to the library
+    /// to import, like `package:decimal/decimal.dart`.
+    pub type_mapping_imports: HashMap<String, String>,
+    /// How to convert mapped types from and to JSON, by Rust type name. Every type
+    /// mapping needs one, unless it maps to a type JSON values can be cast to,
+    /// like `String` or `int`.
+    pub json_converters: HashMap<String, JsonConverter>,
+    /// Whether or not to exclude the version header that normally appears at the top of generated code.
+    /// If you aren't generating a snapshot test, this setting can just be left as a default (false)
+    pub no_version_header: bool,
+    /// The paths of the other crates' files, relative to the file being generated.
+    pub import_paths: HashMap<CrateName, String>,
+    /// The path of the file being generated, relative to the output folder.
+    pub file_name: String,
+    /// The type aliases of every crate by their Rust name. Aliases are typedefs, which
+    /// are converted by functions instead of their own `fromJson` and `toJson`.
+    pub aliases: HashMap<String, RustTypeAlias>,
+    /// Whether a `copyWith` of the file being generated uses the `_unset` default,
+    /// which is then written at the end of the file.
+    pub uses_unset: bool,
+}
+
+impl Language for Dart {
+    fn type_map(&mut self) -> &HashMap<String, TypeMapping> {
+        &self.type_mappings
+    }
+
+    fn type_map_imports(&mut self) -> Option<&HashMap<String, String>> {
+        Some(&self.type_mapping_imports)
+    }
+
+    fn format_special_type(
+        &mut self,
+        special_ty: &SpecialRustType,
+        generic_types: &[String],
+    ) -> Result<String, RustTypeFormatError> {
+        Ok(match special_ty {
+            SpecialRustType::Vec(rtype)
+            | SpecialRustType::Array(rtype, _)
+            | SpecialRustType::Slice(rtype) => {
+                format!("List<{}>", self.format_type(rtype, generic_types)?)
+            }
+            SpecialRustType::Option(rtype) => {
+                let ty = self.format_type(rtype, generic_types)?;
+                if ty.ends_with('?') || ty == "Null" {
+                    ty
+                } else {
+                    format!("{ty}?")
+                }
+            }
+            SpecialRustType::HashMap(rtype1, rtype2) => {
+                format!(
+                    "Map<{}, {}>",
+                    self.format_type(rtype1, generic_types)?,
+                    self.format_type(rtype2, generic_types)?
+                )
+            }
+            SpecialRustType::Unit => "Null".into(),
+            SpecialRustType::String | SpecialRustType::Char => "String".into(),
+            SpecialRustType::I8
+            | SpecialRustType::I16
+            | SpecialRustType::I32
+            | SpecialRustType::I54
+            | SpecialRustType::I64
+            | SpecialRustType::ISize
+            | SpecialRustType::U8
+            | SpecialRustType::U16
+            | SpecialRustType::U32
+            | SpecialRustType::U53
+            | SpecialRustType::U64
+            | SpecialRustType::USize => "int".into(),
+            SpecialRustType::Bool => "bool".into(),
+            SpecialRustType::F32 | SpecialRustType::F64 => "double".into(),
+        })
+    }
+
+    fn begin_file(&mut self, w: &mut dyn Write, parsed_data: &ParsedData) -> std::io::Result<()> {
+        self.file_name = parsed_data.file_name.clone();
+        self.uses_unset = false;
+        self.aliases.extend(
+            parsed_data
+                .aliases
+                .iter()
+                .map(|alias| (alias.id.original.clone(), alias.clone())),
+        );
+
+        if !self.no_version_header {
+            writeln!(w, "// Generated by typeshare {}", env!("CARGO_PKG_VERSION"))?;
+            writeln!(w)?;
+        }
+        if parsed_data
+            .enums
+            .iter()
+            .any(|e| matches!(e, RustEnum::Unit(_)))
+        {
+            writeln!(w, "import 'package:json_annotation/json_annotation.dart';")?;
+            writeln!(w)?;
+        }
+        Ok(())
+    }
+
+    fn end_file(&mut self, w: &mut dyn Write) -> std::io::Result<()> {
+        if self.uses_unset {
+            writeln!(
+                w,
+                "/// The default of nullable `copyWith` parameters, which tells an omitted"
+            )?;
+            writeln!(w, "/// argument apart from `null`.")?;
+            writeln!(w, "const Object _unset = Object();")?;
+        }
+        Ok(())
+    }
+
+    fn write_imports(
+        &mut self,
+        w: &mut dyn Write,
+        imports: ScopedCrateTypes<'_>,
+    ) -> std::io::Result<()> {
+        if imports.is_empty() {
+            return Ok(());
+        }
+        for crate_name in imports.keys() {
+            let relative_path = match self.import_paths.get(crate_name) {
+                Some(path) => path.clone(),
+                None => format!("./{crate_name}"),
+            };
+            if self.package.is_empty() {
+                let path = relative_path.strip_prefix("./").unwrap_or(&relative_path);
+                writeln!(w, "import '{path}.dart';")?;
+            } else {
+                writeln!(
+                    w,
+                    "import 'package:{}/{}.dart';",
+                    self.package,
+                    package_path(&self.file_name, &relative_path)
+                )?;
+            }
+        }
+        writeln!(w)
+    }
+
+    fn write_type_map_imports(
+        &mut self,
+        w: &mut dyn Write,
+        imports: &BTreeSet<MappedImport>,
+    ) -> std::io::Result<()> {
+        for import in imports.iter().map(|import| &import.import).sorted().dedup() {
+            writeln!(w, "import '{import}';")?;
+        }
+        writeln!(w)
+    }
+
+    fn write_type_alias(&mut self, w: &mut dyn Write, ty: &RustTypeAlias) -> std::io::Result<()> {
+        let name = &ty.id.renamed;
+        let function = ty.id.original.to_camel_case();
+        let generics = generic_parameters(&ty.generic_types);
+        let aliased = self
+            .format_type(&ty.r#type, &ty.generic_types)
+            .map_err(std::io::Error::other)?;
+        let decoded = self
+            .decode(&ty.r#type, "json", &ty.generic_types, 0)
+            .map_err(std::io::Error::other)?;
+        let encoded = self
+            .encode(&ty.r#type, "value", &ty.generic_types, 0)
+            .map_err(std::io::Error::other)?;
+
+        self.write_comments(w, 0, &ty.comments)?;
+        writeln!(w, "typedef {name}{generics} = {aliased};")?;
+        writeln!(w)?;
+        writeln!(
+            w,
+            "{name}{generics} {function}FromJson{generics}({}) =>",
+            std::iter::once("dynamic json".to_owned())
+                .chain(from_json_parameters(&ty.generic_types))
+                .join(", ")
+        )?;
+        writeln!(w, "    {decoded};")?;
+        writeln!(w)?;
+        writeln!(
+            w,
+            "Object? {function}ToJson{generics}({}) =>",
+            std::iter::once(format!("{name}{generics} value"))
+                .chain(to_json_parameters(&ty.generic_types))
+                .join(", ")
+        )?;
+        writeln!(w, "    {encoded};")?;
+        writeln!(w)
+    }
+
+    fn write_struct(&mut self, w: &mut dyn Write, rs: &RustStruct) -> std::io::Result<()> {
+        let name = &rs.id.renamed;
+        let generics = generic_parameters(&rs.generic_types);
+        let fields = rs
+            .fields
+            .iter()
+            .map(|f| self.field(f, &rs.generic_types))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(std::io::Error::other)?;
+
+        self.write_comments(w, 0, &rs.comments)?;
+        writeln!(w, "class {name}{generics} {{")?;
+        if fields.is_empty() {
+            writeln!(w, "  const {name}();")?;
+        } else {
+            writeln!(w, "  const {name}({{")?;
+            for field in &fields {
+                let required = if field.ty.ends_with('?') {
+                    ""
+                } else {
+                    "required "
+                };
+                writeln!(w, "    {required}this.{},", field.name)?;
+            }
+            writeln!(w, "  }});")?;
+        }
+        writeln!(w)?;
+
+        writeln!(
+            w,
+            "  factory {name}.fromJson({}) =>",
+            std::iter::once("Map<String, dynamic> json".to_owned())
+                .chain(from_json_parameters(&rs.generic_types))
+                .join(", ")
+        )?;
+        if fields.is_empty() {
+            writeln!(w, "      const {name}{generics}();")?;
+        } else {
+            writeln!(w, "      {name}{generics}(")?;
+            for field in &fields {
+                writeln!(w, "        {}: {},", field.name, field.decoded)?;
+            }
+            writeln!(w, "      );")?;
+        }
+
+        for (field, f) in fields.iter().zip(&rs.fields) {
+            writeln!(w)?;
+            self.write_comments(w, 1, &f.comments)?;
+            writeln!(w, "  final {} {};", field.ty, field.name)?;
+        }
+        writeln!(w)?;
+
+        let to_json = to_json_parameters(&rs.generic_types).join(", ");
+        if fields.is_empty() {
+            writeln!(w, "  Map<String, dynamic> toJson({to_json}) => {{}};")?;
+        } else {
+            writeln!(w, "  Map<String, dynamic> toJson({to_json}) => {{")?;
+            for field in &fields {
+                writeln!(w, "        {}: {},", dart_string(&field.key), field.encoded)?;
+            }
+            writeln!(w, "      }};")?;
+        }
+
+        if !fields.is_empty() {
+            writeln!(w)?;
+            // Nullable fields default to `_unset`, so they can be set back to `null`.
+            writeln!(w, "  {name}{generics} copyWith({{")?;
+            for field in &fields {
+                if field.nullable {
+                    self.uses_unset = true;
+                    writeln!(w, "    Object? {} = _unset,", field.name)?;
+                } else {
+                    writeln!(w, "    {}? {},", field.ty, field.name)?;
+                }
+            }
+            writeln!(w, "  }}) =>")?;
+            writeln!(w, "      {name}{generics}(")?;
+            for field in &fields {
+                if field.nullable {
+                    writeln!(
+                        w,
+                        "        {0}: identical({0}, _unset) ? this.{0} : {0} as {1},",
+                        field.name, field.ty
+                    )?;
+                } else {
+                    writeln!(w, "        {0}: {0} ?? this.{0},", field.name)?;
+                }
+            }
+            writeln!(w, "      );")?;
+        }
+
+        if rs.is_redacted {
+            writeln!(w)?;
+            writeln!(w, "  @override")?;
+            writeln!(w, "  String toString() => {};", dart_string(name))?;
+        }
+        writeln!(w, "}}")?;
+        writeln!(w)
+    }
+
+    fn write_enum(&mut self, w: &mut dyn Write, e: &RustEnum) -> std::io::Result<()> {
+        // Generate named types for any anonymous struct variants of this enum
+        self.write_types_for_anonymous_structs(w, e, &|variant_name| {
+            format!("{}{}Inner", e.shared().id.renamed, variant_name)
+        })?;
+
+        self.write_comments(w, 0, &e.shared().comments)?;
+        match e {
+            RustEnum::Unit(shared) => {
+                let name = &shared.id.renamed;
+                writeln!(w, "enum {name} {{")?;
+                for (i, v) in shared.variants.iter().enumerate() {
+                    self.write_comments(w, 1, &v.shared().comments)?;
+                    let value = dart_string(&v.shared().id.renamed);
+                    writeln!(w, "  @JsonValue({value})")?;
+                    let end = if i + 1 == shared.variants.len() {
+                        ';'
+                    } else {
+                        ','
+                    };
+                    writeln!(
+                        w,
+                        "  {}({value}){end}",
+                        member_name(&v.shared().id.original.to_camel_case())
+                    )?;
+                }
+                writeln!(w)?;
+                writeln!(w, "  const {name}(this.value);")?;
+                writeln!(w)?;
+                writeln!(w, "  final String value;")?;
+                writeln!(w)?;
+                writeln!(
+                    w,
+                    "  static {name} fromJson(String json) => values.firstWhere("
+                )?;
+                writeln!(w, "        (e) => e.value == json,")?;
+                writeln!(
+                    w,
+                    "        orElse: () => throw ArgumentError.value(json, 'json', {}),",
+                    dart_string(&format!("Unknown {name} value"))
+                )?;
+                writeln!(w, "      );")?;
+                writeln!(w)?;
+                writeln!(w, "  String toJson() => value;")?;
+                writeln!(w, "}}")?;
+                writeln!(w)
+            }
+            RustEnum::Algebraic {
+                tag_key,
+                content_key,
+                shared,
+            } => {
+                let name = &shared.id.renamed;
+                let generics = generic_parameters(&shared.generic_types);
+                let to_json = to_json_parameters(&shared.generic_types).join(", ");
+                let content_name = member_name(&FieldNaming::CamelCase.apply(content_key));
+
+                writeln!(w, "sealed class {name}{generics} {{")?;
+                writeln!(w, "  const {name}();")?;
+                writeln!(w)?;
+                writeln!(
+                    w,
+                    "  factory {name}.fromJson({}) =>",
+                    std::iter::once("Map<String, dynamic> json".to_owned())
+                        .chain(from_json_parameters(&shared.generic_types))
+                        .join(", ")
+                )?;
+                writeln!(w, "      switch (json[{}]) {{", dart_string(tag_key))?;
+                let content = format!("json[{}]", dart_string(content_key));
+                for v in &shared.variants {
+                    let class_name = format!("{name}{}", v.shared().id.original.to_pascal_case());
+                    let decoded = match v {
+                        RustEnumVariant::Unit(_) => String::new(),
+                        RustEnumVariant::Tuple { ty, .. } => self
+                            .decode(ty, &content, &shared.generic_types, 0)
+                            .map_err(std::io::Error::other)?,
+                        RustEnumVariant::AnonymousStruct {
+                            shared: variant, ..
+                        } => self
+                            .decode(
+                                &anonymous_struct_type(e, v, &variant.id.original),
+                                &content,
+                                &shared.generic_types,
+                                0,
+                            )
+                            .map_err(std::io::Error::other)?,
+                    };
+                    writeln!(
+                        w,
+                        "        {} => {class_name}{generics}({decoded}),",
+                        dart_string(&v.shared().id.renamed)
+                    )?;
+                }
+                writeln!(
+                    w,
+                    "        final tag => throw ArgumentError.value(tag, {}, {}),",
+                    dart_string(tag_key),
+                    dart_string(&format!("Unknown {name} variant"))
+                )?;
+                writeln!(w, "      }};")?;
+                writeln!(w)?;
+                writeln!(w, "  Map<String, dynamic> toJson({to_json});")?;
+                writeln!(w, "}}")?;
+                writeln!(w)?;
+
+                for v in &shared.variants {
+                    let class_name = format!("{name}{}", v.shared().id.original.to_pascal_case());
+                    let content_type = match v {
+                        RustEnumVariant::Unit(_) => None,
+                        RustEnumVariant::Tuple { ty, .. } => Some(ty.clone()),
+                        RustEnumVariant::AnonymousStruct {
+                            shared: variant, ..
+                        } => Some(anonymous_struct_type(e, v, &variant.id.original)),
+                    };
+
+                    self.write_comments(w, 0, &v.shared().comments)?;
+                    writeln!(
+                        w,
+                        "class {class_name}{generics} extends {name}{generics} {{"
+                    )?;
+                    let mut entries = vec![format!(
+                        "{}: {}",
+                        dart_string(tag_key),
+                        dart_string(&v.shared().id.renamed)
+                    )];
+                    match content_type {
+                        None => {
+                            writeln!(w, "  const {class_name}();")?;
+                        }
+                        Some(content_type) => {
+                            let ty = self
+                                .format_type(&content_type, &shared.generic_types)
+                                .map_err(std::io::Error::other)?;
+                            let encoded = self
+                                .encode(&content_type, &content_name, &shared.generic_types, 0)
+                                .map_err(std::io::Error::other)?;
+                            entries.push(format!("{}: {encoded}", dart_string(content_key)));
+
+                            writeln!(w, "  const {class_name}(this.{content_name});")?;
+                            writeln!(w)?;
+                            writeln!(w, "  final {ty} {content_name};")?;
+                            writeln!(w)?;
+                            writeln!(
+                                w,
+                                "  {class_name}{generics} copyWith({{{} {content_name}}}) =>",
+                                nullable(&ty)
+                            )?;
+                            writeln!(
+                                w,
+                                "      {class_name}{generics}({content_name} ?? this.{content_name});"
+                            )?;
+                        }
+                    }
+                    writeln!(w)?;
+                    writeln!(w, "  @override")?;
+                    writeln!(w, "  Map<String, dynamic> toJson({to_json}) => {{")?;
+                    for entry in entries {
+                        writeln!(w, "        {entry},")?;
+                    }
+                    writeln!(w, "      }};")?;
+                    writeln!(w, "}}")?;
+                    writeln!(w)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn ignored_reference_types(&self) -> Vec<&str> {
+        self.type_mappings.keys().map(|s| s.as_str()).collect()
+    }
+
+    fn prepare_crates(&mut self, crate_parsed_data: &BTreeMap<CrateName, ParsedData>) {
+        self.aliases.extend(
+            crate_parsed_data
+                .values()
+                .flat_map(|parsed_data| &parsed_data.aliases)
+                .map(|alias| (alias.id.original.clone(), alias.clone())),
+        );
+    }
+
+    fn set_import_paths(&mut self, import_paths: HashMap<CrateName, String>) {
+        self.import_paths = import_paths;
+    }
+}
+
+/// A field of a class, with the expressions to convert it from and to JSON.
+struct Field {
+    key: String,
+    name: String,
+    ty: String,
+    /// Whether `null` is a value of the field.
+    nullable: bool,
+    decoded: String,
+    encoded: String,
+}
+
+impl Dart {
+    fn field(
+        &mut self,
+        f: &RustField,
+        generic_types: &[String],
+    ) -> Result<Field, RustTypeFormatError> {
+        let name = member_name(&FieldNaming::CamelCase.apply(&f.id.renamed));
+        let mut ty = match f.type_override(SupportedLanguage::Dart) {
+            Some(type_override) => type_override.to_owned(),
+            None => self.format_type(&f.ty, generic_types)?,
+        };
+        // A missing field with a default is null, like a missing optional field.
+        let field_ty = if f.has_default && !f.ty.is_optional() {
+            ty = nullable(&ty);
+            RustType::Special(SpecialRustType::Option(Box::new(f.ty.clone())))
+        } else {
+            f.ty.clone()
+        };
+        let nullable = match f.type_override(SupportedLanguage::Dart) {
+            Some(_) => is_nullable(&ty),
+            None => is_nullable(&ty) || self.can_be_null(&field_ty, generic_types),
+        };
+        let json = format!("json[{}]", dart_string(&f.id.renamed));
+        Ok(Field {
+            nullable,
+            key: f.id.renamed.clone(),
+            decoded: match f.type_override(SupportedLanguage::Dart) {
+                Some(_) => format!("{json} as {ty}"),
+                None => self.decode(&field_ty, &json, generic_types, 0)?,
+            },
+            encoded: match f.type_override(SupportedLanguage::Dart) {
+                Some(_) => name.clone(),
+                None => self.encode(&field_ty, &name, generic_types, 0)?,
+            },
+            name,
+            ty,
+        })
+    }
+
+    /// Whether `null` is a value of `ty`. Generic parameters can be nullable types, and
+    /// so can aliases, which are typedefs.
+    fn can_be_null(&self, ty: &RustType, generic_types: &[String]) -> bool {
+        match ty {
+            RustType::Special(SpecialRustType::Option(_) | SpecialRustType::Unit) => true,
+            RustType::Special(_) => false,
+            RustType::Simple { id } if generic_types.contains(id) => true,
+            RustType::Simple { id } | RustType::Generic { id, .. } => self
+                .aliases
+                .get(id)
+                .is_some_and(|alias| self.can_be_null(&alias.r#type, &alias.generic_types)),
+        }
+    }
+
+    /// An expression converting the JSON value `expr` to `ty`.
+    fn decode(
+        &mut self,
+        ty: &RustType,
+        expr: &str,
+        generic_types: &[String],
+        depth: usize,
+    ) -> Result<String, RustTypeFormatError> {
+        let value = format!("v{depth}");
+        Ok(match ty {
+            RustType::Special(special) => match special {
+                SpecialRustType::Vec(rtype)
+                | SpecialRustType::Array(rtype, _)
+                | SpecialRustType::Slice(rtype) => format!(
+                    "({expr} as List<dynamic>).map(({value}) => {}).toList()",
+                    self.decode(rtype, &value, generic_types, depth + 1)?
+                ),
+                SpecialRustType::HashMap(key, rtype) => {
+                    let key_value = format!("k{depth}");
+                    format!(
+                        "({expr} as Map<String, dynamic>).map(({key_value}, {value}) => MapEntry({}, {}))",
+                        self.decode_key(key, &key_value, generic_types, depth)?,
+                        self.decode(rtype, &value, generic_types, depth + 1)?
+                    )
+                }
+                SpecialRustType::Option(rtype) => match rtype.as_ref() {
+                    RustType::Special(
+                        SpecialRustType::String | SpecialRustType::Char | SpecialRustType::Bool,
+                    ) => format!("{expr} as {}?", self.format_type(rtype, generic_types)?),
+                    RustType::Special(SpecialRustType::F32 | SpecialRustType::F64) => {
+                        format!("({expr} as num?)?.toDouble()")
+                    }
+                    RustType::Special(special) if is_integer(special) => {
+                        format!("({expr} as num?)?.toInt()")
+                    }
+                    RustType::Special(SpecialRustType::Unit) => "null".into(),
+                    _ => format!(
+                        "{expr} == null ? null : {}",
+                        self.decode(rtype, expr, generic_types, depth)?
+                    ),
+                },
+                SpecialRustType::Unit => "null".into(),
+                SpecialRustType::String | SpecialRustType::Char => format!("{expr} as String"),
+                SpecialRustType::Bool => format!("{expr} as bool"),
+                SpecialRustType::F32 | SpecialRustType::F64 => {
+                    format!("({expr} as num).toDouble()")
+                }
+                _ => format!("({expr} as num).toInt()"),
+            },
+            RustType::Simple { id } if generic_types.contains(id) => {
+                format!("fromJson{id}({expr})")
+            }
+            RustType::Simple { id } | RustType::Generic { id, .. }
+                if self.type_mappings.contains_key(id) =>
+            {
+                let mapped = self.format_type(ty, generic_types)?;
+                match self.json_converters.get(id) {
+                    Some(converter) => format!("{}({expr})", converter.from_json),
+                    None if mapped == "double" => format!("({expr} as num).toDouble()"),
+                    None if mapped == "int" => format!("({expr} as num).toInt()"),
+                    None if JSON_TYPES.contains(&mapped.as_str()) => format!("{expr} as {mapped}"),
+                    None => {
+                        return Err(RustTypeFormatError::MappedTypeWithoutConverterInDart(
+                            id.clone(),
+                        ))
+                    }
+                }
+            }
+            RustType::Simple { id } | RustType::Generic { id, .. } => {
+                let parameters = ty.parameters().collect_vec();
+                let type_arguments = self.type_arguments(&parameters, generic_types)?;
+                let mut arguments = vec![expr.to_owned()];
+                for parameter in parameters {
+                    arguments.push(format!(
+                        "(dynamic {value}) => {}",
+                        self.decode(parameter, &value, generic_types, depth + 1)?
+                    ));
+                }
+                let arguments = arguments.join(", ");
+                if self.aliases.contains_key(id) {
+                    format!(
+                        "{}FromJson{type_arguments}({arguments})",
+                        id.to_camel_case()
+                    )
+                } else {
+                    format!("{id}{type_arguments}.fromJson({arguments})")
+                }
+            }
+        })
+    }
+
+    /// An expression converting the JSON object key `expr` to `ty`.
+    fn decode_key(
+        &mut self,
+        ty: &RustType,
+        expr: &str,
+        generic_types: &[String],
+        depth: usize,
+    ) -> Result<String, RustTypeFormatError> {
+        Ok(match ty {
+            RustType::Special(SpecialRustType::String | SpecialRustType::Char) => expr.to_owned(),
+            RustType::Special(special) if is_integer(special) => format!("int.parse({expr})"),
+            _ => self.decode(ty, expr, generic_types, depth + 1)?,
+        })
+    }
+
+    /// An expression converting the value `expr` of type `ty` to JSON.
+    fn encode(
+        &mut self,
+        ty: &RustType,
+        expr: &str,
+        generic_types: &[String],
+        depth: usize,
+    ) -> Result<String, RustTypeFormatError> {
+        let value = format!("v{depth}");
+        Ok(match ty {
+            RustType::Special(special) => match special {
+                SpecialRustType::Vec(rtype)
+                | SpecialRustType::Array(rtype, _)
+                | SpecialRustType::Slice(rtype) => {
+                    let encoded = self.encode(rtype, &value, generic_types, depth + 1)?;
+                    if encoded == value {
+                        expr.to_owned()
+                    } else {
+                        format!("{expr}.map(({value}) => {encoded}).toList()")
+                    }
+                }
+                SpecialRustType::HashMap(key, rtype) => {
+                    let key_value = format!("k{depth}");
+                    let encoded_key = match key.as_ref() {
+                        RustType::Special(SpecialRustType::String | SpecialRustType::Char) => {
+                            key_value.clone()
+                        }
+                        RustType::Special(special) if is_integer(special) => {
+                            format!("{key_value}.toString()")
+                        }
+                        _ => format!(
+                            "{} as String",
+                            self.encode(key, &key_value, generic_types, depth + 1)?
+                        ),
+                    };
+                    let encoded = self.encode(rtype, &value, generic_types, depth + 1)?;
+                    if encoded_key == key_value && encoded == value {
+                        expr.to_owned()
+                    } else {
+                        format!(
+                            "{expr}.map(({key_value}, {value}) => MapEntry({encoded_key}, {encoded}))"
+                        )
+                    }
+                }
+                SpecialRustType::Option(rtype) => {
+                    let non_null = format!("{expr}!");
+                    let encoded = self.encode(rtype, &non_null, generic_types, depth)?;
+                    if encoded == non_null {
+                        expr.to_owned()
+                    } else {
+                        format!("{expr} == null ? null : {encoded}")
+                    }
+                }
+                _ => expr.to_owned(),
+            },
+            RustType::Simple { id } if generic_types.contains(id) => {
+                format!("toJson{id}({expr})")
+            }
+            RustType::Simple { id } | RustType::Generic { id, .. }
+                if self.type_mappings.contains_key(id) =>
+            {
+                match self.json_converters.get(id) {
+                    Some(converter) => format!("{}({expr})", converter.to_json),
+                    None => expr.to_owned(),
+                }
+            }
+            RustType::Simple { id } | RustType::Generic { id, .. } => {
+                let parameters = ty.parameters().collect_vec();
+                let mut arguments = Vec::new();
+                for parameter in &parameters {
+                    arguments.push(format!(
+                        "({value}) => {}",
+                        self.encode(parameter, &value, generic_types, depth + 1)?
+                    ));
+                }
+                if self.aliases.contains_key(id) {
+                    let type_arguments = self.type_arguments(&parameters, generic_types)?;
+                    format!(
+                        "{}ToJson{type_arguments}({})",
+                        id.to_camel_case(),
+                        std::iter::once(expr.to_owned()).chain(arguments).join(", ")
+                    )
+                } else {
+                    format!("{expr}.toJson({})", arguments.join(", "))
+                }
+            }
+        })
+    }
+
+    /// The type arguments of a generic type, like `<String, int>`.
+    fn type_arguments(
+        &mut self,
+        parameters: &[&RustType],
+        generic_types: &[String],
+    ) -> Result<String, RustTypeFormatError> {
+        if parameters.is_empty() {
+            return Ok(String::new());
+        }
+        let parameters = parameters
+            .iter()
+            .map(|p| self.format_type(p, generic_types))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(format!("<{}>", parameters.join(", ")))
+    }
+
+    fn write_comments(
+        &self,
+        w: &mut dyn Write,
+        indent: usize,
+        comments: &[String],
+    ) -> std::io::Result<()> {
+        for comment in comments {
+            writeln!(w, "{}/// {comment}", "  ".repeat(indent))?;
+        }
+        Ok(())
+    }
+}
+
+fn is_integer(special: &SpecialRustType) -> bool {
+    matches!(
+        special,
+        SpecialRustType::I8
+            | SpecialRustType::I16
+            | SpecialRustType::I32
+            | SpecialRustType::I54
+            | SpecialRustType::I64
+            | SpecialRustType::ISize
+            | SpecialRustType::U8
+            | SpecialRustType::U16
+            | SpecialRustType::U32
+            | SpecialRustType::U53
+            | SpecialRustType::U64
+            | SpecialRustType::USize
+    )
+}
+
+/// The type of the record generated for an anonymous struct variant, with the
+/// generic parameters of the enum its fields use.
+fn anonymous_struct_type(e: &RustEnum, v: &RustEnumVariant, variant_name: &str) -> RustType {
+    let id = format!("{}{}Inner", e.shared().id.renamed, variant_name);
+    let RustEnumVariant::AnonymousStruct { fields, .. } = v else {
+        return RustType::Simple { id };
+    };
+    let parameters = e
+        .shared()
+        .generic_types
+        .iter()
+        .filter(|g| fields.iter().any(|f| f.ty.contains_type(g)))
+        .map(|g| RustType::Simple { id: g.clone() })
+        .collect_vec();
+    if parameters.is_empty() {
+        RustType::Simple { id }
+    } else {
+        RustType::Generic { id, parameters }
+    }
+}
+
+/// Escape a name that is a keyword or the name of a member every class has.
+fn member_name(name: &str) -> String {
+    let name = name
+        .chars()
+        .filter(|c| c.is_alphanumeric() || *c == '_' || *c == '$')
+        .collect::<String>();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("${name}")
+    } else if DART_KEYWORDS.contains(&name.as_str()) || RESERVED_MEMBERS.contains(&name.as_str()) {
+        format!("{name}_")
+    } else {
+        name
+    }
+}
+
+/// A single quoted string literal.
+fn dart_string(value: &str) -> String {
+    format!(
+        "'{}'",
+        value
+            .replace('\\', "\\\\")
+            .replace('\'', "\\'")
+            .replace('$', "\\$")
+    )
+}
+
+/// A nullable version of a type.
+fn nullable(ty: &str) -> String {
+    if is_nullable(ty) {
+        ty.to_owned()
+    } else {
+        format!("{ty}?")
+    }
+}
+
+/// Whether `null` is a value of the type.
+fn is_nullable(ty: &str) -> bool {
+    ty.ends_with('?') || ty == "Null" || ty == "dynamic"
+}
+
+/// Format the generic parameters of a declaration, like `<T, U>`.
+fn generic_parameters(generic_types: &[String]) -> String {
+    if generic_types.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", generic_types.join(", "))
+    }
+}
+
+/// The parameters converting the generic parameters from JSON, like `fromJsonT`.
+fn from_json_parameters(generic_types: &[String]) -> impl Iterator<Item = String> + '_ {
+    generic_types
+        .iter()
+        .map(|g| format!("{g} Function(Object? json) fromJson{g}"))
+}
+
+/// The parameters converting the generic parameters to JSON, like `toJsonT`.
+fn to_json_parameters(generic_types: &[String]) -> impl Iterator<Item = String> + '_ {
+    generic_types
+        .iter()
+        .map(|g| format!("Object? Function({g} value) toJson{g}"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn mapped_types_need_a_converter() {
+        let mut dart = Dart {
+            type_mappings: [
+                ("DateTime".to_owned(), "DateTime".into()),
+                ("Decimal".to_owned(), "double".into()),
+            ]
+            .into(),
+            ..Default::default()
+        };
+        let date_time = RustType::Simple {
+            id: "DateTime".to_owned(),
+        };
+        let decimal = RustType::Simple {
+            id: "Decimal".to_owned(),
+        };
+
+        assert!(matches!(
+            dart.decode(&date_time, "json", &[], 0),
+            Err(RustTypeFormatError::MappedTypeWithoutConverterInDart(_))
+        ));
+        assert_eq!(
+            dart.decode(&decimal, "json", &[], 0).unwrap(),
+            "(json as num).toDouble()"
+        );
+        assert_eq!(dart.encode(&decimal, "value", &[], 0).unwrap(), "value");
+
+        dart.json_converters.insert(
+            "DateTime".to_owned(),
+            JsonConverter {
+                from_json: "DateTime.parse".to_owned(),
+                to_json: "dateTimeToJson".to_owned(),
+            },
+        );
+        assert_eq!(
+            dart.decode(&date_time, "json", &[], 0).unwrap(),
+            "DateTime.parse(json)"
+        );
+        assert_eq!(
+            dart.encode(&date_time, "value", &[], 0).unwrap(),
+            "dateTimeToJson(value)"
+        );
+    }
+}
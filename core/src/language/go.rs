@@ -5,11 +5,12 @@ use crate::parser::ParsedData;
 use crate::rename::RenameExt;
 use crate::rust_types::{RustItem, RustTypeFormatError, SpecialRustType};
 use crate::{
-    language::Language,
+    language::{used_type_map_imports, Language, MappedImport, TypeMapping},
     rust_types::{RustEnum, RustEnumVariant, RustField, RustStruct, RustTypeAlias},
-    topsort::topsort,
+    topsort::sort_items,
 };
-use std::collections::{HashMap, HashSet};
+use itertools::Itertools;
+use std::collections::{BTreeSet, HashMap, HashSet};
 
 use super::CrateTypes;
 
@@ -19,7 +20,9 @@ pub struct Go {
     /// Name of the Go package.
     pub package: String,
     /// Conversions from Rust type names to Go type names.
-    pub type_mappings: HashMap<String, String>,
+    pub type_mappings: HashMap<String, TypeMapping>,
+    /// Imports needed by the mapped types, from Rust type names to the import of the type they map to.
+    pub type_mapping_imports: HashMap<String, String>,
     /// Abbreviations that should be fully uppercased to comply with Go's formatting rules.
     pub uppercase_acronyms: Vec<String>,
     /// Whether or not to exclude the version header that normally appears at the top of generated code.
@@ -54,10 +57,17 @@ impl Language for Go {
     ) -> std::io::Result<()> {
         self.begin_file(w, &data)?;
 
+        let mapped_imports = used_type_map_imports(self, &data);
+        if !mapped_imports.is_empty() {
+            self.write_type_map_imports(w, &mapped_imports)?;
+        }
+
         let ParsedData {
             structs,
             enums,
             aliases,
+            source_positions,
+            type_ordering,
             ..
         } = data;
 
@@ -68,7 +78,7 @@ impl Language for Go {
             .chain(enums.into_iter().map(RustItem::Enum))
             .collect::<Vec<_>>();
 
-        topsort(&mut items);
+        sort_items(&mut items, type_ordering, &source_positions, true);
 
         // Generate a list of all types that either are a struct or are aliased to a struct.
         // This is used to determine whether a type should be defined as a pointer or not.
@@ -102,10 +112,14 @@ impl Language for Go {
         self.end_file(w)
     }
 
-    fn type_map(&mut self) -> &HashMap<String, String> {
+    fn type_map(&mut self) -> &HashMap<String, TypeMapping> {
         &self.type_mappings
     }
 
+    fn type_map_imports(&mut self) -> Option<&HashMap<String, String>> {
+        Some(&self.type_mapping_imports)
+    }
+
     fn format_generic_parameters(&mut self, parameters: Vec<String>) -> String {
         format!("[{}]", parameters.join(", "))
     }
@@ -183,7 +197,7 @@ impl Language for Go {
             "type {} {}\n",
             self.acronyms_to_uppercase(&ty.id.original),
             self.format_type(&ty.r#type, &[])
-                .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?
+                .map_err(std::io::Error::other)?
         )?;
 
         Ok(())
@@ -196,16 +210,18 @@ impl Language for Go {
             w,
             "type {}{} struct {{",
             self.acronyms_to_uppercase(&rs.id.renamed),
-            (!rs.generic_types.is_empty())
-                .then(|| format!(
+            if !rs.generic_types.is_empty() {
+                format!(
                     "[{}]",
                     rs.generic_types
                         .iter()
                         .map(|ty| format!("{} any", ty))
                         .collect::<Vec<String>>()
                         .join(", ")
-                ))
-                .unwrap_or_default()
+                )
+            } else {
+                String::new()
+            }
         )?;
 
         rs.fields
@@ -215,6 +231,22 @@ impl Language for Go {
         writeln!(w, "}}")
     }
 
+    fn write_type_map_imports(
+        &mut self,
+        w: &mut dyn Write,
+        imports: &BTreeSet<MappedImport>,
+    ) -> std::io::Result<()> {
+        for package in imports
+            .iter()
+            .map(|import| &import.import)
+            .dedup()
+            .filter(|package| *package != "encoding/json")
+        {
+            writeln!(w, "import \"{package}\"")?;
+        }
+        writeln!(w)
+    }
+
     fn write_imports(
         &mut self,
         _writer: &mut dyn Write,
@@ -476,7 +508,7 @@ func ({short_name} {full_name}) MarshalJSON() ([]byte, error) {{
             Some(type_override) => type_override.to_owned(),
             None => self
                 .format_type(&field.ty, generic_types)
-                .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?,
+                .map_err(std::io::Error::other)?,
         };
 
         let go_type = self.acronyms_to_uppercase(&type_name);
@@ -487,9 +519,11 @@ func ({short_name} {full_name}) MarshalJSON() ([]byte, error) {{
             w,
             "\t{} {}{} `json:\"{}{}\"`",
             self.format_field_name(field.id.original.to_string(), true),
-            (field.has_default && !field.ty.is_optional())
-                .then_some("*")
-                .unwrap_or_default(),
+            if field.has_default && !field.ty.is_optional() {
+                "*"
+            } else {
+                ""
+            },
             go_type,
             renamed_id,
             option_symbol(is_optional),
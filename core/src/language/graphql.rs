@@ -0,0 +1,534 @@
+use super::{
+    format_mapped_type, mapped_type_name, CrateName, Language, ScopedCrateTypes, TypeMapping,
+};
+use crate::{
+    language::SupportedLanguage,
+    parser::{DecoratorKind, ParsedData},
+    rust_types::{
+        RustEnum, RustEnumShared, RustEnumVariant, RustField, RustStruct, RustType, RustTypeAlias,
+        RustTypeFormatError, SpecialRustType,
+    },
+    GenerationError,
+};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    io::{self, Write},
+    path::Path,
+};
+
+/// The scalars every GraphQL schema has.
+const BUILT_IN_SCALARS: &[&str] = &["Boolean", "Float", "ID", "Int", "String"];
+
+/// The scalar that maps and the unit type are, since GraphQL has neither.
+const JSON_SCALAR: &str = "JSON";
+
+/// The file the scalars of every crate are declared in, when writing one file per crate.
+const SCALARS_FILE_NAME: &str = "scalars.graphql";
+
+/// All information needed to generate a GraphQL schema.
+///
+/// Structs are object types, or input types with `#[typeshare(graphql = "input")]`,
+/// or both with `#[typeshare(graphql = "type, input")]`, in which case the input
+/// type is named `{Name}Input`. GraphQL has no type aliases, so references to
+/// aliases are replaced with the types they alias.
+#[derive(Default)]
+pub struct GraphQl {
+    /// Conversions from Rust type names to GraphQL type names. Mapped types that
+    /// aren't built in scalars are declared as custom scalars.
+    pub type_mappings: HashMap<String, TypeMapping>,
+    /// The URLs of the specifications of mapped scalars, from Rust type names, for
+    /// their `@specifiedBy` directive.
+    pub scalar_specifications: HashMap<String, String>,
+    /// Whether or not to exclude the version header that normally appears at the top of generated code.
+    /// If you aren't generating a snapshot test, this setting can just be left as a default (false)
+    pub no_version_header: bool,
+    /// Whether the files are written one per crate. Scalars are then declared once,
+    /// in a file of their own, instead of in every file using them.
+    pub multi_file: bool,
+    /// The custom scalars used by the files, with the URL of their specification.
+    pub scalars: BTreeMap<String, Option<String>>,
+    /// The kinds of the structs and algebraic enums, by their serialized names.
+    pub kinds: HashMap<String, GraphQlKind>,
+    /// The types that type aliases alias, by the serialized names of the aliases.
+    pub aliases: HashMap<String, RustType>,
+    /// Whether the type being generated is an input type.
+    pub in_input: bool,
+}
+
+/// The kinds of GraphQL types a struct is generated as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphQlKind {
+    /// An object type, which is the default.
+    Type,
+    /// An input type.
+    Input,
+    /// Both an object type and an input type named `{Name}Input`.
+    Both,
+}
+
+impl GraphQlKind {
+    fn of(rs: &RustStruct) -> Self {
+        let Some(kinds) = rs.decorators.get(&DecoratorKind::GraphQl) else {
+            return GraphQlKind::Type;
+        };
+        match (kinds.contains("type"), kinds.contains("input")) {
+            (true, true) => GraphQlKind::Both,
+            (false, true) => GraphQlKind::Input,
+            _ => GraphQlKind::Type,
+        }
+    }
+}
+
+impl Language for GraphQl {
+    fn type_map(&mut self) -> &HashMap<String, TypeMapping> {
+        &self.type_mappings
+    }
+
+    fn format_simple_type(
+        &mut self,
+        base: &String,
+        generic_types: &[String],
+    ) -> Result<String, RustTypeFormatError> {
+        if generic_types.contains(base) {
+            return Err(RustTypeFormatError::GenericsForbiddenInGraphQl(
+                base.clone(),
+            ));
+        }
+        if let Some(mapped) = self.type_mappings.get(base) {
+            return mapped.apply(base, &[]);
+        }
+        if let Some(aliased) = self.aliases.get(base).cloned() {
+            return self.format_type(&aliased, generic_types);
+        }
+        if !self.in_input {
+            return Ok(base.clone());
+        }
+        match self.kinds.get(base) {
+            Some(GraphQlKind::Type) => {
+                Err(RustTypeFormatError::OutputTypeInGraphQlInput(base.clone()))
+            }
+            Some(GraphQlKind::Both) => Ok(format!("{base}Input")),
+            Some(GraphQlKind::Input) | None => Ok(base.clone()),
+        }
+    }
+
+    /// Only mapped types can have generic parameters.
+    fn format_generic_type(
+        &mut self,
+        base: &String,
+        parameters: &[RustType],
+        generic_types: &[String],
+    ) -> Result<String, RustTypeFormatError> {
+        if self.type_mappings.contains_key(base) {
+            format_mapped_type(self, base, parameters, generic_types)
+        } else {
+            Err(RustTypeFormatError::GenericsForbiddenInGraphQl(
+                base.clone(),
+            ))
+        }
+    }
+
+    /// Formats a type as it is when it is nullable, without a `!`.
+    fn format_special_type(
+        &mut self,
+        special_ty: &SpecialRustType,
+        generic_types: &[String],
+    ) -> Result<String, RustTypeFormatError> {
+        Ok(match special_ty {
+            SpecialRustType::Vec(rtype)
+            | SpecialRustType::Array(rtype, _)
+            | SpecialRustType::Slice(rtype) => {
+                format!("[{}]", self.non_null_type(rtype, generic_types)?)
+            }
+            SpecialRustType::Option(rtype) => self.format_type(rtype, generic_types)?,
+            SpecialRustType::HashMap(_, _) | SpecialRustType::Unit => JSON_SCALAR.into(),
+            SpecialRustType::String | SpecialRustType::Char => "String".into(),
+            SpecialRustType::I8
+            | SpecialRustType::I16
+            | SpecialRustType::I32
+            | SpecialRustType::U8
+            | SpecialRustType::U16 => "Int".into(),
+            // `Int` is a signed 32 bit integer, so larger integers are floats.
+            SpecialRustType::U32
+            | SpecialRustType::I54
+            | SpecialRustType::U53
+            | SpecialRustType::I64
+            | SpecialRustType::U64
+            | SpecialRustType::ISize
+            | SpecialRustType::USize
+            | SpecialRustType::F32
+            | SpecialRustType::F64 => "Float".into(),
+            SpecialRustType::Bool => "Boolean".into(),
+        })
+    }
+
+    fn begin_file(&mut self, w: &mut dyn Write, parsed_data: &ParsedData) -> io::Result<()> {
+        self.multi_file = parsed_data.multi_file;
+        self.collect_types(parsed_data);
+
+        if !self.no_version_header {
+            writeln!(w, "# Generated by typeshare {}", env!("CARGO_PKG_VERSION"))?;
+            writeln!(w)?;
+        }
+
+        let scalars = self.used_scalars(parsed_data);
+        if self.multi_file {
+            self.scalars.extend(scalars);
+            return Ok(());
+        }
+        if !scalars.is_empty() {
+            write_scalars(w, &scalars)?;
+            writeln!(w)?;
+        }
+        Ok(())
+    }
+
+    /// GraphQL schemas are made of all of their files, so types of other crates
+    /// don't need to be imported.
+    fn write_imports(
+        &mut self,
+        _writer: &mut dyn Write,
+        _imports: ScopedCrateTypes<'_>,
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Type aliases aren't written, since references to them are replaced with the
+    /// types they alias.
+    fn write_type_alias(&mut self, _w: &mut dyn Write, ty: &RustTypeAlias) -> io::Result<()> {
+        forbid_generics(&ty.generic_types)
+    }
+
+    fn write_struct(&mut self, w: &mut dyn Write, rs: &RustStruct) -> io::Result<()> {
+        forbid_generics(&rs.generic_types)?;
+        let name = &rs.id.renamed;
+        match GraphQlKind::of(rs) {
+            GraphQlKind::Type => self.write_object(w, "type", name, &rs.comments, &rs.fields),
+            GraphQlKind::Input => self.write_object(w, "input", name, &rs.comments, &rs.fields),
+            GraphQlKind::Both => {
+                self.write_object(w, "type", name, &rs.comments, &rs.fields)?;
+                self.write_object(
+                    w,
+                    "input",
+                    &format!("{name}Input"),
+                    &rs.comments,
+                    &rs.fields,
+                )
+            }
+        }
+    }
+
+    fn write_enum(&mut self, w: &mut dyn Write, e: &RustEnum) -> io::Result<()> {
+        let shared = e.shared();
+        forbid_generics(&shared.generic_types)?;
+
+        match e {
+            RustEnum::Unit(shared) => {
+                write_description(w, 0, &shared.comments)?;
+                writeln!(w, "enum {} {{", shared.id.renamed)?;
+                for variant in &shared.variants {
+                    write_description(w, 1, &variant.shared().comments)?;
+                    writeln!(w, "  {}", graphql_name(&variant.shared().id.renamed))?;
+                }
+                writeln!(w, "}}")?;
+                writeln!(w)
+            }
+            RustEnum::Algebraic {
+                tag_key,
+                content_key,
+                shared,
+            } => self.write_union(w, tag_key, content_key, shared),
+        }
+    }
+
+    fn ignored_reference_types(&self) -> Vec<&str> {
+        self.type_mappings.keys().map(|s| s.as_str()).collect()
+    }
+
+    /// Collects the types of every crate, since files can refer to types of other crates.
+    fn prepare_crates(&mut self, crate_parsed_data: &BTreeMap<CrateName, ParsedData>) {
+        for parsed_data in crate_parsed_data.values() {
+            self.collect_types(parsed_data);
+        }
+    }
+
+    /// Declares the scalars of every file in a file of their own.
+    fn post_generation(&self, output_folder: &str) -> Result<(), GenerationError> {
+        if !self.multi_file || self.scalars.is_empty() {
+            return Ok(());
+        }
+        let mut contents = Vec::new();
+        if !self.no_version_header {
+            writeln!(
+                contents,
+                "# Generated by typeshare {}\n",
+                env!("CARGO_PKG_VERSION")
+            )
+            .expect("writing to a vector can't fail");
+        }
+        write_scalars(&mut contents, &self.scalars).expect("writing to a vector can't fail");
+
+        let path = Path::new(output_folder).join(SCALARS_FILE_NAME);
+        fs::write(&path, contents)
+            .map_err(|err| GenerationError::PostGeneration(format!("{path:?}: {err}")))
+    }
+}
+
+impl GraphQl {
+    /// Record the kinds of the structs and algebraic enums and the types of the
+    /// aliases of `data`, which references name by their serialized names.
+    fn collect_types(&mut self, data: &ParsedData) {
+        self.kinds.extend(
+            data.structs
+                .iter()
+                .map(|rs| (rs.id.renamed.clone(), GraphQlKind::of(rs)))
+                .chain(data.enums.iter().filter_map(|e| match e {
+                    RustEnum::Unit(_) => None,
+                    RustEnum::Algebraic { shared, .. } => {
+                        Some((shared.id.renamed.clone(), GraphQlKind::Type))
+                    }
+                })),
+        );
+        self.aliases.extend(
+            data.aliases
+                .iter()
+                .map(|alias| (alias.id.renamed.clone(), alias.r#type.clone())),
+        );
+    }
+
+    /// The custom scalars the types of a file use, with the URL of their specification.
+    fn used_scalars(&self, data: &ParsedData) -> BTreeMap<String, Option<String>> {
+        fn visit(lang: &GraphQl, ty: &RustType, scalars: &mut BTreeMap<String, Option<String>>) {
+            match ty {
+                RustType::Special(SpecialRustType::HashMap(_, _) | SpecialRustType::Unit) => {
+                    scalars.insert(JSON_SCALAR.to_owned(), None);
+                    return;
+                }
+                RustType::Simple { id } | RustType::Generic { id, .. } => {
+                    if let Some(mapped) = lang.type_mappings.get(id) {
+                        let name = mapped_type_name(mapped.as_str());
+                        if !BUILT_IN_SCALARS.contains(&name) {
+                            scalars.insert(
+                                name.to_owned(),
+                                lang.scalar_specifications.get(id).cloned(),
+                            );
+                        }
+                        return;
+                    }
+                    if let Some(aliased) = lang.aliases.get(id) {
+                        visit(lang, aliased, scalars);
+                        return;
+                    }
+                }
+                RustType::Special(_) => {}
+            }
+            for parameter in ty.parameters() {
+                visit(lang, parameter, scalars);
+            }
+        }
+
+        let mut scalars = BTreeMap::new();
+        let fields = |fields: &'_ [RustField]| {
+            fields
+                .iter()
+                .filter(|field| field.type_override(SupportedLanguage::GraphQl).is_none())
+                .map(|field| field.ty.clone())
+                .collect::<Vec<_>>()
+        };
+        let types = data.structs.iter().flat_map(|rs| fields(&rs.fields)).chain(
+            data.enums.iter().flat_map(|e| {
+                e.shared().variants.iter().flat_map(|v| match v {
+                    RustEnumVariant::Unit(_) => Vec::new(),
+                    RustEnumVariant::Tuple { ty, .. } => vec![ty.clone()],
+                    RustEnumVariant::AnonymousStruct { fields: f, .. } => fields(f),
+                })
+            }),
+        );
+        for ty in types {
+            visit(self, &ty, &mut scalars);
+        }
+        scalars
+    }
+
+    /// Format a type as it is when it isn't nullable, with a `!` unless it is optional.
+    fn non_null_type(
+        &mut self,
+        ty: &RustType,
+        generic_types: &[String],
+    ) -> Result<String, RustTypeFormatError> {
+        if let RustType::Simple { id } = ty {
+            if let Some(aliased) = self
+                .aliases
+                .get(id)
+                .filter(|_| !self.type_mappings.contains_key(id))
+            {
+                return self.non_null_type(&aliased.clone(), generic_types);
+            }
+        }
+        let formatted = self.format_type(ty, generic_types)?;
+        if ty.is_optional() {
+            Ok(formatted)
+        } else {
+            Ok(format!("{formatted}!"))
+        }
+    }
+
+    /// Write an object or input type with `fields`. Object types without fields
+    /// can't exist, so empty structs are scalars.
+    fn write_object(
+        &mut self,
+        w: &mut dyn Write,
+        keyword: &str,
+        name: &str,
+        comments: &[String],
+        fields: &[RustField],
+    ) -> io::Result<()> {
+        write_description(w, 0, comments)?;
+        if fields.is_empty() {
+            writeln!(w, "scalar {name}")?;
+            return writeln!(w);
+        }
+
+        self.in_input = keyword == "input";
+        writeln!(w, "{keyword} {name} {{")?;
+        let written = fields
+            .iter()
+            .try_for_each(|field| self.write_field(w, field));
+        self.in_input = false;
+        written?;
+        writeln!(w, "}}")?;
+        writeln!(w)
+    }
+
+    fn write_field(&mut self, w: &mut dyn Write, field: &RustField) -> io::Result<()> {
+        let ty = match field.type_override(SupportedLanguage::GraphQl) {
+            Some(ty) => ty.to_owned(),
+            // Inputs can leave out fields that have a default.
+            None if self.in_input && field.has_default => {
+                self.format_type(&field.ty, &[]).map_err(io::Error::other)?
+            }
+            None => self
+                .non_null_type(&field.ty, &[])
+                .map_err(io::Error::other)?,
+        };
+        write_description(w, 1, &field.comments)?;
+        writeln!(w, "  {}: {ty}", graphql_name(&field.id.renamed))
+    }
+
+    /// Algebraic enums are unions of an object type per variant, named like
+    /// `{Enum}{Variant}`, with the fields the enum is serialized with: the tag,
+    /// and the content of variants that have one. The content of anonymous struct
+    /// variants is an object type named like `{Enum}{Variant}Content`.
+    fn write_union(
+        &mut self,
+        w: &mut dyn Write,
+        tag_key: &str,
+        content_key: &str,
+        shared: &RustEnumShared,
+    ) -> io::Result<()> {
+        let name = &shared.id.renamed;
+        let member_name =
+            |variant: &RustEnumVariant| format!("{name}{}", variant.shared().id.original);
+
+        write_description(w, 0, &shared.comments)?;
+        writeln!(
+            w,
+            "union {name} = {}",
+            shared
+                .variants
+                .iter()
+                .map(member_name)
+                .collect::<Vec<_>>()
+                .join(" | ")
+        )?;
+        writeln!(w)?;
+
+        let tag_key = graphql_name(tag_key);
+        let content_key = graphql_name(content_key);
+        for variant in &shared.variants {
+            let member = member_name(variant);
+            let content = match variant {
+                RustEnumVariant::Unit(_) => None,
+                RustEnumVariant::Tuple { ty, .. } => {
+                    Some(self.non_null_type(ty, &[]).map_err(io::Error::other)?)
+                }
+                RustEnumVariant::AnonymousStruct { fields, .. } if fields.is_empty() => None,
+                RustEnumVariant::AnonymousStruct { .. } => Some(format!("{member}Content!")),
+            };
+
+            write_description(w, 0, &variant.shared().comments)?;
+            writeln!(w, "type {member} {{")?;
+            writeln!(w, "  {tag_key}: String!")?;
+            if let Some(content) = content {
+                writeln!(w, "  {content_key}: {content}")?;
+            }
+            writeln!(w, "}}")?;
+            writeln!(w)?;
+
+            if let RustEnumVariant::AnonymousStruct { fields, .. } = variant {
+                if !fields.is_empty() {
+                    self.write_object(w, "type", &format!("{member}Content"), &[], fields)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn forbid_generics(generic_types: &[String]) -> io::Result<()> {
+    match generic_types.first() {
+        Some(generic) => Err(io::Error::other(
+            RustTypeFormatError::GenericsForbiddenInGraphQl(generic.clone()),
+        )),
+        None => Ok(()),
+    }
+}
+
+fn write_scalars(w: &mut dyn Write, scalars: &BTreeMap<String, Option<String>>) -> io::Result<()> {
+    for (scalar, specification) in scalars {
+        match specification {
+            Some(url) => writeln!(w, "scalar {scalar} @specifiedBy(url: \"{}\")", escape(url))?,
+            None => writeln!(w, "scalar {scalar}")?,
+        }
+    }
+    Ok(())
+}
+
+/// Write comments as a description, which is a block string.
+fn write_description(w: &mut dyn Write, indent: usize, comments: &[String]) -> io::Result<()> {
+    let indent = "  ".repeat(indent);
+    match comments {
+        [] => Ok(()),
+        [comment] if !comment.ends_with('"') => writeln!(
+            w,
+            "{indent}\"\"\"{}\"\"\"",
+            comment.replace("\"\"\"", "\\\"\"\"")
+        ),
+        comments => {
+            writeln!(w, "{indent}\"\"\"")?;
+            for comment in comments {
+                writeln!(w, "{indent}{}", comment.replace("\"\"\"", "\\\"\"\""))?;
+            }
+            writeln!(w, "{indent}\"\"\"")
+        }
+    }
+}
+
+/// Replace the characters names can't have, like the `-` of kebab case names.
+fn graphql_name(name: &str) -> String {
+    let name = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{name}")
+    } else {
+        name
+    }
+}
+
+/// Escape a string literal.
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
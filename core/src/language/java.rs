@@ -0,0 +1,619 @@
+use super::{
+    used_imports, used_type_map_imports, CrateName, CrateTypes, Language, MappedImport,
+    ScopedCrateTypes, TypeMapping,
+};
+use crate::{
+    language::SupportedLanguage,
+    parser::ParsedData,
+    rename::{FieldNaming, RenameExt},
+    rust_types::{
+        RustEnum, RustEnumVariant, RustField, RustItem, RustStruct, RustType, RustTypeAlias,
+        RustTypeFormatError, SpecialRustType,
+    },
+    topsort::sort_items,
+};
+use itertools::Itertools;
+use std::{
+    collections::{BTreeSet, HashMap},
+    io::Write,
+    path::Path,
+};
+
+/// The name of the class the types are nested in when the file name isn't a valid
+/// class name, like when writing to standard output.
+const DEFAULT_CLASS_NAME: &str = "Types";
+
+/// Words that can't be used as identifiers.
+const JAVA_KEYWORDS: &[&str] = &[
+    "_",
+    "abstract",
+    "assert",
+    "boolean",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "class",
+    "const",
+    "continue",
+    "default",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "extends",
+    "false",
+    "final",
+    "finally",
+    "float",
+    "for",
+    "goto",
+    "if",
+    "implements",
+    "import",
+    "instanceof",
+    "int",
+    "interface",
+    "long",
+    "native",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "short",
+    "static",
+    "strictfp",
+    "super",
+    "switch",
+    "synchronized",
+    "this",
+    "throw",
+    "throws",
+    "transient",
+    "true",
+    "try",
+    "void",
+    "volatile",
+    "while",
+];
+
+/// All information needed to generate Java records with Jackson annotations.
+///
+/// Java allows a single public top level class per file, named after the file, so
+/// the generated types are nested in a class named after the output file.
+#[derive(Default)]
+pub struct Java {
+    /// Name of the Java package. When writing one file per crate, each crate gets
+    /// a subpackage named after it.
+    pub package: String,
+    /// Conversions from Rust type names to Java type names.
+    pub type_mappings: HashMap<String, TypeMapping>,
+    /// Imports needed by the mapped types, from Rust type names to the fully
+    /// qualified name of the type they map to.
+    pub type_mapping_imports: HashMap<String, String>,
+    /// Whether or not to exclude the version header that normally appears at the top of generated code.
+    /// If you aren't generating a snapshot test, this setting can just be left as a default (false)
+    pub no_version_header: bool,
+    /// The paths of the other crates' files, relative to the file being generated.
+    pub import_paths: HashMap<CrateName, String>,
+    /// The class the types of the file being generated are nested in.
+    pub class_name: String,
+}
+
+impl Language for Java {
+    fn generate_types(
+        &mut self,
+        w: &mut dyn Write,
+        all_types: &CrateTypes,
+        data: ParsedData,
+    ) -> std::io::Result<()> {
+        self.begin_file(w, &data)?;
+
+        let mapped_imports = used_type_map_imports(self, &data);
+        if !mapped_imports.is_empty() {
+            self.write_type_map_imports(w, &mapped_imports)?;
+        }
+
+        if data.multi_file {
+            self.write_imports(w, used_imports(&data, all_types))?;
+        }
+
+        self.class_name = class_name(&data.file_name);
+        writeln!(w)?;
+        writeln!(w, "public final class {} {{", self.class_name)?;
+        writeln!(w, "\tprivate {}() {{}}", self.class_name)?;
+
+        let ParsedData {
+            structs,
+            enums,
+            aliases,
+            source_positions,
+            type_ordering,
+            ..
+        } = data;
+
+        let mut items = Vec::from_iter(
+            aliases
+                .into_iter()
+                .map(RustItem::Alias)
+                .chain(structs.into_iter().map(RustItem::Struct))
+                .chain(enums.into_iter().map(RustItem::Enum)),
+        );
+
+        sort_items(&mut items, type_ordering, &source_positions, true);
+
+        for thing in &items {
+            match thing {
+                RustItem::Enum(e) => self.write_enum(w, e)?,
+                RustItem::Struct(s) => self.write_struct(w, s)?,
+                RustItem::Alias(a) => self.write_type_alias(w, a)?,
+            }
+        }
+
+        writeln!(w, "}}")
+    }
+
+    fn type_map(&mut self) -> &HashMap<String, TypeMapping> {
+        &self.type_mappings
+    }
+
+    fn type_map_imports(&mut self) -> Option<&HashMap<String, String>> {
+        Some(&self.type_mapping_imports)
+    }
+
+    fn format_simple_type(
+        &mut self,
+        base: &String,
+        _generic_types: &[String],
+    ) -> Result<String, RustTypeFormatError> {
+        Ok(if let Some(mapped) = self.type_map().get(base) {
+            mapped.apply(base, &[])?
+        } else {
+            base.into()
+        })
+    }
+
+    fn format_special_type(
+        &mut self,
+        special_ty: &SpecialRustType,
+        generic_types: &[String],
+    ) -> Result<String, RustTypeFormatError> {
+        // Generic parameters can only be classes, so these are the boxed types. Fields
+        // use the primitive types where they can, see `component_type`.
+        Ok(match special_ty {
+            SpecialRustType::Vec(rtype)
+            | SpecialRustType::Array(rtype, _)
+            | SpecialRustType::Slice(rtype) => {
+                format!("List<{}>", self.format_type(rtype, generic_types)?)
+            }
+            SpecialRustType::Option(rtype) => self.format_type(rtype, generic_types)?,
+            SpecialRustType::HashMap(rtype1, rtype2) => {
+                format!(
+                    "Map<{}, {}>",
+                    self.format_type(rtype1, generic_types)?,
+                    self.format_type(rtype2, generic_types)?
+                )
+            }
+            SpecialRustType::Unit => "Void".into(),
+            // Char in Java is 16 bits long, so we need to use String
+            SpecialRustType::String | SpecialRustType::Char => "String".into(),
+            // Java has no unsigned integers, so these use the next larger signed type
+            SpecialRustType::I8 => "Byte".into(),
+            SpecialRustType::I16 | SpecialRustType::U8 => "Short".into(),
+            SpecialRustType::I32 | SpecialRustType::U16 => "Integer".into(),
+            SpecialRustType::I54
+            | SpecialRustType::I64
+            | SpecialRustType::ISize
+            | SpecialRustType::U32
+            | SpecialRustType::U53
+            | SpecialRustType::U64
+            | SpecialRustType::USize => "Long".into(),
+            SpecialRustType::Bool => "Boolean".into(),
+            SpecialRustType::F32 => "Float".into(),
+            SpecialRustType::F64 => "Double".into(),
+        })
+    }
+
+    fn begin_file(&mut self, w: &mut dyn Write, parsed_data: &ParsedData) -> std::io::Result<()> {
+        if !self.no_version_header {
+            writeln!(w, "/**")?;
+            writeln!(w, " * Generated by typeshare {}", env!("CARGO_PKG_VERSION"))?;
+            writeln!(w, " */")?;
+            writeln!(w)?;
+        }
+        if parsed_data.multi_file {
+            writeln!(
+                w,
+                "package {};",
+                self.crate_package(&parsed_data.crate_name)
+            )?;
+            writeln!(w)?;
+        } else if !self.package.is_empty() {
+            writeln!(w, "package {};", self.package)?;
+            writeln!(w)?;
+        }
+        writeln!(w, "import com.fasterxml.jackson.annotation.*;")?;
+        writeln!(w, "import java.util.List;")?;
+        writeln!(w, "import java.util.Map;")
+    }
+
+    fn write_imports(
+        &mut self,
+        w: &mut dyn Write,
+        imports: ScopedCrateTypes<'_>,
+    ) -> std::io::Result<()> {
+        for (crate_name, types) in imports {
+            // The types of another crate are nested in the class named after its file.
+            let class_name = match self.import_paths.get(crate_name) {
+                Some(path) => class_name(path),
+                None => crate_name.to_string().to_pascal_case(),
+            };
+            let package = self.crate_package(crate_name);
+            for ty in types {
+                writeln!(w, "import {package}.{class_name}.{ty};")?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_type_map_imports(
+        &mut self,
+        w: &mut dyn Write,
+        imports: &BTreeSet<MappedImport>,
+    ) -> std::io::Result<()> {
+        for import in imports.iter().map(|import| &import.import).dedup() {
+            writeln!(w, "import {import};")?;
+        }
+        Ok(())
+    }
+
+    fn write_type_alias(&mut self, w: &mut dyn Write, ty: &RustTypeAlias) -> std::io::Result<()> {
+        writeln!(w)?;
+        self.write_comments(w, 1, &ty.comments)?;
+        let value_type = self
+            .component_type(&ty.r#type, &ty.generic_types)
+            .map_err(std::io::Error::other)?;
+
+        writeln!(
+            w,
+            "\tpublic record {}{}(@JsonValue {} value) {{",
+            ty.id.renamed,
+            generic_parameters(&ty.generic_types),
+            value_type
+        )?;
+        writeln!(w, "\t\t@JsonCreator(mode = JsonCreator.Mode.DELEGATING)")?;
+        writeln!(w, "\t\tpublic {} {{}}", ty.id.renamed)?;
+        if ty.is_redacted {
+            writeln!(w)?;
+            write_to_string(w, "***")?;
+        }
+        writeln!(w, "\t}}")
+    }
+
+    fn write_struct(&mut self, w: &mut dyn Write, rs: &RustStruct) -> std::io::Result<()> {
+        writeln!(w)?;
+        self.write_comments(w, 1, &rs.comments)?;
+        write!(
+            w,
+            "\tpublic record {}{}(",
+            rs.id.renamed,
+            generic_parameters(&rs.generic_types)
+        )?;
+
+        if !rs.fields.is_empty() {
+            writeln!(w)?;
+            for (i, field) in rs.fields.iter().enumerate() {
+                if i > 0 {
+                    writeln!(w, ",")?;
+                }
+                self.write_component(w, field, &rs.generic_types)?;
+            }
+            writeln!(w)?;
+            write!(w, "\t")?;
+        }
+
+        if rs.is_redacted {
+            writeln!(w, ") {{")?;
+            write_to_string(w, &rs.id.renamed)?;
+            writeln!(w, "\t}}")
+        } else {
+            writeln!(w, ") {{}}")
+        }
+    }
+
+    fn write_enum(&mut self, w: &mut dyn Write, e: &RustEnum) -> std::io::Result<()> {
+        // Generate named types for any anonymous struct variants of this enum
+        self.write_types_for_anonymous_structs(w, e, &|variant_name| {
+            anonymous_struct_name(e, variant_name)
+        })?;
+
+        writeln!(w)?;
+        self.write_comments(w, 1, &e.shared().comments)?;
+
+        match e {
+            RustEnum::Unit(shared) => {
+                writeln!(w, "\tpublic enum {} {{", shared.id.renamed)?;
+                for v in &shared.variants {
+                    self.write_comments(w, 2, &v.shared().comments)?;
+                    writeln!(w, "\t\t@JsonProperty({:?})", v.shared().id.renamed)?;
+                    writeln!(w, "\t\t{},", v.shared().id.original)?;
+                }
+            }
+            RustEnum::Algebraic {
+                tag_key,
+                content_key,
+                shared,
+            } => {
+                let name = &shared.id.renamed;
+                let generics = generic_parameters(&shared.generic_types);
+
+                writeln!(
+                    w,
+                    "\t@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = {tag_key:?})"
+                )?;
+                writeln!(w, "\t@JsonSubTypes({{")?;
+                for v in &shared.variants {
+                    writeln!(
+                        w,
+                        "\t\t@JsonSubTypes.Type(value = {name}.{}.class, name = {:?}),",
+                        variant_name(v),
+                        v.shared().id.renamed
+                    )?;
+                }
+                writeln!(w, "\t}})")?;
+                // A sealed interface needs at least one implementation.
+                let sealed = if shared.variants.is_empty() {
+                    ""
+                } else {
+                    "sealed "
+                };
+                writeln!(w, "\tpublic {sealed}interface {name}{generics} {{")?;
+
+                let content_name = component_name(content_key);
+                let variant_names = shared.variants.iter().map(variant_name).collect_vec();
+                for v in &shared.variants {
+                    self.write_comments(w, 2, &v.shared().comments)?;
+                    write!(w, "\t\trecord {}{generics}(", variant_name(v))?;
+                    match v {
+                        RustEnumVariant::Unit(_) => {}
+                        RustEnumVariant::Tuple { ty, .. } => {
+                            let content_type = self
+                                .format_type(ty, &shared.generic_types)
+                                .map_err(std::io::Error::other)?;
+                            let content_type = self.qualify(&content_type, &variant_names);
+                            write!(
+                                w,
+                                "@JsonProperty({content_key:?}) {content_type} {content_name}"
+                            )?;
+                        }
+                        RustEnumVariant::AnonymousStruct {
+                            shared: variant,
+                            fields,
+                        } => {
+                            // Only the generic parameters of the enum used by the fields
+                            // are parameters of the generated record.
+                            let variant_generics = shared
+                                .generic_types
+                                .iter()
+                                .filter(|g| fields.iter().any(|f| f.ty.contains_type(g)))
+                                .cloned()
+                                .collect_vec();
+                            write!(
+                                w,
+                                "@JsonProperty({content_key:?}) {}{} {content_name}",
+                                anonymous_struct_name(e, &variant.id.original),
+                                generic_parameters(&variant_generics)
+                            )?;
+                        }
+                    }
+                    writeln!(w, ") implements {name}{generics} {{}}")?;
+                }
+            }
+        }
+
+        writeln!(w, "\t}}")
+    }
+
+    fn ignored_reference_types(&self) -> Vec<&str> {
+        self.type_mappings.keys().map(|s| s.as_str()).collect()
+    }
+
+    fn set_import_paths(&mut self, import_paths: HashMap<CrateName, String>) {
+        self.import_paths = import_paths;
+    }
+}
+
+impl Java {
+    /// The package of the file of a crate when writing one file per crate.
+    fn crate_package(&self, crate_name: &CrateName) -> String {
+        if self.package.is_empty() {
+            crate_name.to_string()
+        } else {
+            format!("{}.{crate_name}", self.package)
+        }
+    }
+
+    /// Qualify the names in a type that are shadowed by the records of the variants
+    /// of the enum the type is used in, like the `String` in `record String(String content)`.
+    fn qualify(&self, ty: &str, variant_names: &[String]) -> String {
+        let mut qualified = String::with_capacity(ty.len());
+        let mut rest = ty;
+        while let Some(start) = rest.find(is_identifier_char) {
+            let end = rest[start..]
+                .find(|c| !is_identifier_char(c))
+                .map_or(rest.len(), |end| start + end);
+            let name = &rest[start..end];
+            qualified.push_str(&rest[..start]);
+            if !rest[..start].ends_with('.') && variant_names.iter().any(|v| v == name) {
+                match name {
+                    "List" | "Map" => qualified.push_str("java.util."),
+                    "String" | "Void" | "Byte" | "Short" | "Integer" | "Long" | "Boolean"
+                    | "Float" | "Double" => qualified.push_str("java.lang."),
+                    _ => {
+                        qualified.push_str(&self.class_name);
+                        qualified.push('.');
+                    }
+                }
+            }
+            qualified.push_str(name);
+            rest = &rest[end..];
+        }
+        qualified.push_str(rest);
+        qualified
+    }
+
+    /// The type of a record component. Values that can't be null use primitive
+    /// types, the rest use the boxed types.
+    fn component_type(
+        &mut self,
+        ty: &RustType,
+        generic_types: &[String],
+    ) -> Result<String, RustTypeFormatError> {
+        let primitive = match ty {
+            RustType::Special(special) => match special {
+                SpecialRustType::Bool => Some("boolean"),
+                SpecialRustType::I8 => Some("byte"),
+                SpecialRustType::I16 | SpecialRustType::U8 => Some("short"),
+                SpecialRustType::I32 | SpecialRustType::U16 => Some("int"),
+                SpecialRustType::I54
+                | SpecialRustType::I64
+                | SpecialRustType::ISize
+                | SpecialRustType::U32
+                | SpecialRustType::U53
+                | SpecialRustType::U64
+                | SpecialRustType::USize => Some("long"),
+                SpecialRustType::F32 => Some("float"),
+                SpecialRustType::F64 => Some("double"),
+                _ => None,
+            },
+            _ => None,
+        };
+        match primitive {
+            Some(primitive) => Ok(primitive.to_owned()),
+            None => self.format_type(ty, generic_types),
+        }
+    }
+
+    fn write_component(
+        &mut self,
+        w: &mut dyn Write,
+        f: &RustField,
+        generic_types: &[String],
+    ) -> std::io::Result<()> {
+        self.write_comments(w, 2, &f.comments)?;
+        let ty = match f.type_override(SupportedLanguage::Java) {
+            Some(type_override) => Ok(type_override.to_owned()),
+            // Missing fields with a default deserialize to null, so they can't be primitives.
+            None if f.has_default => self.format_type(&f.ty, generic_types),
+            None => self.component_type(&f.ty, generic_types),
+        }
+        .map_err(std::io::Error::other)?;
+
+        write!(
+            w,
+            "\t\t@JsonProperty({:?}) {} {}",
+            f.id.renamed,
+            ty,
+            component_name(&f.id.renamed)
+        )
+    }
+
+    fn write_comments(
+        &self,
+        w: &mut dyn Write,
+        indent: usize,
+        comments: &[String],
+    ) -> std::io::Result<()> {
+        if comments.is_empty() {
+            return Ok(());
+        }
+        let indent = "\t".repeat(indent);
+        writeln!(w, "{indent}/**")?;
+        for comment in comments {
+            // A `*/` in the comment would end the comment block early.
+            let comment = comment.replace("*/", "*\\/");
+            if comment.is_empty() {
+                writeln!(w, "{indent} *")?;
+            } else {
+                writeln!(w, "{indent} * {comment}")?;
+            }
+        }
+        writeln!(w, "{indent} */")
+    }
+}
+
+/// Override `toString` to hide the values of a redacted type.
+fn write_to_string(w: &mut dyn Write, value: &str) -> std::io::Result<()> {
+    writeln!(w, "\t\t@Override")?;
+    writeln!(w, "\t\tpublic String toString() {{")?;
+    writeln!(w, "\t\t\treturn {value:?};")?;
+    writeln!(w, "\t\t}}")
+}
+
+/// The name of the class the types of a file are nested in, which has to match the
+/// name of the file.
+fn class_name(file_name: &str) -> String {
+    Path::new(file_name)
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .filter(|stem| is_identifier(stem))
+        .unwrap_or(DEFAULT_CLASS_NAME)
+        .to_owned()
+}
+
+fn is_identifier(name: &str) -> bool {
+    name.chars().all(is_identifier_char)
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && !name.is_empty()
+        && !JAVA_KEYWORDS.contains(&name)
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$'
+}
+
+/// The name of a record component for the serialized key `key`.
+fn component_name(key: &str) -> String {
+    let name = FieldNaming::CamelCase
+        .apply(key)
+        .chars()
+        .filter(|c| is_identifier_char(*c))
+        .collect::<String>();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{name}")
+    } else if JAVA_KEYWORDS.contains(&name.as_str()) {
+        format!("{name}_")
+    } else {
+        name
+    }
+}
+
+/// The name of the record of an algebraic enum variant.
+fn variant_name(v: &RustEnumVariant) -> String {
+    let name = v.shared().id.original.to_pascal_case();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        // If the name starts with a digit just add an underscore
+        // to the front and make it valid
+        format!("_{name}")
+    } else {
+        name
+    }
+}
+
+/// The name of the record generated for an anonymous struct variant.
+fn anonymous_struct_name(e: &RustEnum, variant_name: &str) -> String {
+    format!("{}{}Inner", e.shared().id.renamed, variant_name)
+}
+
+/// Format the generic parameters of a type declaration, like `<T, U>`.
+fn generic_parameters(generic_types: &[String]) -> String {
+    if generic_types.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", generic_types.join(", "))
+    }
+}
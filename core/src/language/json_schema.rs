@@ -0,0 +1,737 @@
+use super::{used_imports, CrateName, CrateTypes, Language, ScopedCrateTypes, TypeMapping};
+use crate::{
+    language::SupportedLanguage,
+    parser::ParsedData,
+    rust_types::{
+        RustEnum, RustEnumVariant, RustField, RustItem, RustStruct, RustType, RustTypeAlias,
+        RustTypeFormatError, SpecialRustType,
+    },
+    topsort::sort_items,
+};
+use itertools::Itertools;
+use serde::{ser::SerializeMap, Serialize, Serializer};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    io::Write,
+};
+
+/// The dialect of the generated schemas.
+const SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// All information needed to generate JSON Schema documents.
+///
+/// Every type of a file is a definition under `$defs`. Generic types are
+/// monomorphised: each use of a generic type with concrete parameters, like
+/// `Page<User>`, gets its own definition, like `Page_User`.
+#[derive(Default)]
+pub struct JsonSchema {
+    /// Conversions from Rust type names to schemas. A mapping is either a JSON
+    /// schema, like `{"type": "string", "format": "date-time"}`, or the name of a
+    /// JSON type, like `string`.
+    pub type_mappings: HashMap<String, TypeMapping>,
+    /// Whether or not to exclude the version comment that normally appears in generated schemas.
+    /// If you aren't generating a snapshot test, this setting can just be left as a default (false)
+    pub no_version_header: bool,
+    /// The paths of the other crates' files, relative to the file being generated.
+    pub import_paths: HashMap<CrateName, String>,
+}
+
+impl Language for JsonSchema {
+    fn generate_types(
+        &mut self,
+        writable: &mut dyn Write,
+        all_types: &CrateTypes,
+        data: ParsedData,
+    ) -> std::io::Result<()> {
+        let defs = definitions(
+            data,
+            all_types,
+            &self.import_paths,
+            &self.type_mappings,
+            Dialect::JsonSchema,
+            "json",
+        )?;
+
+        let mut document = vec![("$schema".to_owned(), Json::from(SCHEMA_DIALECT))];
+        if !self.no_version_header {
+            document.push((
+                "$comment".to_owned(),
+                Json::from(format!(
+                    "Generated by typeshare {}",
+                    env!("CARGO_PKG_VERSION")
+                )),
+            ));
+        }
+        document.push(("$defs".to_owned(), Json::Object(defs)));
+
+        serde_json::to_writer_pretty(&mut *writable, &Json::Object(document))?;
+        writeln!(writable)
+    }
+
+    fn type_map(&mut self) -> &HashMap<String, TypeMapping> {
+        &self.type_mappings
+    }
+
+    /// Types of other crates are referenced by the path of their file instead.
+    fn write_imports(
+        &mut self,
+        _writer: &mut dyn Write,
+        _imports: ScopedCrateTypes<'_>,
+    ) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    /// Formats a type as its schema, in compact JSON.
+    fn format_special_type(
+        &mut self,
+        special_ty: &SpecialRustType,
+        _generic_types: &[String],
+    ) -> Result<String, RustTypeFormatError> {
+        let mut schemas = Schemas::new(&self.type_mappings, Dialect::JsonSchema);
+        let schema = schemas.special_schema(special_ty, &[], &HashMap::new())?;
+        Ok(serde_json::to_string(&schema).expect("schemas are valid JSON"))
+    }
+
+    fn ignored_reference_types(&self) -> Vec<&str> {
+        self.type_mappings.keys().map(|s| s.as_str()).collect()
+    }
+
+    fn set_import_paths(&mut self, import_paths: HashMap<CrateName, String>) {
+        self.import_paths = import_paths;
+    }
+}
+
+/// Where the schemas of a file are defined, and how they describe algebraic enums.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(super) enum Dialect {
+    /// Definitions under `$defs` of a JSON Schema document.
+    JsonSchema,
+    /// Definitions under `components/schemas` of an OpenAPI document. The variants
+    /// of algebraic enums are definitions of their own, so that the `discriminator`
+    /// of the enum can map to them.
+    OpenApi,
+}
+
+impl Dialect {
+    /// The JSON pointer of the definitions of a document.
+    fn definitions_pointer(self) -> &'static str {
+        match self {
+            Dialect::JsonSchema => "#/$defs/",
+            Dialect::OpenApi => "#/components/schemas/",
+        }
+    }
+}
+
+/// The named schemas of the types of a file, in the order of the file's types
+/// followed by the monomorphised generic types they use. Types of other crates
+/// are referenced through their file, with the given extension.
+pub(super) fn definitions(
+    data: ParsedData,
+    all_types: &CrateTypes,
+    import_paths: &HashMap<CrateName, String>,
+    type_mappings: &HashMap<String, TypeMapping>,
+    dialect: Dialect,
+    extension: &str,
+) -> std::io::Result<Vec<(String, Json)>> {
+    // The files the types of other crates are defined in.
+    let mut imported = HashMap::new();
+    if data.multi_file {
+        for (crate_name, type_names) in used_imports(&data, all_types) {
+            let path = import_paths
+                .get(crate_name)
+                .cloned()
+                .unwrap_or_else(|| format!("./{crate_name}"));
+            for type_name in type_names {
+                imported.insert(type_name.to_owned(), format!("{path}.{extension}"));
+            }
+        }
+    }
+
+    let ParsedData {
+        structs,
+        enums,
+        aliases,
+        source_positions,
+        type_ordering,
+        ..
+    } = data;
+    let mut items = Vec::from_iter(
+        aliases
+            .into_iter()
+            .map(RustItem::Alias)
+            .chain(structs.into_iter().map(RustItem::Struct))
+            .chain(enums.into_iter().map(RustItem::Enum)),
+    );
+    sort_items(&mut items, type_ordering, &source_positions, true);
+
+    let mut schemas = Schemas::new(type_mappings, dialect);
+    schemas.items = items.iter().map(|item| (item_id(item), item)).collect();
+    schemas.imported = imported;
+    let mut defs = Vec::new();
+    for item in &items {
+        let name = item_id(item);
+        let schema = schemas
+            .item_schema(item, name, &HashMap::new())
+            .map_err(std::io::Error::other)?;
+        defs.push((name.to_owned(), schema));
+        defs.append(&mut schemas.variants);
+    }
+    while let Some((name, id, parameters)) = schemas.queue.pop_front() {
+        let item = schemas.items[id.as_str()];
+        let bindings = generic_types(item)
+            .iter()
+            .cloned()
+            .zip(parameters)
+            .collect();
+        let schema = schemas
+            .item_schema(item, &name, &bindings)
+            .map_err(std::io::Error::other)?;
+        defs.push((name, schema));
+        defs.append(&mut schemas.variants);
+    }
+    Ok(defs)
+}
+
+/// The concrete types generic parameters are replaced with.
+type Bindings = HashMap<String, RustType>;
+
+/// Builds the schemas of the types of a file.
+struct Schemas<'a> {
+    type_mappings: &'a HashMap<String, TypeMapping>,
+    dialect: Dialect,
+    /// The types defined in the file, by the name types refer to them by.
+    items: HashMap<&'a str, &'a RustItem>,
+    /// The files of the types defined in other crates.
+    imported: HashMap<String, String>,
+    /// The names of the monomorphised definitions already queued.
+    queued: HashSet<String>,
+    /// The monomorphised definitions left to generate: their name, the generic
+    /// type and its concrete parameters.
+    queue: VecDeque<(String, String, Vec<RustType>)>,
+    /// The definitions of the variants of the last algebraic enum, for OpenAPI.
+    variants: Vec<(String, Json)>,
+}
+
+impl<'a> Schemas<'a> {
+    fn new(type_mappings: &'a HashMap<String, TypeMapping>, dialect: Dialect) -> Self {
+        Self {
+            type_mappings,
+            dialect,
+            items: HashMap::new(),
+            imported: HashMap::new(),
+            queued: HashSet::new(),
+            queue: VecDeque::new(),
+            variants: Vec::new(),
+        }
+    }
+
+    /// The schema of a type, defined with the given name.
+    fn item_schema(
+        &mut self,
+        item: &RustItem,
+        name: &str,
+        bindings: &Bindings,
+    ) -> Result<Json, RustTypeFormatError> {
+        match item {
+            RustItem::Struct(rs) => self.struct_schema(rs, bindings),
+            RustItem::Enum(e) => self.enum_schema(e, name, bindings),
+            RustItem::Alias(ty) => self.alias_schema(ty, bindings),
+        }
+    }
+
+    fn struct_schema(
+        &mut self,
+        rs: &RustStruct,
+        bindings: &Bindings,
+    ) -> Result<Json, RustTypeFormatError> {
+        let schema = self.object_schema(&rs.fields, &rs.generic_types, bindings)?;
+        Ok(with_description(schema, &rs.comments))
+    }
+
+    /// The schema of an object with the given fields. Fields are required unless
+    /// they are optional or have a default.
+    fn object_schema(
+        &mut self,
+        fields: &[RustField],
+        generic_types: &[String],
+        bindings: &Bindings,
+    ) -> Result<Json, RustTypeFormatError> {
+        let mut properties = Vec::new();
+        let mut required = Vec::new();
+        for field in fields {
+            let schema = match field.type_override(SupportedLanguage::JsonSchema) {
+                Some(type_override) => mapped_schema(type_override),
+                None => self.type_schema(&field.ty, generic_types, bindings)?,
+            };
+            properties.push((
+                field.id.renamed.clone(),
+                with_description(schema, &field.comments),
+            ));
+            if !field.ty.is_optional() && !field.has_default {
+                required.push(Json::from(field.id.renamed.as_str()));
+            }
+        }
+
+        let mut schema = vec![
+            ("type".to_owned(), Json::from("object")),
+            ("properties".to_owned(), Json::Object(properties)),
+        ];
+        if !required.is_empty() {
+            schema.push(("required".to_owned(), Json::Array(required)));
+        }
+        Ok(Json::Object(schema))
+    }
+
+    fn enum_schema(
+        &mut self,
+        e: &RustEnum,
+        name: &str,
+        bindings: &Bindings,
+    ) -> Result<Json, RustTypeFormatError> {
+        let schema = match e {
+            RustEnum::Unit(shared) => Json::Object(vec![
+                ("type".to_owned(), Json::from("string")),
+                (
+                    "enum".to_owned(),
+                    Json::Array(
+                        shared
+                            .variants
+                            .iter()
+                            .map(|v| Json::from(v.shared().id.renamed.as_str()))
+                            .collect(),
+                    ),
+                ),
+            ]),
+            RustEnum::Algebraic {
+                tag_key,
+                content_key,
+                shared,
+            } => {
+                let mut variants = Vec::new();
+                let mut mapping = Vec::new();
+                for v in &shared.variants {
+                    let mut properties = vec![(
+                        tag_key.clone(),
+                        Json::Object(vec![(
+                            "const".to_owned(),
+                            Json::from(v.shared().id.renamed.as_str()),
+                        )]),
+                    )];
+                    let mut required = vec![Json::from(tag_key.as_str())];
+                    let content = match v {
+                        RustEnumVariant::Unit(_) => None,
+                        RustEnumVariant::Tuple { ty, .. } => {
+                            Some(self.type_schema(ty, &shared.generic_types, bindings)?)
+                        }
+                        RustEnumVariant::AnonymousStruct { fields, .. } => {
+                            Some(self.object_schema(fields, &shared.generic_types, bindings)?)
+                        }
+                    };
+                    if let Some(content) = content {
+                        properties.push((content_key.clone(), content));
+                        required.push(Json::from(content_key.as_str()));
+                    }
+                    let variant = with_description(
+                        Json::Object(vec![
+                            ("type".to_owned(), Json::from("object")),
+                            ("properties".to_owned(), Json::Object(properties)),
+                            ("required".to_owned(), Json::Array(required)),
+                        ]),
+                        &v.shared().comments,
+                    );
+                    match self.dialect {
+                        Dialect::JsonSchema => variants.push(variant),
+                        Dialect::OpenApi => {
+                            // Variants of monomorphised enums are separated from
+                            // the names of the enum's parameters, like `Page_User_Next`.
+                            let separator = if name == e.shared().id.renamed {
+                                ""
+                            } else {
+                                "_"
+                            };
+                            let variant_name =
+                                format!("{name}{separator}{}", v.shared().id.original);
+                            let reference = self.reference(&variant_name);
+                            mapping.push((
+                                v.shared().id.renamed.clone(),
+                                Json::from(format!(
+                                    "{}{variant_name}",
+                                    self.dialect.definitions_pointer()
+                                )),
+                            ));
+                            variants.push(reference);
+                            self.variants.push((variant_name, variant));
+                        }
+                    }
+                }
+                let mut schema = vec![("oneOf".to_owned(), Json::Array(variants))];
+                if self.dialect == Dialect::OpenApi {
+                    schema.push((
+                        "discriminator".to_owned(),
+                        Json::Object(vec![
+                            ("propertyName".to_owned(), Json::from(tag_key.as_str())),
+                            ("mapping".to_owned(), Json::Object(mapping)),
+                        ]),
+                    ));
+                }
+                Json::Object(schema)
+            }
+        };
+        Ok(with_description(schema, &e.shared().comments))
+    }
+
+    fn alias_schema(
+        &mut self,
+        ty: &RustTypeAlias,
+        bindings: &Bindings,
+    ) -> Result<Json, RustTypeFormatError> {
+        let schema = self.type_schema(&ty.r#type, &ty.generic_types, bindings)?;
+        Ok(with_description(schema, &ty.comments))
+    }
+
+    /// The schema of a type used by a definition with the given generic parameters.
+    /// Parameters without a binding accept any value.
+    fn type_schema(
+        &mut self,
+        ty: &RustType,
+        generic_types: &[String],
+        bindings: &Bindings,
+    ) -> Result<Json, RustTypeFormatError> {
+        match ty {
+            RustType::Simple { id } if bindings.contains_key(id) => {
+                self.type_schema(&bindings[id], &[], &Bindings::new())
+            }
+            RustType::Simple { id } if generic_types.contains(id) => Ok(Json::Object(Vec::new())),
+            RustType::Simple { id } | RustType::Generic { id, .. }
+                if self.type_mappings.contains_key(id) =>
+            {
+                Ok(mapped_schema(self.type_mappings[id].as_str()))
+            }
+            RustType::Simple { id } => Ok(self.reference(id)),
+            RustType::Generic { id, parameters } => {
+                let parameters = parameters
+                    .iter()
+                    .map(|p| substitute(p, bindings))
+                    .collect_vec();
+                let unbound = parameters
+                    .iter()
+                    .any(|p| generic_types.iter().any(|g| p.contains_type(g)));
+                if unbound || !self.items.contains_key(id.as_str()) {
+                    // Types with generic parameters of their own, and generic types
+                    // of other crates, can only refer to the generic definition.
+                    return Ok(self.reference(id));
+                }
+                let name = monomorphised_name(ty.id(), &parameters);
+                if self.queued.insert(name.clone()) {
+                    self.queue.push_back((name.clone(), id.clone(), parameters));
+                }
+                Ok(self.reference(&name))
+            }
+            RustType::Special(special) => self.special_schema(special, generic_types, bindings),
+        }
+    }
+
+    fn special_schema(
+        &mut self,
+        special_ty: &SpecialRustType,
+        generic_types: &[String],
+        bindings: &Bindings,
+    ) -> Result<Json, RustTypeFormatError> {
+        let integer = |minimum: Option<i64>, maximum: Option<i64>| {
+            let mut schema = vec![("type".to_owned(), Json::from("integer"))];
+            if let Some(minimum) = minimum {
+                schema.push(("minimum".to_owned(), Json::from(minimum)));
+            }
+            if let Some(maximum) = maximum {
+                schema.push(("maximum".to_owned(), Json::from(maximum)));
+            }
+            Json::Object(schema)
+        };
+        const MAX_SAFE_INTEGER: i64 = (1 << 53) - 1;
+
+        Ok(match special_ty {
+            SpecialRustType::Vec(rtype) | SpecialRustType::Slice(rtype) => Json::Object(vec![
+                ("type".to_owned(), Json::from("array")),
+                (
+                    "items".to_owned(),
+                    self.type_schema(rtype, generic_types, bindings)?,
+                ),
+            ]),
+            SpecialRustType::Array(rtype, len) => Json::Object(vec![
+                ("type".to_owned(), Json::from("array")),
+                (
+                    "items".to_owned(),
+                    self.type_schema(rtype, generic_types, bindings)?,
+                ),
+                ("minItems".to_owned(), Json::from(*len as i64)),
+                ("maxItems".to_owned(), Json::from(*len as i64)),
+            ]),
+            SpecialRustType::Option(rtype) => {
+                nullable(self.type_schema(rtype, generic_types, bindings)?)
+            }
+            SpecialRustType::HashMap(key, value) => {
+                let mut schema = vec![("type".to_owned(), Json::from("object"))];
+                let key_schema = match key.as_ref() {
+                    RustType::Special(SpecialRustType::String) => None,
+                    RustType::Special(SpecialRustType::Char) => {
+                        Some(self.type_schema(key, generic_types, bindings)?)
+                    }
+                    RustType::Special(
+                        SpecialRustType::U8
+                        | SpecialRustType::U16
+                        | SpecialRustType::U32
+                        | SpecialRustType::U53
+                        | SpecialRustType::U64
+                        | SpecialRustType::USize,
+                    ) => Some(Json::Object(vec![(
+                        "pattern".to_owned(),
+                        Json::from("^[0-9]+$"),
+                    )])),
+                    RustType::Special(
+                        SpecialRustType::I8
+                        | SpecialRustType::I16
+                        | SpecialRustType::I32
+                        | SpecialRustType::I54
+                        | SpecialRustType::I64
+                        | SpecialRustType::ISize,
+                    ) => Some(Json::Object(vec![(
+                        "pattern".to_owned(),
+                        Json::from("^-?[0-9]+$"),
+                    )])),
+                    RustType::Special(_) => None,
+                    _ => Some(self.type_schema(key, generic_types, bindings)?),
+                };
+                if let Some(key_schema) = key_schema {
+                    schema.push(("propertyNames".to_owned(), key_schema));
+                }
+                schema.push((
+                    "additionalProperties".to_owned(),
+                    self.type_schema(value, generic_types, bindings)?,
+                ));
+                Json::Object(schema)
+            }
+            SpecialRustType::Unit => json_type("null"),
+            SpecialRustType::String => json_type("string"),
+            SpecialRustType::Char => Json::Object(vec![
+                ("type".to_owned(), Json::from("string")),
+                ("minLength".to_owned(), Json::from(1)),
+                ("maxLength".to_owned(), Json::from(1)),
+            ]),
+            SpecialRustType::Bool => json_type("boolean"),
+            SpecialRustType::F32 | SpecialRustType::F64 => json_type("number"),
+            SpecialRustType::I8 => integer(Some(i8::MIN.into()), Some(i8::MAX.into())),
+            SpecialRustType::I16 => integer(Some(i16::MIN.into()), Some(i16::MAX.into())),
+            SpecialRustType::I32 => integer(Some(i32::MIN.into()), Some(i32::MAX.into())),
+            SpecialRustType::I54 => integer(Some(-MAX_SAFE_INTEGER), Some(MAX_SAFE_INTEGER)),
+            SpecialRustType::I64 | SpecialRustType::ISize => integer(None, None),
+            SpecialRustType::U8 => integer(Some(0), Some(u8::MAX.into())),
+            SpecialRustType::U16 => integer(Some(0), Some(u16::MAX.into())),
+            SpecialRustType::U32 => integer(Some(0), Some(u32::MAX.into())),
+            SpecialRustType::U53 => integer(Some(0), Some(MAX_SAFE_INTEGER)),
+            SpecialRustType::U64 | SpecialRustType::USize => integer(Some(0), None),
+        })
+    }
+
+    /// A reference to a definition of this file or of the file of another crate.
+    fn reference(&self, name: &str) -> Json {
+        let file = self
+            .imported
+            .get(name)
+            .filter(|_| !self.items.contains_key(name))
+            .map(String::as_str)
+            .unwrap_or_default();
+        Json::Object(vec![(
+            "$ref".to_owned(),
+            Json::from(format!(
+                "{file}{}{name}",
+                self.dialect.definitions_pointer()
+            )),
+        )])
+    }
+}
+
+/// The name of an item, as types refer to it.
+fn item_id(item: &RustItem) -> &str {
+    &item.id().renamed
+}
+
+fn generic_types(item: &RustItem) -> &[String] {
+    match item {
+        RustItem::Struct(rs) => &rs.generic_types,
+        RustItem::Enum(e) => &e.shared().generic_types,
+        RustItem::Alias(ty) => &ty.generic_types,
+    }
+}
+
+/// Replace the generic parameters of a type with their bindings.
+fn substitute(ty: &RustType, bindings: &Bindings) -> RustType {
+    let boxed = |ty: &RustType| Box::new(substitute(ty, bindings));
+    match ty {
+        RustType::Simple { id } => bindings.get(id).cloned().unwrap_or_else(|| ty.clone()),
+        RustType::Generic { id, parameters } => RustType::Generic {
+            id: id.clone(),
+            parameters: parameters.iter().map(|p| substitute(p, bindings)).collect(),
+        },
+        RustType::Special(special) => RustType::Special(match special {
+            SpecialRustType::Vec(rtype) => SpecialRustType::Vec(boxed(rtype)),
+            SpecialRustType::Array(rtype, len) => SpecialRustType::Array(boxed(rtype), *len),
+            SpecialRustType::Slice(rtype) => SpecialRustType::Slice(boxed(rtype)),
+            SpecialRustType::Option(rtype) => SpecialRustType::Option(boxed(rtype)),
+            SpecialRustType::HashMap(key, value) => {
+                SpecialRustType::HashMap(boxed(key), boxed(value))
+            }
+            _ => special.clone(),
+        }),
+    }
+}
+
+/// The name of the definition of a generic type with concrete parameters, like
+/// `Page_Vec_String` for `Page<Vec<String>>`.
+fn monomorphised_name(id: &str, parameters: &[RustType]) -> String {
+    fn type_name(ty: &RustType) -> String {
+        match ty {
+            RustType::Simple { id } => id.clone(),
+            RustType::Generic { id, parameters } => monomorphised_name(id, parameters),
+            RustType::Special(SpecialRustType::Unit) => "Unit".to_owned(),
+            RustType::Special(SpecialRustType::Array(rtype, len)) => {
+                format!("Array_{}_{len}", type_name(rtype))
+            }
+            RustType::Special(SpecialRustType::Slice(rtype)) => {
+                format!("Vec_{}", type_name(rtype))
+            }
+            RustType::Special(special) => std::iter::once(special.id().to_owned())
+                .chain(special.parameters().map(type_name))
+                .join("_"),
+        }
+    }
+    std::iter::once(id.to_owned())
+        .chain(parameters.iter().map(type_name))
+        .join("_")
+}
+
+/// The schema of a mapped type: either a JSON schema or the name of a JSON type.
+fn mapped_schema(mapping: &str) -> Json {
+    match serde_json::from_str::<serde_json::Value>(mapping) {
+        Ok(schema @ serde_json::Value::Object(_)) => Json::Raw(schema),
+        _ => json_type(mapping),
+    }
+}
+
+fn json_type(name: &str) -> Json {
+    Json::Object(vec![("type".to_owned(), Json::from(name))])
+}
+
+/// Allow `null` in addition to the values of a schema.
+fn nullable(schema: Json) -> Json {
+    match schema {
+        Json::Object(entries) if entries.is_empty() => Json::Object(entries),
+        Json::Object(mut entries) => {
+            if let Some((_, Json::String(ty))) = entries.iter().find(|(key, _)| key == "type") {
+                let ty = ty.clone();
+                if ty == "null" {
+                    return Json::Object(entries);
+                }
+                for (key, value) in &mut entries {
+                    if key == "type" {
+                        *value = Json::Array(vec![Json::String(ty.clone()), Json::from("null")]);
+                    }
+                }
+                return Json::Object(entries);
+            }
+            if entries.iter().any(|(key, _)| key == "anyOf") {
+                let null = json_type("null");
+                for (key, value) in &mut entries {
+                    if let (true, Json::Array(schemas)) = (key == "anyOf", value) {
+                        if !schemas.contains(&null) {
+                            schemas.push(null.clone());
+                        }
+                    }
+                }
+                return Json::Object(entries);
+            }
+            Json::Object(vec![(
+                "anyOf".to_owned(),
+                Json::Array(vec![Json::Object(entries), json_type("null")]),
+            )])
+        }
+        Json::Raw(serde_json::Value::Object(mut entries))
+            if entries.get("type").is_some_and(|ty| ty.is_string()) =>
+        {
+            let ty = entries["type"].clone();
+            if ty != "null" {
+                entries.insert("type".to_owned(), serde_json::json!([ty, "null"]));
+            }
+            Json::Raw(serde_json::Value::Object(entries))
+        }
+        schema => Json::Object(vec![(
+            "anyOf".to_owned(),
+            Json::Array(vec![schema, json_type("null")]),
+        )]),
+    }
+}
+
+/// Add a description made of the comments of a type, variant or field.
+fn with_description(schema: Json, comments: &[String]) -> Json {
+    if comments.is_empty() {
+        return schema;
+    }
+    let description = ("description".to_owned(), Json::from(comments.join("\n")));
+    match schema {
+        Json::Object(entries) => {
+            Json::Object(std::iter::once(description).chain(entries).collect())
+        }
+        Json::Raw(serde_json::Value::Object(mut entries)) => {
+            entries.insert(description.0, comments.join("\n").into());
+            Json::Raw(serde_json::Value::Object(entries))
+        }
+        schema => schema,
+    }
+}
+
+/// A JSON value whose objects keep the order of their entries, so that the
+/// schemas follow the order of the Rust definitions.
+#[derive(Clone, PartialEq)]
+pub(super) enum Json {
+    String(String),
+    Number(i64),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+    /// A value given by the configuration, like a mapped type.
+    Raw(serde_json::Value),
+}
+
+impl From<&str> for Json {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_owned())
+    }
+}
+
+impl From<String> for Json {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<i64> for Json {
+    fn from(value: i64) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl Serialize for Json {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Json::String(value) => serializer.serialize_str(value),
+            Json::Number(value) => serializer.serialize_i64(*value),
+            Json::Array(values) => values.serialize(serializer),
+            Json::Object(entries) => {
+                let mut map = serializer.serialize_map(Some(entries.len()))?;
+                for (key, value) in entries {
+                    map.serialize_entry(key, value)?;
+                }
+                map.end()
+            }
+            Json::Raw(value) => value.serialize(serializer),
+        }
+    }
+}
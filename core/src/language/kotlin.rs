@@ -1,9 +1,9 @@
-use super::{Language, ScopedCrateTypes};
+use super::{Language, MappedImport, ScopedCrateTypes, TypeMapping};
 use crate::language::SupportedLanguage;
 use crate::parser::{remove_dash_from_identifier, DecoratorKind, ParsedData};
 use crate::rust_types::{RustTypeFormatError, SpecialRustType};
 use crate::{
-    rename::RenameExt,
+    rename::{FieldNaming, RenameExt},
     rust_types::{Id, RustEnum, RustEnumVariant, RustField, RustStruct, RustTypeAlias},
 };
 use itertools::Itertools;
@@ -23,24 +23,32 @@ pub struct Kotlin {
     /// The prefix to append to user-defined types
     pub prefix: String,
     /// Conversions from Rust type names to Kotlin type names.
-    pub type_mappings: HashMap<String, String>,
+    pub type_mappings: HashMap<String, TypeMapping>,
+    /// Imports needed by the mapped types, from Rust type names to the import of the type they map to.
+    pub type_mapping_imports: HashMap<String, String>,
     /// Whether or not to exclude the version header that normally appears at the top of generated code.
     /// If you aren't generating a snapshot test, this setting can just be left as a default (false)
     pub no_version_header: bool,
+    /// How to name data class properties. Keys that differ from the property name are kept with `@SerialName`.
+    pub field_naming: FieldNaming,
 }
 
 impl Language for Kotlin {
-    fn type_map(&mut self) -> &HashMap<String, String> {
+    fn type_map(&mut self) -> &HashMap<String, TypeMapping> {
         &self.type_mappings
     }
 
+    fn type_map_imports(&mut self) -> Option<&HashMap<String, String>> {
+        Some(&self.type_mapping_imports)
+    }
+
     fn format_simple_type(
         &mut self,
         base: &String,
         generic_types: &[String],
     ) -> Result<String, RustTypeFormatError> {
         Ok(if let Some(mapped) = self.type_map().get(base) {
-            mapped.into()
+            mapped.apply(base, &[])?
         } else if generic_types.contains(base) {
             base.into()
         } else {
@@ -136,6 +144,7 @@ impl Language for Kotlin {
                     comments: vec![],
                     has_default: false,
                     decorators: HashMap::new(),
+                    proto_tag: None,
                 },
                 &[],
                 false,
@@ -163,11 +172,13 @@ impl Language for Kotlin {
                 w,
                 "typealias {}{} = {}\n",
                 type_name,
-                (!ty.generic_types.is_empty())
-                    .then(|| format!("<{}>", ty.generic_types.join(", ")))
-                    .unwrap_or_default(),
+                if !ty.generic_types.is_empty() {
+                    format!("<{}>", ty.generic_types.join(", "))
+                } else {
+                    String::new()
+                },
                 self.format_type(&ty.r#type, ty.generic_types.as_slice())
-                    .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?
+                    .map_err(std::io::Error::other)?
             )?;
         }
 
@@ -187,9 +198,11 @@ impl Language for Kotlin {
                 "data class {}{}{} (",
                 self.prefix,
                 rs.id.renamed,
-                (!rs.generic_types.is_empty())
-                    .then(|| format!("<{}>", rs.generic_types.join(", ")))
-                    .unwrap_or_default()
+                if !rs.generic_types.is_empty() {
+                    format!("<{}>", rs.generic_types.join(", "))
+                } else {
+                    String::new()
+                }
             )?;
 
             // Use @SerialName when writing the struct
@@ -244,9 +257,11 @@ impl Language for Kotlin {
         self.write_comments(w, 0, &e.shared().comments)?;
         writeln!(w, "@Serializable")?;
 
-        let generic_parameters = (!e.shared().generic_types.is_empty())
-            .then(|| format!("<{}>", e.shared().generic_types.join(", ")))
-            .unwrap_or_default();
+        let generic_parameters = if !e.shared().generic_types.is_empty() {
+            format!("<{}>", e.shared().generic_types.join(", "))
+        } else {
+            String::new()
+        };
 
         match e {
             RustEnum::Unit(..) => {
@@ -289,6 +304,17 @@ impl Language for Kotlin {
         writeln!(w)
     }
 
+    fn write_type_map_imports(
+        &mut self,
+        w: &mut dyn Write,
+        imports: &BTreeSet<MappedImport>,
+    ) -> std::io::Result<()> {
+        for import in imports.iter().map(|import| &import.import).dedup() {
+            writeln!(w, "import {import}")?;
+        }
+        writeln!(w)
+    }
+
     fn ignored_reference_types(&self) -> Vec<&str> {
         self.type_mappings.keys().map(|s| s.as_str()).collect()
     }
@@ -351,13 +377,15 @@ impl Kotlin {
                                 w,
                                 "\tdata class {}{}(",
                                 variant_name,
-                                (!e.shared().generic_types.is_empty())
-                                    .then(|| format!("<{}>", e.shared().generic_types.join(", ")))
-                                    .unwrap_or_default()
+                                if !e.shared().generic_types.is_empty() {
+                                    format!("<{}>", e.shared().generic_types.join(", "))
+                                } else {
+                                    String::new()
+                                }
                             )?;
                             let variant_type = self
                                 .format_type(ty, e.shared().generic_types.as_slice())
-                                .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+                                .map_err(std::io::Error::other)?;
                             write!(w, "val {}: {}", content_key, variant_type)?;
                             write!(w, ")")?;
                         }
@@ -366,9 +394,11 @@ impl Kotlin {
                                 w,
                                 "\tdata class {}{}(",
                                 variant_name,
-                                (!e.shared().generic_types.is_empty())
-                                    .then(|| format!("<{}>", e.shared().generic_types.join(", ")))
-                                    .unwrap_or_default()
+                                if !e.shared().generic_types.is_empty() {
+                                    format!("<{}>", e.shared().generic_types.join(", "))
+                                } else {
+                                    String::new()
+                                }
                             )?;
 
                             // Builds the list of generic types (e.g [T, U, V]), by digging
@@ -409,9 +439,11 @@ impl Kotlin {
                         ": {}{}{}()",
                         self.prefix,
                         e.shared().id.original,
-                        (!e.shared().generic_types.is_empty())
-                            .then(|| format!("<{}>", e.shared().generic_types.join(", ")))
-                            .unwrap_or_default()
+                        if !e.shared().generic_types.is_empty() {
+                            format!("<{}>", e.shared().generic_types.join(", "))
+                        } else {
+                            String::new()
+                        }
                     )?;
                 }
             }
@@ -429,21 +461,22 @@ impl Kotlin {
         visibility: Visibility,
     ) -> std::io::Result<()> {
         self.write_comments(w, 1, &f.comments)?;
-        if requires_serial_name {
+        let property = remove_dash_from_identifier(&self.field_naming.apply(&f.id.renamed));
+        if requires_serial_name || property != f.id.renamed {
             writeln!(w, "\t@SerialName({:?})", &f.id.renamed)?;
         }
         let ty = match f.type_override(SupportedLanguage::Kotlin) {
             Some(type_override) => type_override.to_owned(),
             None => self
                 .format_type(&f.ty, generic_types)
-                .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?,
+                .map_err(std::io::Error::other)?,
         };
 
         match visibility {
             Visibility::Public => write!(
                 w,
                 "\tval {}: {}{}",
-                remove_dash_from_identifier(&f.id.renamed),
+                property,
                 ty,
                 (f.has_default && !f.ty.is_optional())
                     .then_some("? = null")
@@ -453,7 +486,7 @@ impl Kotlin {
             Visibility::Private => write!(
                 w,
                 "\tprivate val {}: {}{}",
-                remove_dash_from_identifier(&f.id.renamed),
+                property,
                 ty,
                 (f.has_default && !f.ty.is_optional())
                     .then_some("? = null")
@@ -4,13 +4,14 @@ use crate::{
         Id, RustEnum, RustEnumVariant, RustItem, RustStruct, RustType, RustTypeAlias,
         RustTypeFormatError, SpecialRustType,
     },
-    topsort::topsort,
+    topsort::sort_items,
     visitors::ImportedType,
     GenerationError,
 };
 use itertools::Itertools;
 use log::warn;
 use proc_macro2::Ident;
+use serde::{Deserialize, Serialize};
 use std::{
     collections::{BTreeMap, BTreeSet, HashMap, HashSet},
     fmt::Display,
@@ -19,22 +20,38 @@ use std::{
     str::FromStr,
 };
 
+mod csharp;
+mod dart;
 mod go;
+mod graphql;
+mod java;
+mod json_schema;
 mod kotlin;
+mod openapi;
+mod protobuf;
 mod python;
 mod scala;
 mod swift;
+mod type_mapping;
 mod typescript;
 
+pub use csharp::CSharp;
+pub use dart::{Dart, JsonConverter};
 pub use go::Go;
+pub use graphql::{GraphQl, GraphQlKind};
+pub use java::Java;
+pub use json_schema::JsonSchema;
 pub use kotlin::Kotlin;
+pub use openapi::{OpenApi, OpenApiFormat};
+pub use protobuf::{FieldNumbers, Protobuf};
 pub use python::Python;
 pub use scala::Scala;
 pub use swift::GenericConstraints;
 pub use swift::Swift;
-pub use typescript::TypeScript;
+pub use type_mapping::{MappedType, TypeMapping};
+pub use typescript::{TypeScript, UnitEnumStyle, ZodSchemas};
 
-#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize)]
 /// A crate name.
 pub struct CrateName(String);
 
@@ -95,32 +112,50 @@ pub type ScopedCrateTypes<'a> = BTreeMap<SortedCrateNames<'a>, SortedTypeNames<'
 
 /// All supported programming languages.
 #[allow(missing_docs)]
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum SupportedLanguage {
     Go,
+    Java,
     Kotlin,
     Scala,
     Swift,
     TypeScript,
     Python,
+    CSharp,
+    Dart,
+    JsonSchema,
+    OpenApi,
+    Protobuf,
+    GraphQl,
 }
 
 impl SupportedLanguage {
     /// Returns an iterator over all supported language variants.
     pub fn all_languages() -> impl Iterator<Item = Self> {
         use SupportedLanguage::*;
-        [Go, Kotlin, Scala, Swift, TypeScript, Python].into_iter()
+        [
+            Go, Java, Kotlin, Scala, Swift, TypeScript, Python, CSharp, Dart, JsonSchema, OpenApi,
+            Protobuf, GraphQl,
+        ]
+        .into_iter()
     }
 
     /// Get the file name extension for the supported language.
     pub fn language_extension(&self) -> &'static str {
         match self {
             SupportedLanguage::Go => "go",
+            SupportedLanguage::Java => "java",
             SupportedLanguage::Kotlin => "kt",
             SupportedLanguage::Scala => "scala",
             SupportedLanguage::Swift => "swift",
             SupportedLanguage::TypeScript => "ts",
             SupportedLanguage::Python => "py",
+            SupportedLanguage::CSharp => "cs",
+            SupportedLanguage::Dart => "dart",
+            SupportedLanguage::JsonSchema => "json",
+            SupportedLanguage::OpenApi => "yaml",
+            SupportedLanguage::Protobuf => "proto",
+            SupportedLanguage::GraphQl => "graphql",
         }
     }
 }
@@ -131,11 +166,18 @@ impl FromStr for SupportedLanguage {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             "go" => Ok(Self::Go),
+            "java" => Ok(Self::Java),
             "kotlin" => Ok(Self::Kotlin),
             "scala" => Ok(Self::Scala),
             "swift" => Ok(Self::Swift),
             "typescript" => Ok(Self::TypeScript),
             "python" => Ok(Self::Python),
+            "csharp" => Ok(Self::CSharp),
+            "dart" => Ok(Self::Dart),
+            "jsonschema" => Ok(Self::JsonSchema),
+            "openapi" => Ok(Self::OpenApi),
+            "protobuf" => Ok(Self::Protobuf),
+            "graphql" => Ok(Self::GraphQl),
             _ => Err(ParseError::UnsupportedLanguage(s.into())),
         }
     }
@@ -165,6 +207,11 @@ pub trait Language {
     ) -> std::io::Result<()> {
         self.begin_file(writable, &data)?;
 
+        let mapped_imports = used_type_map_imports(self, &data);
+        if !mapped_imports.is_empty() {
+            self.write_type_map_imports(writable, &mapped_imports)?;
+        }
+
         if data.multi_file {
             self.write_imports(writable, used_imports(&data, all_types))?;
         }
@@ -173,6 +220,8 @@ pub trait Language {
             structs,
             enums,
             aliases,
+            source_positions,
+            type_ordering,
             ..
         } = data;
 
@@ -184,7 +233,12 @@ pub trait Language {
                 .chain(enums.into_iter().map(RustItem::Enum)),
         );
 
-        topsort(&mut items);
+        sort_items(
+            &mut items,
+            type_ordering,
+            &source_positions,
+            self.forward_references(),
+        );
 
         for thing in &items {
             match thing {
@@ -197,8 +251,32 @@ pub trait Language {
         self.end_file(writable)
     }
 
+    /// Whether types can be used before they are declared. Otherwise types are always
+    /// written after the types they depend on, whatever the [`TypeOrdering`](crate::TypeOrdering).
+    fn forward_references(&self) -> bool {
+        true
+    }
+
     /// Get the type mapping for this language `(Rust type name -> lang type name)`
-    fn type_map(&mut self) -> &HashMap<String, String>;
+    fn type_map(&mut self) -> &HashMap<String, TypeMapping>;
+
+    /// Get the imports needed by mapped types `(Rust type name -> import)`.
+    ///
+    /// What an import is depends on the language, such as a module to import the
+    /// mapped type from or the fully qualified name of the mapped type.
+    fn type_map_imports(&mut self) -> Option<&HashMap<String, String>> {
+        None
+    }
+
+    /// Write the imports needed by the mapped types used in a file. Only called
+    /// if at least one mapped type with an import is used.
+    fn write_type_map_imports(
+        &mut self,
+        _w: &mut dyn Write,
+        _imports: &BTreeSet<MappedImport>,
+    ) -> std::io::Result<()> {
+        Ok(())
+    }
 
     /// Convert a Rust type into a type from this language.
     fn format_type(
@@ -226,7 +304,7 @@ pub trait Language {
         _generic_types: &[String],
     ) -> Result<String, RustTypeFormatError> {
         Ok(if let Some(mapped) = self.type_map().get(base) {
-            mapped.into()
+            mapped.apply(base, &[])?
         } else {
             base.into()
         })
@@ -242,8 +320,8 @@ pub trait Language {
         parameters: &[RustType],
         generic_types: &[String],
     ) -> Result<String, RustTypeFormatError> {
-        if let Some(mapped) = self.type_map().get(base) {
-            Ok(mapped.into())
+        if self.type_map().contains_key(base) {
+            format_mapped_type(self, base, parameters, generic_types)
         } else {
             let parameters: Result<Vec<String>, RustTypeFormatError> = parameters
                 .iter()
@@ -253,9 +331,11 @@ pub trait Language {
             Ok(format!(
                 "{}{}",
                 self.format_simple_type(base, generic_types)?,
-                (!parameters.is_empty())
-                    .then(|| self.format_generic_parameters(parameters))
-                    .unwrap_or_default()
+                if !parameters.is_empty() {
+                    self.format_generic_parameters(parameters)
+                } else {
+                    String::new()
+                }
             ))
         }
     }
@@ -342,7 +422,7 @@ pub trait Language {
     ///
     /// This function will write out:
     ///
-    /// ```compile_fail
+    /// ```text
     /// /// Generated type representing the anonymous struct variant `<make_struct_name>` of the `AlgebraicEnum` rust enum
     /// /* the struct definition for whatever language */
     /// ```
@@ -407,13 +487,108 @@ pub trait Language {
         Vec::new()
     }
 
+    /// Called once before any file is generated, with the parsed data of every crate.
+    /// Languages that resolve types of other crates, like GraphQL replacing type
+    /// aliases with the types they alias, collect them here.
+    fn prepare_crates(&mut self, _crate_parsed_data: &BTreeMap<CrateName, ParsedData>) {}
+
+    /// Called before each file is generated when writing one file per crate, with
+    /// the path of every crate's file relative to the file about to be generated and
+    /// without its extension, like `./other` or `../other/index`. Languages that
+    /// import other crates by their path use these in their imports.
+    fn set_import_paths(&mut self, _import_paths: HashMap<CrateName, String>) {}
+
     /// Any other final steps after modules have been generated. For example creating a new
-    /// module with special types.
+    /// module with special types. Not called when only checking that the output is up to date.
     fn post_generation(&self, _output_folder: &str) -> Result<(), GenerationError> {
         Ok(())
     }
 }
 
+/// An import needed by a mapped type.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MappedImport {
+    /// The import as configured for the mapping.
+    pub import: String,
+    /// The name of the mapped type, without any generic parameters.
+    pub type_name: String,
+}
+
+/// The imports of all mapped types used by the types in `data`.
+fn used_type_map_imports<L: Language + ?Sized>(
+    lang: &mut L,
+    data: &ParsedData,
+) -> BTreeSet<MappedImport> {
+    fn visit<'a>(ty: &'a RustType, names: &mut HashSet<&'a str>) {
+        names.insert(ty.id());
+        for parameter in ty.parameters() {
+            visit(parameter, names);
+        }
+    }
+
+    let mut names = HashSet::new();
+    let types = data
+        .structs
+        .iter()
+        .flat_map(|s| s.fields.iter().map(|f| &f.ty))
+        .chain(data.enums.iter().flat_map(|e| {
+            e.shared().variants.iter().flat_map(|v| match v {
+                RustEnumVariant::Unit(_) => Vec::new(),
+                RustEnumVariant::Tuple { ty, .. } => vec![ty],
+                RustEnumVariant::AnonymousStruct { fields, .. } => {
+                    fields.iter().map(|f| &f.ty).collect()
+                }
+            })
+        }))
+        .chain(data.aliases.iter().map(|a| &a.r#type));
+    for ty in types {
+        visit(ty, &mut names);
+    }
+
+    let Some(imports) = lang.type_map_imports().cloned() else {
+        return BTreeSet::new();
+    };
+    let type_map = lang.type_map();
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let import = imports.get(name)?;
+            let mapped = type_map.get(name).map_or(name, TypeMapping::as_str);
+            Some(MappedImport {
+                import: import.clone(),
+                type_name: mapped_type_name(mapped).to_owned(),
+            })
+        })
+        .collect()
+}
+
+/// The name of a mapped type without its generic parameters, such as `Map` for `Map<{0}, {1}>`.
+fn mapped_type_name(mapped: &str) -> &str {
+    mapped
+        .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '.'))
+        .next()
+        .unwrap_or_default()
+}
+
+/// Format a generic type found in the type map, filling in its formatted
+/// generic parameters if the mapping uses them.
+fn format_mapped_type<L: Language + ?Sized>(
+    lang: &mut L,
+    base: &str,
+    parameters: &[RustType],
+    generic_types: &[String],
+) -> Result<String, RustTypeFormatError> {
+    let parameters = if lang.type_map()[base].has_parameters() {
+        parameters
+            .iter()
+            .map(|p| lang.format_type(p, generic_types))
+            .collect::<Result<Vec<_>, _>>()?
+    } else {
+        Vec::new()
+    };
+    lang.type_map()[base].apply(base, &parameters)
+}
+
 /// Lookup any refeferences to other typeshared types in order to build
 /// a list of imports for the generated module.
 fn used_imports<'a, 'b: 'a>(
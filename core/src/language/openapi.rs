@@ -0,0 +1,223 @@
+use super::{
+    json_schema::{definitions, Dialect},
+    CrateName, CrateTypes, Language, ScopedCrateTypes, TypeMapping,
+};
+use crate::{
+    parser::ParsedData,
+    rust_types::{RustTypeFormatError, SpecialRustType},
+};
+use serde::{Deserialize, Serialize};
+use serde_norway::{Mapping, Value};
+use std::{collections::HashMap, fs, io::Write, path::PathBuf};
+
+/// The version of the OpenAPI specification of generated documents.
+const OPENAPI_VERSION: &str = "3.1.0";
+
+/// The extension of merged documents that records the names of the generated schemas.
+const EXTENSION: &str = "x-typeshare";
+
+/// The format OpenAPI documents are written in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum OpenApiFormat {
+    /// YAML, the default.
+    #[default]
+    Yaml,
+    /// JSON.
+    Json,
+}
+
+impl OpenApiFormat {
+    /// The extension of files in this format.
+    pub fn extension(self) -> &'static str {
+        match self {
+            OpenApiFormat::Yaml => "yaml",
+            OpenApiFormat::Json => "json",
+        }
+    }
+}
+
+/// All information needed to generate the `components/schemas` of an OpenAPI document.
+///
+/// Schemas are generated like the [`JsonSchema`](super::JsonSchema) definitions,
+/// which OpenAPI 3.1 shares the dialect of. The variants of algebraic enums are
+/// schemas of their own, which the `discriminator` of the enum maps its tags to.
+#[derive(Default)]
+pub struct OpenApi {
+    /// Conversions from Rust type names to schemas. A mapping is either a JSON
+    /// schema, like `{"type": "string", "format": "date-time"}`, or the name of a
+    /// JSON type, like `string`.
+    pub type_mappings: HashMap<String, TypeMapping>,
+    /// Whether or not to exclude the version header that normally appears at the top of generated YAML.
+    /// If you aren't generating a snapshot test, this setting can just be left as a default (false)
+    pub no_version_header: bool,
+    /// The paths of the other crates' files, relative to the file being generated.
+    pub import_paths: HashMap<CrateName, String>,
+    /// The format to write documents in.
+    pub format: OpenApiFormat,
+    /// The `info.title` of new documents. Defaults to the name of the crate.
+    pub title: String,
+    /// The `info.version` of new documents. Defaults to `1.0.0`.
+    pub version: String,
+    /// An existing document to add the schemas to, like a specification with hand
+    /// written paths. Everything in it is kept, except for schemas with the names
+    /// of generated ones, which are replaced, and schemas an earlier run generated
+    /// that are no longer generated, which are removed. The names of the generated
+    /// schemas are recorded under `x-typeshare` for this.
+    ///
+    /// The document is read as data, so its comments are dropped and its anchors
+    /// and aliases are expanded.
+    pub spec: Option<PathBuf>,
+}
+
+impl Language for OpenApi {
+    fn generate_types(
+        &mut self,
+        writable: &mut dyn Write,
+        all_types: &CrateTypes,
+        data: ParsedData,
+    ) -> std::io::Result<()> {
+        let title = match self.title.as_str() {
+            "" => data.crate_name.to_string(),
+            title => title.to_owned(),
+        };
+        let defs = definitions(
+            data,
+            all_types,
+            &self.import_paths,
+            &self.type_mappings,
+            Dialect::OpenApi,
+            self.format.extension(),
+        )?;
+
+        let mut document = match &self.spec {
+            Some(spec) => {
+                let contents = fs::read_to_string(spec).map_err(|err| {
+                    std::io::Error::new(err.kind(), format!("{}: {err}", spec.display()))
+                })?;
+                // YAML is a superset of JSON, so this reads both.
+                serde_norway::from_str(&contents)
+                    .map_err(|err| std::io::Error::other(format!("{}: {err}", spec.display())))?
+            }
+            None => self.new_document(title),
+        };
+        let Value::Mapping(root) = &mut document else {
+            return Err(std::io::Error::other(
+                "the OpenAPI document is not an object",
+            ));
+        };
+        let stale = generated_schemas(root);
+        let schemas = child_mapping(child_mapping(root, "components")?, "schemas")?;
+        for name in &stale {
+            schemas.remove(name.as_str());
+        }
+        let mut names = Vec::new();
+        for (name, schema) in defs {
+            let schema = serde_norway::to_value(&schema).map_err(std::io::Error::other)?;
+            names.push(Value::String(name.clone()));
+            schemas.insert(Value::String(name), schema);
+        }
+        if self.spec.is_some() {
+            let mut extension = Mapping::new();
+            extension.insert("schemas".into(), Value::Sequence(names));
+            root.insert(EXTENSION.into(), Value::Mapping(extension));
+        }
+
+        match self.format {
+            OpenApiFormat::Yaml => {
+                if !self.no_version_header {
+                    writeln!(
+                        writable,
+                        "# Generated by typeshare {}",
+                        env!("CARGO_PKG_VERSION")
+                    )?;
+                }
+                let yaml = serde_norway::to_string(&document).map_err(std::io::Error::other)?;
+                writable.write_all(yaml.as_bytes())
+            }
+            OpenApiFormat::Json => {
+                serde_json::to_writer_pretty(&mut *writable, &document)?;
+                writeln!(writable)
+            }
+        }
+    }
+
+    fn type_map(&mut self) -> &HashMap<String, TypeMapping> {
+        &self.type_mappings
+    }
+
+    /// Types of other crates are referenced by the path of their file instead.
+    fn write_imports(
+        &mut self,
+        _writer: &mut dyn Write,
+        _imports: ScopedCrateTypes<'_>,
+    ) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    /// Formats a type as its schema, in compact JSON.
+    fn format_special_type(
+        &mut self,
+        special_ty: &SpecialRustType,
+        generic_types: &[String],
+    ) -> Result<String, RustTypeFormatError> {
+        super::JsonSchema {
+            type_mappings: self.type_mappings.clone(),
+            ..Default::default()
+        }
+        .format_special_type(special_ty, generic_types)
+    }
+
+    fn ignored_reference_types(&self) -> Vec<&str> {
+        self.type_mappings.keys().map(|s| s.as_str()).collect()
+    }
+
+    fn set_import_paths(&mut self, import_paths: HashMap<CrateName, String>) {
+        self.import_paths = import_paths;
+    }
+}
+
+impl OpenApi {
+    /// A document with only the fields OpenAPI requires.
+    fn new_document(&self, title: String) -> Value {
+        let version = match self.version.as_str() {
+            "" => "1.0.0",
+            version => version,
+        };
+        let mut info = Mapping::new();
+        info.insert("title".into(), title.into());
+        info.insert("version".into(), version.into());
+
+        let mut document = Mapping::new();
+        document.insert("openapi".into(), OPENAPI_VERSION.into());
+        document.insert("info".into(), Value::Mapping(info));
+        Value::Mapping(document)
+    }
+}
+
+/// The names of the schemas an earlier run recorded under `x-typeshare`.
+fn generated_schemas(root: &Mapping) -> Vec<String> {
+    root.get(EXTENSION)
+        .and_then(|extension| extension.get("schemas"))
+        .and_then(Value::as_sequence)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .map(str::to_owned)
+        .collect()
+}
+
+/// The object under `key`, added if it is missing or empty.
+fn child_mapping<'a>(mapping: &'a mut Mapping, key: &str) -> std::io::Result<&'a mut Mapping> {
+    let child = mapping.entry(key.into()).or_insert(Value::Null);
+    if child.is_null() {
+        *child = Value::Mapping(Mapping::new());
+    }
+    match child {
+        Value::Mapping(child) => Ok(child),
+        _ => Err(std::io::Error::other(format!(
+            "`{key}` of the OpenAPI document is not an object"
+        ))),
+    }
+}
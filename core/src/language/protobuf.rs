@@ -0,0 +1,783 @@
+use super::{format_mapped_type, CrateName, Language, MappedImport, ScopedCrateTypes, TypeMapping};
+use crate::{
+    generator::package_path,
+    language::SupportedLanguage,
+    parser::ParsedData,
+    rename::RenameExt,
+    rust_types::{
+        RustEnum, RustEnumShared, RustEnumVariant, RustField, RustStruct, RustType, RustTypeAlias,
+        RustTypeFormatError, SpecialRustType,
+    },
+    GenerationError,
+};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fs,
+    io::{self, Write},
+    ops::RangeInclusive,
+    path::PathBuf,
+};
+use thiserror::Error;
+
+/// The message that unit types and unit variants are.
+const EMPTY: &str = "google.protobuf.Empty";
+
+/// The numbers fields can have.
+const FIELD_NUMBERS: RangeInclusive<u32> = 1..=536_870_911;
+
+/// Field numbers reserved for the implementation of Protocol Buffers.
+const IMPLEMENTATION_RESERVED: RangeInclusive<u32> = 19_000..=19_999;
+
+/// The numbers enum values other than the zero value can have.
+const ENUM_VALUES: RangeInclusive<u32> = 1..=i32::MAX as u32;
+
+/// The types that can be the keys of maps.
+const MAP_KEY_TYPES: &[&str] = &[
+    "bool", "fixed32", "fixed64", "int32", "int64", "sfixed32", "sfixed64", "sint32", "sint64",
+    "string", "uint32", "uint64",
+];
+
+const LOCK_HEADER: &str = "\
+# Field numbers assigned by typeshare. Commit this file: numbers can't change once
+# messages are encoded, and removed fields stay here so their numbers aren't reused.";
+
+/// All information needed to generate Protocol Buffers messages.
+///
+/// Every field needs a number that stays the same for as long as encoded messages
+/// exist. Numbers are either given by `#[typeshare(proto_tag = N)]` or assigned in
+/// the order of the fields, and kept in the lock file so that adding, removing or
+/// reordering fields doesn't change the numbers of the others.
+#[derive(Default)]
+pub struct Protobuf {
+    /// The package of the generated files. Files have no package if this is empty.
+    pub package: String,
+    /// Conversions from Rust type names to Protocol Buffers type names.
+    pub type_mappings: HashMap<String, TypeMapping>,
+    /// Imports needed by the mapped types, from Rust type names to the file to
+    /// import, like `google/protobuf/timestamp.proto`.
+    pub type_mapping_imports: HashMap<String, String>,
+    /// Whether or not to exclude the version header that normally appears at the top of generated code.
+    /// If you aren't generating a snapshot test, this setting can just be left as a default (false)
+    pub no_version_header: bool,
+    /// The paths of the other crates' files, relative to the file being generated.
+    pub import_paths: HashMap<CrateName, String>,
+    /// The path of the file being generated, relative to the output folder.
+    pub file_name: String,
+    /// The file the field numbers are kept in. Numbers are only stable within a
+    /// single run without one, unless every field has a `proto_tag`.
+    pub lock_file: Option<PathBuf>,
+    /// The numbers of the lock file, read when the first file is generated and
+    /// written back with the numbers of new fields once all files are.
+    pub field_numbers: Option<FieldNumbers>,
+}
+
+/// The field numbers of every message and the values of every enum, as they are
+/// kept in the lock file.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FieldNumbers {
+    /// The numbers of the fields of each message, by the name of the field.
+    /// Nested messages are named after the message they are in, like `Shape.CircleContent`.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub messages: BTreeMap<String, BTreeMap<String, u32>>,
+    /// The values of each enum, by the name of the Rust variant.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub enums: BTreeMap<String, BTreeMap<String, u32>>,
+}
+
+/// Field numbers that can't be used.
+#[derive(Debug, Error)]
+enum NumberingError {
+    #[error("`{scope}.{member}` is numbered {locked} in the lock file and can't be renumbered to {number}")]
+    Renumbered {
+        scope: String,
+        member: String,
+        locked: u32,
+        number: u32,
+    },
+    #[error("`{scope}.{member}` can't be numbered {number}, which belongs to `{other}`")]
+    Taken {
+        scope: String,
+        member: String,
+        number: u32,
+        other: String,
+    },
+    #[error("`{scope}.{member}` can't be numbered {number}, which is not a valid number")]
+    Invalid {
+        scope: String,
+        member: String,
+        number: u32,
+    },
+}
+
+/// What is being numbered.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Numbering {
+    Field,
+    EnumValue,
+}
+
+impl Numbering {
+    fn is_valid(self, number: u32) -> bool {
+        match self {
+            Numbering::Field => {
+                FIELD_NUMBERS.contains(&number) && !IMPLEMENTATION_RESERVED.contains(&number)
+            }
+            Numbering::EnumValue => ENUM_VALUES.contains(&number),
+        }
+    }
+}
+
+/// The numbers of the members of a message or enum.
+struct Numbered {
+    /// The number of each member, in order.
+    numbers: Vec<u32>,
+    /// The names and numbers of removed members, which must not be reused.
+    reserved: Vec<(String, u32)>,
+}
+
+impl Language for Protobuf {
+    fn type_map(&mut self) -> &HashMap<String, TypeMapping> {
+        &self.type_mappings
+    }
+
+    fn type_map_imports(&mut self) -> Option<&HashMap<String, String>> {
+        Some(&self.type_mapping_imports)
+    }
+
+    fn format_simple_type(
+        &mut self,
+        base: &String,
+        generic_types: &[String],
+    ) -> Result<String, RustTypeFormatError> {
+        if generic_types.contains(base) {
+            return Err(RustTypeFormatError::GenericsForbiddenInProtobuf(
+                base.clone(),
+            ));
+        }
+        Ok(match self.type_mappings.get(base) {
+            Some(mapped) => mapped.apply(base, &[])?,
+            None => base.clone(),
+        })
+    }
+
+    /// Only mapped types can have generic parameters.
+    fn format_generic_type(
+        &mut self,
+        base: &String,
+        parameters: &[RustType],
+        generic_types: &[String],
+    ) -> Result<String, RustTypeFormatError> {
+        if self.type_mappings.contains_key(base) {
+            format_mapped_type(self, base, parameters, generic_types)
+        } else {
+            Err(RustTypeFormatError::GenericsForbiddenInProtobuf(
+                base.clone(),
+            ))
+        }
+    }
+
+    /// Formats a type with its label, like `repeated string`, since lists, maps
+    /// and options can only be the types of fields.
+    fn format_special_type(
+        &mut self,
+        special_ty: &SpecialRustType,
+        generic_types: &[String],
+    ) -> Result<String, RustTypeFormatError> {
+        Ok(match special_ty {
+            SpecialRustType::Vec(rtype)
+            | SpecialRustType::Array(rtype, _)
+            | SpecialRustType::Slice(rtype) => {
+                format!("repeated {}", self.element_type(rtype, generic_types)?)
+            }
+            SpecialRustType::Option(rtype) => {
+                let ty = self.format_type(rtype, generic_types)?;
+                if is_labelled(&ty) {
+                    ty
+                } else {
+                    format!("optional {ty}")
+                }
+            }
+            SpecialRustType::HashMap(rtype1, rtype2) => {
+                let key = self.format_type(rtype1, generic_types)?;
+                if !MAP_KEY_TYPES.contains(&key.as_str()) {
+                    return Err(RustTypeFormatError::MapKeyForbiddenInProtobuf(key));
+                }
+                format!("map<{key}, {}>", self.element_type(rtype2, generic_types)?)
+            }
+            SpecialRustType::Unit => EMPTY.into(),
+            SpecialRustType::String | SpecialRustType::Char => "string".into(),
+            SpecialRustType::I8 | SpecialRustType::I16 | SpecialRustType::I32 => "int32".into(),
+            SpecialRustType::U8 | SpecialRustType::U16 | SpecialRustType::U32 => "uint32".into(),
+            SpecialRustType::I54 | SpecialRustType::I64 | SpecialRustType::ISize => "int64".into(),
+            SpecialRustType::U53 | SpecialRustType::U64 | SpecialRustType::USize => "uint64".into(),
+            SpecialRustType::Bool => "bool".into(),
+            SpecialRustType::F32 => "float".into(),
+            SpecialRustType::F64 => "double".into(),
+        })
+    }
+
+    fn begin_file(&mut self, w: &mut dyn Write, parsed_data: &ParsedData) -> io::Result<()> {
+        if self.field_numbers.is_none() {
+            self.field_numbers = Some(self.read_lock_file()?);
+        }
+        self.file_name = parsed_data.file_name.clone();
+
+        if !self.no_version_header {
+            writeln!(w, "// Generated by typeshare {}", env!("CARGO_PKG_VERSION"))?;
+            writeln!(w)?;
+        }
+        writeln!(w, "syntax = \"proto3\";")?;
+        writeln!(w)?;
+        if !self.package.is_empty() {
+            writeln!(w, "package {};", self.package)?;
+            writeln!(w)?;
+        }
+        if uses_unit(parsed_data) {
+            writeln!(w, "import \"google/protobuf/empty.proto\";")?;
+            writeln!(w)?;
+        }
+        Ok(())
+    }
+
+    /// Files are imported by their path in the output folder, which is expected to
+    /// be the import path of `protoc`.
+    fn write_imports(
+        &mut self,
+        w: &mut dyn Write,
+        imports: ScopedCrateTypes<'_>,
+    ) -> io::Result<()> {
+        if imports.is_empty() {
+            return Ok(());
+        }
+        for crate_name in imports.keys() {
+            let relative_path = match self.import_paths.get(crate_name) {
+                Some(path) => path.clone(),
+                None => format!("./{crate_name}"),
+            };
+            writeln!(
+                w,
+                "import \"{}.proto\";",
+                package_path(&self.file_name, &relative_path)
+            )?;
+        }
+        writeln!(w)
+    }
+
+    fn write_type_map_imports(
+        &mut self,
+        w: &mut dyn Write,
+        imports: &BTreeSet<MappedImport>,
+    ) -> io::Result<()> {
+        for import in imports.iter().map(|import| &import.import).sorted().dedup() {
+            writeln!(w, "import \"{import}\";")?;
+        }
+        writeln!(w)
+    }
+
+    /// Type aliases are messages with the aliased type as their only field, since
+    /// Protocol Buffers has no aliases.
+    fn write_type_alias(&mut self, w: &mut dyn Write, ty: &RustTypeAlias) -> io::Result<()> {
+        forbid_generics(&ty.generic_types)?;
+        let aliased = self
+            .format_type(&ty.r#type, &[])
+            .map_err(io::Error::other)?;
+
+        write_comments(w, 0, &ty.comments)?;
+        writeln!(w, "message {} {{", ty.id.renamed)?;
+        writeln!(w, "  {aliased} value = 1;")?;
+        writeln!(w, "}}")?;
+        writeln!(w)
+    }
+
+    fn write_struct(&mut self, w: &mut dyn Write, rs: &RustStruct) -> io::Result<()> {
+        forbid_generics(&rs.generic_types)?;
+        write_comments(w, 0, &rs.comments)?;
+        self.write_message(w, 0, &rs.id.renamed, &rs.id.renamed, &rs.fields)?;
+        writeln!(w)
+    }
+
+    fn write_enum(&mut self, w: &mut dyn Write, e: &RustEnum) -> io::Result<()> {
+        let shared = e.shared();
+        forbid_generics(&shared.generic_types)?;
+        write_comments(w, 0, &shared.comments)?;
+
+        match e {
+            RustEnum::Unit(shared) => self.write_unit_enum(w, shared)?,
+            RustEnum::Algebraic {
+                content_key,
+                shared,
+                ..
+            } => self.write_algebraic_enum(w, content_key, shared)?,
+        }
+        writeln!(w)
+    }
+
+    fn ignored_reference_types(&self) -> Vec<&str> {
+        self.type_mappings.keys().map(|s| s.as_str()).collect()
+    }
+
+    fn set_import_paths(&mut self, import_paths: HashMap<CrateName, String>) {
+        self.import_paths = import_paths;
+    }
+
+    /// Writes the numbers of new fields to the lock file.
+    fn post_generation(&self, _output_folder: &str) -> Result<(), GenerationError> {
+        let (Some(lock_file), Some(field_numbers)) = (&self.lock_file, &self.field_numbers) else {
+            return Ok(());
+        };
+        let error = |err: &dyn std::fmt::Display| {
+            GenerationError::PostGeneration(format!("{}: {err}", lock_file.display()))
+        };
+
+        let contents = toml::to_string(field_numbers).map_err(|err| error(&err))?;
+        let contents = format!("{LOCK_HEADER}\n\n{contents}");
+        if fs::read_to_string(lock_file).is_ok_and(|previous| previous == contents) {
+            return Ok(());
+        }
+        fs::write(lock_file, contents).map_err(|err| error(&err))
+    }
+}
+
+impl Protobuf {
+    /// The numbers of the lock file, or none if there is no lock file yet.
+    fn read_lock_file(&self) -> io::Result<FieldNumbers> {
+        let Some(lock_file) = &self.lock_file else {
+            return Ok(FieldNumbers::default());
+        };
+        match fs::read_to_string(lock_file) {
+            Ok(contents) => toml::from_str(&contents)
+                .map_err(|err| io::Error::other(format!("{}: {err}", lock_file.display()))),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(FieldNumbers::default()),
+            Err(err) => Err(io::Error::new(
+                err.kind(),
+                format!("{}: {err}", lock_file.display()),
+            )),
+        }
+    }
+
+    /// Assign numbers to the members of the message or enum `scope`, keeping the
+    /// numbers they have in the lock file.
+    fn assign_numbers<'a>(
+        &mut self,
+        scope: &str,
+        members: impl IntoIterator<Item = (&'a str, Option<u32>)>,
+        numbering: Numbering,
+    ) -> io::Result<Numbered> {
+        let field_numbers = self.field_numbers.get_or_insert_with(Default::default);
+        let locked = match numbering {
+            Numbering::Field => &mut field_numbers.messages,
+            Numbering::EnumValue => &mut field_numbers.enums,
+        }
+        .entry(scope.to_owned())
+        .or_default();
+        assign_numbers(locked, scope, members.into_iter().collect(), numbering)
+            .map_err(io::Error::other)
+    }
+
+    /// Write a message with `fields`, named `name` in its parent and `scope` in the lock file.
+    fn write_message(
+        &mut self,
+        w: &mut dyn Write,
+        indent: usize,
+        name: &str,
+        scope: &str,
+        fields: &[RustField],
+    ) -> io::Result<()> {
+        let numbered = self.assign_numbers(
+            scope,
+            fields
+                .iter()
+                .map(|field| (field.id.original.as_str(), field.proto_tag)),
+            Numbering::Field,
+        )?;
+
+        let prefix = "  ".repeat(indent);
+        if fields.is_empty() && numbered.reserved.is_empty() {
+            return writeln!(w, "{prefix}message {name} {{}}");
+        }
+        writeln!(w, "{prefix}message {name} {{")?;
+        write_reserved(w, indent + 1, &numbered.reserved, |name| name.to_owned())?;
+        for (field, number) in fields.iter().zip(numbered.numbers) {
+            self.write_field(w, indent + 1, field, number)?;
+        }
+        writeln!(w, "{prefix}}}")
+    }
+
+    fn write_field(
+        &mut self,
+        w: &mut dyn Write,
+        indent: usize,
+        field: &RustField,
+        number: u32,
+    ) -> io::Result<()> {
+        let ty = match field.type_override(SupportedLanguage::Protobuf) {
+            Some(ty) => ty.to_owned(),
+            None => self.format_type(&field.ty, &[]).map_err(io::Error::other)?,
+        };
+        let name = &field.id.original;
+        // The JSON names of fields are camel case unless they are given.
+        let options = if field.id.renamed == name.to_camel_case() {
+            String::new()
+        } else {
+            format!(" [json_name = \"{}\"]", escape(&field.id.renamed))
+        };
+
+        write_comments(w, indent, &field.comments)?;
+        writeln!(w, "{}{ty} {name} = {number}{options};", "  ".repeat(indent))
+    }
+
+    /// Unit enums are enums with values prefixed by the name of the enum, after the
+    /// zero value every enum must start with.
+    fn write_unit_enum(&mut self, w: &mut dyn Write, shared: &RustEnumShared) -> io::Result<()> {
+        let name = &shared.id.renamed;
+        let prefix = name.to_screaming_snake_case();
+        let numbered = self.assign_numbers(
+            name,
+            shared
+                .variants
+                .iter()
+                .map(|v| (v.shared().id.original.as_str(), v.shared().proto_tag)),
+            Numbering::EnumValue,
+        )?;
+
+        writeln!(w, "enum {name} {{")?;
+        write_reserved(w, 1, &numbered.reserved, |variant| {
+            format!("{prefix}_{}", variant.to_owned().to_screaming_snake_case())
+        })?;
+        writeln!(w, "  {prefix}_UNSPECIFIED = 0;")?;
+        for (variant, number) in shared.variants.iter().zip(numbered.numbers) {
+            write_comments(w, 1, &variant.shared().comments)?;
+            writeln!(
+                w,
+                "  {prefix}_{} = {number};",
+                variant.shared().id.original.to_screaming_snake_case()
+            )?;
+        }
+        writeln!(w, "}}")
+    }
+
+    /// Algebraic enums are messages with a `oneof` named after the content key.
+    /// Unit variants are empty messages, and the contents of variants that can't
+    /// be in a `oneof`, like lists or anonymous structs, are nested messages.
+    fn write_algebraic_enum(
+        &mut self,
+        w: &mut dyn Write,
+        content_key: &str,
+        shared: &RustEnumShared,
+    ) -> io::Result<()> {
+        let name = &shared.id.renamed;
+        let members = shared
+            .variants
+            .iter()
+            .map(|v| v.shared().id.original.to_snake_case())
+            .collect::<Vec<_>>();
+        let numbered = self.assign_numbers(
+            name,
+            members
+                .iter()
+                .map(String::as_str)
+                .zip(shared.variants.iter().map(|v| v.shared().proto_tag)),
+            Numbering::Field,
+        )?;
+
+        if shared.variants.is_empty() && numbered.reserved.is_empty() {
+            return writeln!(w, "message {name} {{}}");
+        }
+        writeln!(w, "message {name} {{")?;
+        write_reserved(w, 1, &numbered.reserved, |member| member.to_owned())?;
+
+        let mut contents = Vec::new();
+        if !shared.variants.is_empty() {
+            writeln!(w, "  oneof {} {{", content_key.to_owned().to_snake_case())?;
+            for ((variant, member), number) in
+                shared.variants.iter().zip(&members).zip(numbered.numbers)
+            {
+                let content_name =
+                    format!("{}Content", variant.shared().id.original.to_pascal_case());
+                let ty = match variant {
+                    RustEnumVariant::Unit(_) => EMPTY.to_owned(),
+                    RustEnumVariant::Tuple { ty, .. } => {
+                        let ty = self.format_type(ty, &[]).map_err(io::Error::other)?;
+                        if is_labelled(&ty) {
+                            contents.push((content_name.clone(), variant, Some(ty)));
+                            content_name
+                        } else {
+                            ty
+                        }
+                    }
+                    RustEnumVariant::AnonymousStruct { .. } => {
+                        contents.push((content_name.clone(), variant, None));
+                        content_name
+                    }
+                };
+                write_comments(w, 2, &variant.shared().comments)?;
+                writeln!(w, "    {ty} {member} = {number};")?;
+            }
+            writeln!(w, "  }}")?;
+        }
+
+        for (content_name, variant, ty) in contents {
+            writeln!(w)?;
+            match (variant, ty) {
+                (RustEnumVariant::AnonymousStruct { fields, .. }, _) => self.write_message(
+                    w,
+                    1,
+                    &content_name,
+                    &format!("{name}.{content_name}"),
+                    fields,
+                )?,
+                (_, Some(ty)) => {
+                    writeln!(w, "  message {content_name} {{")?;
+                    writeln!(w, "    {ty} value = 1;")?;
+                    writeln!(w, "  }}")?;
+                }
+                _ => unreachable!("only anonymous structs and labelled types have contents"),
+            }
+        }
+        writeln!(w, "}}")
+    }
+
+    /// Format the type of a list element or map value, which can't have a label.
+    fn element_type(
+        &mut self,
+        ty: &RustType,
+        generic_types: &[String],
+    ) -> Result<String, RustTypeFormatError> {
+        let element = self.format_type(ty, generic_types)?;
+        if is_labelled(&element) {
+            return Err(RustTypeFormatError::NestedCollectionInProtobuf(element));
+        }
+        Ok(element)
+    }
+}
+
+/// Assign the numbers of `members`, which are given by their tag, then by the
+/// `locked` numbers, then by the next number no other member has or had. The
+/// numbers of new members are added to `locked`.
+fn assign_numbers(
+    locked: &mut BTreeMap<String, u32>,
+    scope: &str,
+    members: Vec<(&str, Option<u32>)>,
+    numbering: Numbering,
+) -> Result<Numbered, NumberingError> {
+    let mut taken = locked
+        .iter()
+        .map(|(member, number)| (*number, member.clone()))
+        .collect::<BTreeMap<_, _>>();
+
+    let mut numbers = Vec::with_capacity(members.len());
+    for &(member, tag) in &members {
+        let number = match (tag, locked.get(member).copied()) {
+            (Some(number), Some(locked)) if number != locked => {
+                return Err(NumberingError::Renumbered {
+                    scope: scope.to_owned(),
+                    member: member.to_owned(),
+                    locked,
+                    number,
+                })
+            }
+            (Some(number), _) | (None, Some(number)) => number,
+            (None, None) => {
+                numbers.push(None);
+                continue;
+            }
+        };
+        if !numbering.is_valid(number) {
+            return Err(NumberingError::Invalid {
+                scope: scope.to_owned(),
+                member: member.to_owned(),
+                number,
+            });
+        }
+        match taken.get(&number) {
+            Some(other) if other != member => {
+                return Err(NumberingError::Taken {
+                    scope: scope.to_owned(),
+                    member: member.to_owned(),
+                    number,
+                    other: other.clone(),
+                })
+            }
+            _ => {}
+        }
+        taken.insert(number, member.to_owned());
+        numbers.push(Some(number));
+    }
+
+    let mut next = 1;
+    let numbers = members
+        .iter()
+        .zip(numbers)
+        .map(|(&(member, _), number)| {
+            let number = number.unwrap_or_else(|| {
+                while taken.contains_key(&next) || !numbering.is_valid(next) {
+                    next += 1;
+                }
+                taken.insert(next, member.to_owned());
+                next
+            });
+            locked.insert(member.to_owned(), number);
+            number
+        })
+        .collect();
+
+    let reserved = locked
+        .iter()
+        .filter(|(member, _)| !members.iter().any(|(name, _)| name == member))
+        .map(|(member, number)| (member.clone(), *number))
+        .sorted_by_key(|(_, number)| *number)
+        .collect();
+    Ok(Numbered { numbers, reserved })
+}
+
+/// Whether a formatted type has a label, and so can't be in a list, map or `oneof`.
+fn is_labelled(ty: &str) -> bool {
+    ty.starts_with("repeated ") || ty.starts_with("optional ") || ty.starts_with("map<")
+}
+
+/// Whether any of the types of the file are or contain the unit type.
+fn uses_unit(data: &ParsedData) -> bool {
+    let is_unit = |ty: &RustType| ty.contains_type(SpecialRustType::Unit.id());
+    let field_is_unit = |field: &RustField| {
+        field.type_override(SupportedLanguage::Protobuf).is_none() && is_unit(&field.ty)
+    };
+
+    data.structs
+        .iter()
+        .any(|s| s.fields.iter().any(field_is_unit))
+        || data.aliases.iter().any(|a| is_unit(&a.r#type))
+        || data.enums.iter().any(|e| match e {
+            RustEnum::Unit(_) => false,
+            RustEnum::Algebraic { shared, .. } => shared.variants.iter().any(|v| match v {
+                RustEnumVariant::Unit(_) => true,
+                RustEnumVariant::Tuple { ty, .. } => is_unit(ty),
+                RustEnumVariant::AnonymousStruct { fields, .. } => fields.iter().any(field_is_unit),
+            }),
+        })
+}
+
+fn forbid_generics(generic_types: &[String]) -> io::Result<()> {
+    match generic_types.first() {
+        Some(generic) => Err(io::Error::other(
+            RustTypeFormatError::GenericsForbiddenInProtobuf(generic.clone()),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Write the numbers and names of removed members, with `name` giving the name
+/// of a member in the generated code.
+fn write_reserved(
+    w: &mut dyn Write,
+    indent: usize,
+    reserved: &[(String, u32)],
+    name: impl Fn(&str) -> String,
+) -> io::Result<()> {
+    if reserved.is_empty() {
+        return Ok(());
+    }
+    let prefix = "  ".repeat(indent);
+    writeln!(
+        w,
+        "{prefix}reserved {};",
+        reserved.iter().map(|(_, number)| number).join(", ")
+    )?;
+    writeln!(
+        w,
+        "{prefix}reserved {};",
+        reserved
+            .iter()
+            .map(|(member, _)| format!("\"{}\"", name(member)))
+            .join(", ")
+    )
+}
+
+fn write_comments(w: &mut dyn Write, indent: usize, comments: &[String]) -> io::Result<()> {
+    for comment in comments {
+        writeln!(w, "{}// {comment}", "  ".repeat(indent))?;
+    }
+    Ok(())
+}
+
+/// Escape a string literal.
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn locked(members: &[(&str, u32)]) -> BTreeMap<String, u32> {
+        members
+            .iter()
+            .map(|(member, number)| (member.to_string(), *number))
+            .collect()
+    }
+
+    #[test]
+    fn new_members_take_free_numbers() {
+        let mut numbers = locked(&[("removed", 1), ("kept", 3)]);
+        let numbered = assign_numbers(
+            &mut numbers,
+            "Message",
+            vec![("added", None), ("kept", None), ("tagged", Some(2))],
+            Numbering::Field,
+        )
+        .unwrap();
+
+        assert_eq!(numbered.numbers, [4, 3, 2]);
+        assert_eq!(numbered.reserved, [("removed".to_owned(), 1)]);
+        assert_eq!(
+            numbers,
+            locked(&[("added", 4), ("kept", 3), ("removed", 1), ("tagged", 2)])
+        );
+    }
+
+    #[test]
+    fn skips_implementation_reserved_numbers() {
+        let mut numbers = (1..=18_999)
+            .map(|number| (format!("field_{number}"), number))
+            .collect();
+        let numbered = assign_numbers(
+            &mut numbers,
+            "Message",
+            vec![("next", None)],
+            Numbering::Field,
+        )
+        .unwrap();
+
+        assert_eq!(numbered.numbers, [20_000]);
+    }
+
+    #[test]
+    fn refuses_to_renumber_or_reuse_numbers() {
+        let mut numbers = locked(&[("removed", 1), ("kept", 2)]);
+        for (members, error) in [
+            (
+                vec![("kept", Some(3))],
+                "`Message.kept` is numbered 2 in the lock file and can't be renumbered to 3",
+            ),
+            (
+                vec![("added", Some(1))],
+                "`Message.added` can't be numbered 1, which belongs to `removed`",
+            ),
+            (
+                vec![("first", Some(5)), ("second", Some(5))],
+                "`Message.second` can't be numbered 5, which belongs to `first`",
+            ),
+            (
+                vec![("added", Some(19_000))],
+                "`Message.added` can't be numbered 19000, which is not a valid number",
+            ),
+        ] {
+            let err = assign_numbers(&mut numbers, "Message", members, Numbering::Field)
+                .err()
+                .unwrap();
+            assert_eq!(err.to_string(), error);
+        }
+    }
+}
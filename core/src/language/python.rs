@@ -1,16 +1,18 @@
 use crate::parser::ParsedData;
 use crate::rust_types::{RustEnumShared, RustItem, RustType, RustTypeFormatError, SpecialRustType};
-use crate::topsort::topsort;
+use crate::topsort::sort_items;
+use crate::GenerationError;
 use crate::{
-    language::Language,
+    language::{format_mapped_type, mapped_type_name, Language, TypeMapping},
     rust_types::{RustEnum, RustEnumVariant, RustField, RustStruct, RustTypeAlias},
 };
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
 use std::hash::Hash;
+use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
-use std::{collections::HashMap, io::Write};
+use std::{collections::HashMap, fs, io::Write};
 
-use super::CrateTypes;
+use super::{used_imports, CrateName, CrateTypes};
 
 use convert_case::{Case, Casing};
 
@@ -69,7 +71,9 @@ fn dedup<T: Eq + Hash + Clone>(v: &mut Vec<T>) {
 #[derive(Default)]
 pub struct Python {
     /// Mappings from Rust type names to Python type names
-    pub type_mappings: HashMap<String, String>,
+    pub type_mappings: HashMap<String, TypeMapping>,
+    /// Imports needed by the mapped types, from Rust type names to the import of the type they map to.
+    pub type_mapping_imports: HashMap<String, String>,
     /// HashMap<ModuleName, HashSet<Identifier>
     pub imports: HashMap<String, HashSet<String>>,
     /// HashMap<Identifier, Vec<DependencyIdentifiers>>
@@ -80,24 +84,57 @@ pub struct Python {
     /// Whether or not to exclude the version header that normally appears at the top of generated code.
     /// If you aren't generating a snapshot test, this setting can just be left as a default (false)
     pub no_version_header: bool,
+    /// The paths to import the files of other crates from, relative to the file being generated.
+    pub import_paths: HashMap<CrateName, String>,
+    /// The directories of the files generated for multiple crates, relative to the output
+    /// folder. Each of them gets an `__init__.py` so the files can import each other.
+    pub package_dirs: BTreeSet<PathBuf>,
 }
 
 impl Language for Python {
-    fn type_map(&mut self) -> &HashMap<String, String> {
+    fn type_map(&mut self) -> &HashMap<String, TypeMapping> {
         &self.type_mappings
     }
+
+    fn type_map_imports(&mut self) -> Option<&HashMap<String, String>> {
+        Some(&self.type_mapping_imports)
+    }
     fn generate_types(
         &mut self,
         w: &mut dyn Write,
-        _imports: &CrateTypes,
+        all_types: &CrateTypes,
         data: ParsedData,
     ) -> std::io::Result<()> {
+        // Imports are collected while the file is generated, so start over for each file.
+        self.imports.clear();
+        self.type_variables.clear();
+
         self.begin_file(w, &data)?;
 
+        if data.multi_file {
+            for (crate_name, types) in used_imports(&data, all_types) {
+                let module = match self.import_paths.get(crate_name) {
+                    Some(path) => python_module(path),
+                    None => format!(".{crate_name}"),
+                };
+                for ty in types {
+                    self.add_import(module.clone(), ty.to_string());
+                }
+            }
+
+            let mut dir = Path::new(&data.file_name).parent();
+            while let Some(package_dir) = dir {
+                self.package_dirs.insert(package_dir.to_path_buf());
+                dir = package_dir.parent();
+            }
+        }
+
         let ParsedData {
             structs,
             enums,
             aliases,
+            source_positions,
+            type_ordering,
             ..
         } = data;
 
@@ -108,7 +145,8 @@ impl Language for Python {
             .chain(enums.into_iter().map(RustItem::Enum))
             .collect::<Vec<_>>();
 
-        topsort(&mut items);
+        // Classes can't refer to classes declared after them.
+        sort_items(&mut items, type_ordering, &source_positions, false);
 
         let mut body: Vec<u8> = Vec::new();
         for thing in items {
@@ -131,8 +169,9 @@ impl Language for Python {
         parameters: &[RustType],
         generic_types: &[String],
     ) -> Result<String, RustTypeFormatError> {
-        if let Some(mapped) = self.type_map().get(base) {
-            Ok(mapped.into())
+        if self.type_map().contains_key(base) {
+            self.add_imports(base);
+            format_mapped_type(self, base, parameters, generic_types)
         } else {
             let parameters: Result<Vec<String>, RustTypeFormatError> = parameters
                 .iter()
@@ -142,9 +181,11 @@ impl Language for Python {
             Ok(format!(
                 "{}{}",
                 self.format_simple_type(base, generic_types)?,
-                (!parameters.is_empty())
-                    .then(|| format!("[{}]", parameters.join(", ")))
-                    .unwrap_or_default()
+                if !parameters.is_empty() {
+                    format!("[{}]", parameters.join(", "))
+                } else {
+                    String::new()
+                }
             ))
         }
     }
@@ -156,7 +197,7 @@ impl Language for Python {
     ) -> Result<String, RustTypeFormatError> {
         self.add_imports(base);
         Ok(if let Some(mapped) = self.type_map().get(base) {
-            mapped.into()
+            mapped.apply(base, &[])?
         } else {
             base.into()
         })
@@ -226,15 +267,17 @@ impl Language for Python {
     fn write_type_alias(&mut self, w: &mut dyn Write, ty: &RustTypeAlias) -> std::io::Result<()> {
         let r#type = self
             .format_type(&ty.r#type, ty.generic_types.as_slice())
-            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+            .map_err(std::io::Error::other)?;
 
         writeln!(
             w,
             "{}{} = {}\n",
             ty.id.renamed,
-            (!ty.generic_types.is_empty())
-                .then(|| format!("[{}]", ty.generic_types.join(", ")))
-                .unwrap_or_default(),
+            if !ty.generic_types.is_empty() {
+                format!("[{}]", ty.generic_types.join(", "))
+            } else {
+                String::new()
+            },
             r#type,
         )?;
 
@@ -335,13 +378,47 @@ impl Language for Python {
         _writer: &mut dyn Write,
         _imports: super::ScopedCrateTypes<'_>,
     ) -> std::io::Result<()> {
-        // TODO: to implement when adding suport for outputting to multiple files.
+        // Imports of other crates are collected with all other imports in `generate_types`.
+        Ok(())
+    }
+
+    fn set_import_paths(&mut self, import_paths: HashMap<CrateName, String>) {
+        self.import_paths = import_paths;
+    }
+
+    fn post_generation(&self, output_folder: &str) -> Result<(), GenerationError> {
+        for package_dir in &self.package_dirs {
+            let init_file = Path::new(output_folder)
+                .join(package_dir)
+                .join("__init__.py");
+            if !init_file.exists() {
+                fs::write(&init_file, "").map_err(|err| {
+                    GenerationError::PostGeneration(format!("{init_file:?}: {err}"))
+                })?;
+            }
+        }
         Ok(())
     }
 }
 
+/// A relative import path like `../other/models` as a python module, like `..other.models`.
+fn python_module(import_path: &str) -> String {
+    let mut module = String::from(".");
+    let mut path = import_path.strip_prefix("./").unwrap_or(import_path);
+    while let Some(rest) = path.strip_prefix("../") {
+        module.push('.');
+        path = rest;
+    }
+    module + &path.replace('/', ".")
+}
+
 impl Python {
     fn add_imports(&mut self, tp: &str) {
+        if let Some(import) = self.type_mapping_imports.get(tp) {
+            let mapped = self.type_mappings.get(tp).map_or(tp, TypeMapping::as_str);
+            self.add_import(import.clone(), mapped_type_name(mapped).to_owned());
+            return;
+        }
         match tp {
             "Url" => {
                 self.add_import("pydantic.networks".to_string(), "AnyUrl".to_string());
@@ -364,7 +441,7 @@ impl Python {
         let not_optional_but_default = !field.ty.is_optional() && field.has_default;
         let python_type = self
             .format_type(&field.ty, generic_types)
-            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+            .map_err(std::io::Error::other)?;
         let python_field_name = python_property_aware_rename(&field.id.original);
         let is_aliased = python_field_name != field.id.renamed;
         match (not_optional_but_default, is_aliased) {
@@ -594,7 +671,7 @@ impl Python {
                 } => {
                     let tuple_name = self
                         .format_type(ty, shared.generic_types.as_slice())
-                        .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+                        .map_err(std::io::Error::other)?;
                     self.write_variant_class(
                         &variant_class_name,
                         tag_key,
@@ -704,6 +781,7 @@ mod test {
             has_default: true,
             comments: Default::default(),
             decorators: Default::default(),
+            proto_tag: None,
         };
         python.write_field(mock_writer, &rust_field, &[]).unwrap();
         assert_eq!(
@@ -728,6 +806,7 @@ mod test {
             has_default: false,
             comments: Default::default(),
             decorators: Default::default(),
+            proto_tag: None,
         };
         python.write_field(mock_writer, &rust_field, &[]).unwrap();
         assert_eq!(
@@ -754,6 +833,7 @@ mod test {
             has_default: true,
             comments: Default::default(),
             decorators: Default::default(),
+            proto_tag: None,
         };
         python.write_field(mock_writer, &rust_field, &[]).unwrap();
         assert_eq!(
@@ -778,6 +858,7 @@ mod test {
             has_default: false,
             comments: Default::default(),
             decorators: Default::default(),
+            proto_tag: None,
         };
         python.write_field(mock_writer, &rust_field, &[]).unwrap();
         assert_eq!(String::from_utf8_lossy(mock_writer), "    field: str\n");
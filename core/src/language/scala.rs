@@ -1,4 +1,4 @@
-use super::{CrateTypes, Language};
+use super::{used_type_map_imports, CrateTypes, Language, MappedImport, TypeMapping};
 use crate::language::SupportedLanguage;
 use crate::parser::{remove_dash_from_identifier, ParsedData};
 use crate::rust_types::{RustEnum, RustEnumVariant, RustField, RustStruct, RustTypeAlias};
@@ -7,7 +7,10 @@ use itertools::Itertools;
 use joinery::JoinableIterator;
 use lazy_format::lazy_format;
 use std::ops::Deref;
-use std::{collections::HashMap, io::Write};
+use std::{
+    collections::{BTreeSet, HashMap},
+    io::Write,
+};
 
 /// All information needed for Scala type-code
 #[derive(Default)]
@@ -17,7 +20,9 @@ pub struct Scala {
     /// Name of the Scala module
     pub module_name: String,
     /// Conversions from Rust type names to Scala type names.
-    pub type_mappings: HashMap<String, String>,
+    pub type_mappings: HashMap<String, TypeMapping>,
+    /// Imports needed by the mapped types, from Rust type names to the import of the type they map to.
+    pub type_mapping_imports: HashMap<String, String>,
     /// Whether or not to exclude the version header that normally appears at the top of generated code.
     /// If you aren't generating a snapshot test, this setting can just be left as a default (false)
     pub no_version_header: bool,
@@ -32,6 +37,11 @@ impl Language for Scala {
     ) -> std::io::Result<()> {
         self.begin_file(writable, &data)?;
 
+        let mapped_imports = used_type_map_imports(self, &data);
+        if !mapped_imports.is_empty() {
+            self.write_type_map_imports(writable, &mapped_imports)?;
+        }
+
         // Package object to hold type aliases: aliases must be in class or object in Scala 2)
         let unsigned_used = self.unsigned_integer_used(&data);
         if unsigned_used || !data.aliases.is_empty() {
@@ -61,10 +71,14 @@ impl Language for Scala {
         Ok(())
     }
 
-    fn type_map(&mut self) -> &HashMap<String, String> {
+    fn type_map(&mut self) -> &HashMap<String, TypeMapping> {
         &self.type_mappings
     }
 
+    fn type_map_imports(&mut self) -> Option<&HashMap<String, String>> {
+        Some(&self.type_mapping_imports)
+    }
+
     fn format_generic_parameters(&mut self, parameters: Vec<String>) -> String {
         format!("[{}]", parameters.into_iter().join(", "))
     }
@@ -140,11 +154,13 @@ impl Language for Scala {
             w,
             "type {}{} = {}\n",
             ty.id.original,
-            (!ty.generic_types.is_empty())
-                .then(|| format!("[{}]", ty.generic_types.join(", ")))
-                .unwrap_or_default(),
+            if !ty.generic_types.is_empty() {
+                format!("[{}]", ty.generic_types.join(", "))
+            } else {
+                String::new()
+            },
             self.format_type(&ty.r#type, ty.generic_types.as_slice())
-                .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?
+                .map_err(std::io::Error::other)?
         )?;
 
         Ok(())
@@ -158,9 +174,11 @@ impl Language for Scala {
                 w,
                 "case class {}{} (",
                 rs.id.renamed,
-                (!rs.generic_types.is_empty())
-                    .then(|| format!("[{}]", rs.generic_types.join(", ")))
-                    .unwrap_or_default()
+                if !rs.generic_types.is_empty() {
+                    format!("[{}]", rs.generic_types.join(", "))
+                } else {
+                    String::new()
+                }
             )?;
 
             if let Some((last, elements)) = rs.fields.split_last() {
@@ -186,9 +204,11 @@ impl Language for Scala {
 
         self.write_comments(w, 0, &e.shared().comments)?;
 
-        let generic_parameters = (!e.shared().generic_types.is_empty())
-            .then(|| format!("[{}]", e.shared().generic_types.join(", ")))
-            .unwrap_or_default();
+        let generic_parameters = if !e.shared().generic_types.is_empty() {
+            format!("[{}]", e.shared().generic_types.join(", "))
+        } else {
+            String::new()
+        };
 
         match e {
             RustEnum::Unit(shared) => {
@@ -214,6 +234,17 @@ impl Language for Scala {
         writeln!(w, "}}\n")
     }
 
+    fn write_type_map_imports(
+        &mut self,
+        w: &mut dyn Write,
+        imports: &BTreeSet<MappedImport>,
+    ) -> std::io::Result<()> {
+        for import in imports.iter().map(|import| &import.import).dedup() {
+            writeln!(w, "import {import}")?;
+        }
+        writeln!(w)
+    }
+
     fn write_imports(
         &mut self,
         _writer: &mut dyn Write,
@@ -278,13 +309,15 @@ impl Scala {
                                 w,
                                 "\tcase class {}{}(",
                                 variant_name,
-                                (!e.shared().generic_types.is_empty())
-                                    .then(|| format!("[{}]", e.shared().generic_types.join(", ")))
-                                    .unwrap_or_default()
+                                if !e.shared().generic_types.is_empty() {
+                                    format!("[{}]", e.shared().generic_types.join(", "))
+                                } else {
+                                    String::new()
+                                }
                             )?;
                             let variant_type = self
                                 .format_type(ty, e.shared().generic_types.as_slice())
-                                .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+                                .map_err(std::io::Error::other)?;
                             write!(w, "{}: {}", content_key, variant_type)?;
                             write!(w, ")")?;
                         }
@@ -293,9 +326,11 @@ impl Scala {
                                 w,
                                 "\tcase class {}{}(",
                                 variant_name,
-                                (!e.shared().generic_types.is_empty())
-                                    .then(|| format!("[{}]", e.shared().generic_types.join(", ")))
-                                    .unwrap_or_default()
+                                if !e.shared().generic_types.is_empty() {
+                                    format!("[{}]", e.shared().generic_types.join(", "))
+                                } else {
+                                    String::new()
+                                }
                             )?;
 
                             // Builds the list of generic types (e.g [T, U, V]), by digging
@@ -334,9 +369,11 @@ impl Scala {
                         w,
                         " extends {}{} {{",
                         e.shared().id.original,
-                        (!e.shared().generic_types.is_empty())
-                            .then(|| format!("[{}]", e.shared().generic_types.join(", ")))
-                            .unwrap_or_default()
+                        if !e.shared().generic_types.is_empty() {
+                            format!("[{}]", e.shared().generic_types.join(", "))
+                        } else {
+                            String::new()
+                        }
                     )?;
                     writeln!(w, "\t\tval serialName: String = {}", printed_value)?;
                     writeln!(w, "\t}}")?;
@@ -359,7 +396,7 @@ impl Scala {
             Some(type_override) => type_override.to_owned(),
             None => self
                 .format_type(&f.ty, generic_types)
-                .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?,
+                .map_err(std::io::Error::other)?,
         };
 
         write!(
@@ -1,7 +1,7 @@
 use crate::{
-    language::{Language, SupportedLanguage},
+    language::{Language, MappedImport, SupportedLanguage, TypeMapping},
     parser::{remove_dash_from_identifier, DecoratorKind, ParsedData},
-    rename::RenameExt,
+    rename::{FieldNaming, RenameExt},
     rust_types::{
         DecoratorMap, RustEnum, RustEnumVariant, RustStruct, RustTypeAlias, RustTypeFormatError,
         SpecialRustType,
@@ -138,7 +138,9 @@ pub struct Swift {
     /// The prefix to append to user-defined types
     pub prefix: String,
     /// Type mappings from Rust type names to Swift type names
-    pub type_mappings: HashMap<String, String>,
+    pub type_mappings: HashMap<String, TypeMapping>,
+    /// Imports needed by the mapped types, from Rust type names to the import of the type they map to.
+    pub type_mapping_imports: HashMap<String, String>,
     /// Default decorators that will be applied to all typeshared types
     pub default_decorators: Vec<String>,
     /// Default type constraints that will be applied to all generic parameters of typeshared types
@@ -154,20 +156,26 @@ pub struct Swift {
     pub multi_file: bool,
     /// The constraints to apply to `CodableVoid`.
     pub codablevoid_constraints: Vec<String>,
+    /// How to name struct properties. Keys that differ from the property name are kept with `CodingKeys`.
+    pub field_naming: FieldNaming,
 }
 
 impl Language for Swift {
-    fn type_map(&mut self) -> &HashMap<String, String> {
+    fn type_map(&mut self) -> &HashMap<String, TypeMapping> {
         &self.type_mappings
     }
 
+    fn type_map_imports(&mut self) -> Option<&HashMap<String, String>> {
+        Some(&self.type_mapping_imports)
+    }
+
     fn format_simple_type(
         &mut self,
         base: &String,
         generic_types: &[String],
     ) -> Result<String, RustTypeFormatError> {
         Ok(if let Some(mapped) = self.type_map().get(base) {
-            mapped.into()
+            mapped.apply(base, &[])?
         } else if generic_types.contains(base) {
             base.into()
         } else {
@@ -248,11 +256,13 @@ impl Language for Swift {
             w,
             "public typealias {}{} = {}",
             type_name,
-            (!ty.generic_types.is_empty())
-                .then(|| format!("<{}>", ty.generic_types.join(", ")))
-                .unwrap_or_default(),
+            if !ty.generic_types.is_empty() {
+                format!("<{}>", ty.generic_types.join(", "))
+            } else {
+                String::new()
+            },
             self.format_type(&ty.r#type, ty.generic_types.as_slice())
-                .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?
+                .map_err(std::io::Error::other)?
         )?;
 
         Ok(())
@@ -292,50 +302,51 @@ impl Language for Swift {
         writeln!(
             w,
             "public struct {type_name}{}: {} {{",
-            (!rs.generic_types.is_empty())
-                .then(|| format!("<{generic_names_and_constraints}>",))
-                .unwrap_or_default(),
+            if !rs.generic_types.is_empty() {
+                format!("<{generic_names_and_constraints}>",)
+            } else {
+                String::new()
+            },
             decs
         )?;
 
         for f in &rs.fields {
             self.write_comments(w, 1, &f.comments)?;
 
+            let name = self.field_naming.apply(&f.id.renamed);
+            let property = remove_dash_from_identifier(swift_keyword_aware_rename(&name).as_ref());
+
             // Create coding keys for serialization / deserialization
             //
-            // As of right now this was only written to handle fields
-            // that get renamed to an ident with - in it
-            if f.id.renamed.chars().any(|c| c == '-') {
-                coding_keys.push(format!(
-                    r##"{} = "{}""##,
-                    remove_dash_from_identifier(swift_keyword_aware_rename(&f.id.renamed).as_ref()),
-                    &f.id.renamed
-                ));
+            // They are needed when a property can't be named after its key,
+            // like when the key contains a - or the property is renamed.
+            if remove_dash_from_identifier(&name) != f.id.renamed {
+                coding_keys.push(format!(r##"{property} = "{}""##, &f.id.renamed));
 
                 // We only need to write out coding keys if we encounter a
                 // situation like this
                 should_write_coding_keys = true;
             } else {
-                coding_keys.push(remove_dash_from_identifier(
-                    swift_keyword_aware_rename(&f.id.renamed).as_ref(),
-                ));
+                coding_keys.push(property.clone());
             }
 
             let case_type: String = match f.type_override(SupportedLanguage::Swift) {
                 Some(type_override) => type_override.to_owned(),
                 None => self
                     .format_type(&f.ty, rs.generic_types.as_slice())
-                    .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?,
+                    .map_err(io::Error::other)?,
             };
 
             writeln!(
                 w,
                 "\tpublic let {}: {}{}",
-                remove_dash_from_identifier(swift_keyword_aware_rename(&f.id.renamed).as_ref()),
+                property,
                 case_type,
-                (f.has_default && !f.ty.is_optional())
-                    .then_some("?")
-                    .unwrap_or_default()
+                if f.has_default && !f.ty.is_optional() {
+                    "?"
+                } else {
+                    ""
+                }
             )?;
         }
 
@@ -360,26 +371,29 @@ impl Language for Swift {
                 Some(type_override) => type_override.to_owned(),
                 None => self
                     .format_type(&f.ty, rs.generic_types.as_slice())
-                    .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?,
+                    .map_err(io::Error::other)?,
             };
 
             init_params.push(format!(
                 "{}: {}{}",
-                remove_dash_from_identifier(&f.id.renamed),
+                remove_dash_from_identifier(&self.field_naming.apply(&f.id.renamed)),
                 swift_ty,
-                (f.has_default && !f.ty.is_optional())
-                    .then_some("?")
-                    .unwrap_or_default()
+                if f.has_default && !f.ty.is_optional() {
+                    "?"
+                } else {
+                    ""
+                }
             ));
         }
 
         write!(w, "\tpublic init({}) {{", init_params.join(", "))?;
         for f in &rs.fields {
+            let name = self.field_naming.apply(&f.id.renamed);
             write!(
                 w,
                 "\n\t\tself.{} = {}",
-                remove_dash_from_identifier(&f.id.renamed),
-                remove_dash_from_identifier(swift_keyword_aware_rename(&f.id.renamed).as_ref())
+                remove_dash_from_identifier(&name),
+                remove_dash_from_identifier(swift_keyword_aware_rename(&name).as_ref())
             )?;
         }
         if !rs.fields.is_empty() {
@@ -444,9 +458,11 @@ impl Language for Swift {
         writeln!(
             w,
             "public {indirect}enum {enum_name}{}: {} {{",
-            (!e.shared().generic_types.is_empty())
-                .then(|| format!("<{generic_names_and_constraints}>",))
-                .unwrap_or_default(),
+            if !e.shared().generic_types.is_empty() {
+                format!("<{generic_names_and_constraints}>",)
+            } else {
+                String::new()
+            },
             decs
         )?;
 
@@ -522,6 +538,23 @@ impl Language for Swift {
         Ok(())
     }
 
+    fn write_type_map_imports(
+        &mut self,
+        w: &mut dyn Write,
+        imports: &BTreeSet<MappedImport>,
+    ) -> io::Result<()> {
+        // Foundation is always imported.
+        for module in imports
+            .iter()
+            .map(|import| &import.import)
+            .dedup()
+            .filter(|module| *module != "Foundation")
+        {
+            writeln!(w, "import {module}")?;
+        }
+        Ok(())
+    }
+
     fn post_generation(&self, output_folder: &str) -> Result<(), GenerationError> {
         //
         if self.should_emit_codable_void.load(Ordering::SeqCst) && self.multi_file {
@@ -623,7 +656,7 @@ impl Swift {
                             let content_optional = ty.is_optional();
                             let case_type = self
                                 .format_type(ty, e.shared().generic_types.as_slice())
-                                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+                                .map_err(io::Error::other)?;
                             write!(w, "({})", swift_keyword_aware_rename(&case_type))?;
 
                             if content_optional {
@@ -0,0 +1,285 @@
+//! Type mappings that keep the generic parameters of the mapped type.
+//!
+//! A mapping such as `IndexMap = "Map<{0}, {1}>"` or `IndexMap = "Record<$K, $V>"`
+//! is split into literal text and parameter placeholders once, and each
+//! placeholder is then replaced with the already formatted generic parameter.
+//! Formatted parameters are never scanned for placeholders themselves.
+use crate::rust_types::RustTypeFormatError;
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, collections::HashMap, fmt};
+
+/// The target of a type mapping as written in a configuration file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum MappedType {
+    /// Only the type to map to, like `"Date"`.
+    Type(String),
+    /// The type to map to and what the generated code needs to import to use it,
+    /// like `{ type = "Instant", import = "kotlinx.datetime.Instant" }`.
+    WithImport {
+        /// The type to map to.
+        #[serde(rename = "type")]
+        ty: String,
+        /// The import the type needs.
+        import: String,
+    },
+}
+
+impl MappedType {
+    /// The type to map to.
+    pub fn ty(&self) -> &str {
+        match self {
+            Self::Type(ty) | Self::WithImport { ty, .. } => ty,
+        }
+    }
+
+    /// The import the type needs, if any.
+    pub fn import(&self) -> Option<&str> {
+        match self {
+            Self::Type(_) => None,
+            Self::WithImport { import, .. } => Some(import),
+        }
+    }
+
+    /// Split configured type mappings into the type mappings and the type mapping
+    /// imports of a language.
+    pub fn split(
+        mappings: &HashMap<String, MappedType>,
+    ) -> (HashMap<String, TypeMapping>, HashMap<String, String>) {
+        let types = mappings
+            .iter()
+            .map(|(name, mapped)| (name.clone(), TypeMapping::parse(mapped.ty())))
+            .collect();
+        let imports = mappings
+            .iter()
+            .filter_map(|(name, mapped)| Some((name.clone(), mapped.import()?.to_owned())))
+            .collect();
+        (types, imports)
+    }
+}
+
+impl From<&str> for MappedType {
+    fn from(ty: &str) -> Self {
+        Self::Type(ty.to_owned())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Literal(String),
+    Parameter(usize),
+    Named { index: usize, name: String },
+}
+
+/// A parsed type mapping.
+///
+/// Placeholders are either positional, like `{0}` for the first generic parameter,
+/// or named, like `$K`. Named placeholders are numbered in the order they first
+/// appear in the mapping, so in `Record<$K, $V>` `$K` is the first parameter and
+/// `$V` the second. Anything else, including braces without a number, is copied
+/// as is.
+///
+/// As `$` is common in type names, named placeholders are only filled in for
+/// types with generic parameters and in mappings without positional placeholders.
+/// Otherwise `Outer$Inner` stays `Outer$Inner`. A `$` that should always be kept
+/// is written as `$$`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeMapping {
+    source: String,
+    segments: Vec<Segment>,
+}
+
+impl TypeMapping {
+    /// Split a mapping into literal text and placeholders.
+    pub fn parse(mapping: &str) -> Self {
+        let mut segments = Vec::new();
+        let mut literal = String::new();
+        let mut names = Vec::<&str>::new();
+        let mut rest = mapping;
+
+        while let Some(c) = rest.chars().next() {
+            let placeholder = match c {
+                '{' => rest[1..].find('}').and_then(|end| {
+                    let index = &rest[1..end + 1];
+                    if !index.is_empty() && index.bytes().all(|b| b.is_ascii_digit()) {
+                        index
+                            .parse()
+                            .ok()
+                            .map(|index| (Segment::Parameter(index), end + 2))
+                    } else {
+                        None
+                    }
+                }),
+                '$' if rest[1..].starts_with('$') => {
+                    literal.push('$');
+                    rest = &rest[2..];
+                    continue;
+                }
+                '$' => {
+                    let len = rest[1..]
+                        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                        .unwrap_or(rest.len() - 1);
+                    let name = &rest[1..len + 1];
+                    if name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+                        let index = names.iter().position(|n| *n == name).unwrap_or_else(|| {
+                            names.push(name);
+                            names.len() - 1
+                        });
+                        let name = name.to_owned();
+                        Some((Segment::Named { index, name }, len + 1))
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            };
+
+            match placeholder {
+                Some((segment, len)) => {
+                    if !literal.is_empty() {
+                        segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                    }
+                    segments.push(segment);
+                    rest = &rest[len..];
+                }
+                None => {
+                    literal.push(c);
+                    rest = &rest[c.len_utf8()..];
+                }
+            }
+        }
+        if !literal.is_empty() {
+            segments.push(Segment::Literal(literal));
+        }
+
+        // Mappings with positional placeholders use `$` literally.
+        if segments
+            .iter()
+            .any(|segment| matches!(segment, Segment::Parameter(_)))
+        {
+            for segment in &mut segments {
+                if let Segment::Named { name, .. } = segment {
+                    *segment = Segment::Literal(format!("${name}"));
+                }
+            }
+        }
+
+        Self {
+            source: mapping.to_owned(),
+            segments,
+        }
+    }
+
+    /// The mapping as it was written.
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// Whether the mapping uses any of the generic parameters.
+    pub fn has_parameters(&self) -> bool {
+        self.segments
+            .iter()
+            .any(|segment| matches!(segment, Segment::Parameter(_) | Segment::Named { .. }))
+    }
+
+    /// Fill in the formatted generic parameters of the mapped type `base`.
+    pub fn apply(&self, base: &str, parameters: &[String]) -> Result<String, RustTypeFormatError> {
+        self.segments
+            .iter()
+            .map(|segment| match segment {
+                Segment::Literal(literal) => Ok(Cow::Borrowed(literal.as_str())),
+                Segment::Named { name, .. } if parameters.is_empty() => {
+                    Ok(Cow::Owned(format!("${name}")))
+                }
+                Segment::Parameter(index) | Segment::Named { index, .. } => parameters
+                    .get(*index)
+                    .map(Cow::from)
+                    .ok_or_else(|| RustTypeFormatError::MissingMappingParameter {
+                        base: base.to_owned(),
+                        index: *index,
+                        count: parameters.len(),
+                    }),
+            })
+            .collect()
+    }
+}
+
+impl From<&str> for TypeMapping {
+    fn from(mapping: &str) -> Self {
+        Self::parse(mapping)
+    }
+}
+
+impl From<String> for TypeMapping {
+    fn from(mapping: String) -> Self {
+        Self::parse(&mapping)
+    }
+}
+
+impl fmt::Display for TypeMapping {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::TypeMapping;
+
+    fn apply(mapping: &str, parameters: &[&str]) -> String {
+        let parameters = parameters.iter().map(|p| p.to_string()).collect::<Vec<_>>();
+        TypeMapping::parse(mapping)
+            .apply("Base", &parameters)
+            .unwrap()
+    }
+
+    #[test]
+    fn fills_in_placeholders() {
+        assert_eq!(
+            apply("Map<{0}, {1}>", &["string", "Foo"]),
+            "Map<string, Foo>"
+        );
+        assert_eq!(
+            apply("Record<$K, $V>", &["string", "Foo"]),
+            "Record<string, Foo>"
+        );
+        assert_eq!(apply("Pair<$V, $K, $V>", &["A", "B"]), "Pair<A, B, A>");
+        assert_eq!(apply("{1}[]", &["unused", "number"]), "number[]");
+    }
+
+    #[test]
+    fn keeps_other_text() {
+        assert_eq!(
+            apply("{ [key: {0}]: {1} }", &["string", "Foo"]),
+            "{ [key: string]: Foo }"
+        );
+        assert_eq!(apply("Price$", &[]), "Price$");
+        assert_eq!(apply("Map", &["string", "Foo"]), "Map");
+        assert!(!TypeMapping::parse("{x} $1 {}").has_parameters());
+    }
+
+    #[test]
+    fn keeps_dollar_names_without_parameters() {
+        assert_eq!(apply("Outer$Inner", &[]), "Outer$Inner");
+        assert_eq!(apply("$Root.Date", &[]), "$Root.Date");
+        assert_eq!(apply("Outer$Inner<{0}>", &["T"]), "Outer$Inner<T>");
+        assert_eq!(
+            apply("Outer$$Inner<$T>", &["number"]),
+            "Outer$Inner<number>"
+        );
+        assert_eq!(apply("Outer$$Inner", &[]), "Outer$Inner");
+    }
+
+    #[test]
+    fn does_not_expand_parameters() {
+        assert_eq!(apply("Box<{0}, {1}>", &["{1}", "$V"]), "Box<{1}, $V>");
+    }
+
+    #[test]
+    fn reports_missing_parameters() {
+        assert!(TypeMapping::parse("Map<{0}, {1}>")
+            .apply("IndexMap", &["string".to_owned()])
+            .is_err());
+    }
+}
@@ -1,35 +1,102 @@
 use crate::{
-    language::{Language, SupportedLanguage},
+    language::{CrateName, Language, MappedImport, SupportedLanguage, TypeMapping},
     parser::ParsedData,
+    rename::RenameExt,
     rust_types::{
-        RustEnum, RustEnumVariant, RustField, RustStruct, RustType, RustTypeAlias,
+        FieldDecorator, RustEnum, RustEnumVariant, RustField, RustStruct, RustType, RustTypeAlias,
         RustTypeFormatError, SpecialRustType,
     },
 };
 use itertools::Itertools;
 use joinery::JoinableIterator;
+use serde::{Deserialize, Serialize};
 use std::{
-    collections::HashMap,
+    collections::{BTreeSet, HashMap, HashSet},
     io::{self, Write},
 };
 
 use super::ScopedCrateTypes;
 
+/// Parses the `null` that serde writes for `None` as `undefined`, which is how
+/// TypeScript types have it.
+const NULL_AS_UNDEFINED: &str = ".transform((value) => value ?? undefined)";
+
 /// All information needed to generate Typescript type-code
 #[derive(Default)]
 pub struct TypeScript {
     /// Mappings from Rust type names to Typescript type names
-    pub type_mappings: HashMap<String, String>,
+    pub type_mappings: HashMap<String, TypeMapping>,
+    /// Imports needed by the mapped types, from Rust type names to the import of the type they map to.
+    pub type_mapping_imports: HashMap<String, String>,
     /// Whether or not to exclude the version header that normally appears at the top of generated code.
     /// If you aren't generating a snapshot test, this setting can just be left as a default (false)
     pub no_version_header: bool,
+    /// The paths to import the files of other crates from, relative to the file being generated.
+    pub import_paths: HashMap<CrateName, String>,
+    /// Whether to write [Zod](https://zod.dev) schemas to validate the types at runtime.
+    pub zod: ZodSchemas,
+    /// The Zod schemas of mapped types, from Rust type names, like `z.coerce.date()`,
+    /// with `$0`, `$1`… for the schemas of generic parameters. Other mapped types are
+    /// checked if they map to `string`, `number` or `boolean`, and are `z.unknown()`
+    /// otherwise.
+    pub zod_type_mappings: HashMap<String, TypeMapping>,
+    /// Whether to write type guards, constructors and a `match` function for every
+    /// variant of algebraic enums.
+    pub helpers: bool,
+    /// How to write unit enums.
+    pub unit_enums: UnitEnumStyle,
+    /// The types of the file being generated whose schemas haven't been written yet.
+    pub undeclared_schemas: HashSet<String>,
+}
+
+/// How unit enums are written. Whatever the style, the type of a unit enum has the
+/// name of the enum.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum UnitEnumStyle {
+    /// An `enum`, which is the default.
+    #[default]
+    Enum,
+    /// A `const enum`, whose values are inlined where they are used.
+    ConstEnum,
+    /// A union of string literals, like `"red" | "green"`.
+    Union,
+    /// A union of string literals, and an object of the values with `as const`,
+    /// like `{ Red: "red", Green: "green" } as const`, for using them like an enum.
+    ConstObject,
+}
+
+/// Whether to write Zod schemas, like `UserSchema` for `User`, along with the types.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ZodSchemas {
+    /// Only types are written, which is the default.
+    #[default]
+    Off,
+    /// Schemas are written after the types.
+    Alongside,
+    /// Schemas are written instead of interfaces and type aliases, which are
+    /// inferred from the schemas with `z.infer`. Generic types can't be inferred,
+    /// so they keep theirs.
+    Instead,
 }
 
 impl Language for TypeScript {
-    fn type_map(&mut self) -> &HashMap<String, String> {
+    fn type_map(&mut self) -> &HashMap<String, TypeMapping> {
         &self.type_mappings
     }
 
+    /// Schemas can't be used before they are declared.
+    fn forward_references(&self) -> bool {
+        self.zod == ZodSchemas::Off
+    }
+
+    fn type_map_imports(&mut self) -> Option<&HashMap<String, String>> {
+        Some(&self.type_mapping_imports)
+    }
+
     fn format_special_type(
         &mut self,
         special_ty: &SpecialRustType,
@@ -43,9 +110,7 @@ impl Language for TypeScript {
                 let formatted_type = self.format_type(rtype, generic_types)?;
                 Ok(format!(
                     "[{}]",
-                    std::iter::repeat(&formatted_type)
-                        .take(*len)
-                        .join_with(", ")
+                    std::iter::repeat_n(&formatted_type, *len).join_with(", ")
                 ))
             }
             SpecialRustType::Slice(rtype) => {
@@ -86,91 +151,233 @@ impl Language for TypeScript {
         }
     }
 
-    fn begin_file(&mut self, w: &mut dyn Write, _parsed_data: &ParsedData) -> io::Result<()> {
+    fn begin_file(&mut self, w: &mut dyn Write, parsed_data: &ParsedData) -> io::Result<()> {
         if !self.no_version_header {
             writeln!(w, "/*")?;
             writeln!(w, " Generated by typeshare {}", env!("CARGO_PKG_VERSION"))?;
             writeln!(w, "*/")?;
             writeln!(w)?;
         }
+        if self.zod != ZodSchemas::Off {
+            writeln!(w, "import {{ z }} from \"zod\";")?;
+            writeln!(w)?;
+            self.undeclared_schemas = parsed_data
+                .structs
+                .iter()
+                .map(|s| &s.id)
+                .chain(parsed_data.enums.iter().map(|e| &e.shared().id))
+                .chain(parsed_data.aliases.iter().map(|a| &a.id))
+                .map(|id| id.renamed.clone())
+                .collect();
+        }
         Ok(())
     }
 
     fn write_type_alias(&mut self, w: &mut dyn Write, ty: &RustTypeAlias) -> io::Result<()> {
-        self.write_comments(w, 0, &ty.comments)?;
+        let schema = match self.zod {
+            ZodSchemas::Off => None,
+            _ => Some(
+                self.zod_property(None, &ty.r#type, false, &ty.generic_types)
+                    .map_err(io::Error::other)?,
+            ),
+        };
+        let inferred = self.infers_type(&ty.generic_types, schema.as_deref());
 
-        let r#type = self
-            .format_type(&ty.r#type, ty.generic_types.as_slice())
-            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        if !inferred {
+            self.write_comments(w, 0, &ty.comments)?;
 
-        writeln!(
-            w,
-            "export type {}{} = {}{};\n",
-            ty.id.renamed,
-            (!ty.generic_types.is_empty())
-                .then(|| format!("<{}>", ty.generic_types.join(", ")))
-                .unwrap_or_default(),
-            r#type,
-            ty.r#type
-                .is_optional()
-                .then_some(" | undefined")
-                .unwrap_or_default(),
-        )?;
+            let r#type = self
+                .format_type(&ty.r#type, ty.generic_types.as_slice())
+                .map_err(io::Error::other)?;
+
+            writeln!(
+                w,
+                "export type {}{} = {}{};\n",
+                ty.id.renamed,
+                if !ty.generic_types.is_empty() {
+                    format!("<{}>", ty.generic_types.join(", "))
+                } else {
+                    String::new()
+                },
+                r#type,
+                if ty.r#type.is_optional() {
+                    " | undefined"
+                } else {
+                    ""
+                },
+            )?;
+        }
+
+        if let Some(schema) = schema {
+            self.write_schema(
+                w,
+                &ty.id.renamed,
+                &ty.generic_types,
+                &ty.comments,
+                inferred,
+                schema,
+            )?;
+        }
 
         Ok(())
     }
 
     fn write_struct(&mut self, w: &mut dyn Write, rs: &RustStruct) -> io::Result<()> {
-        self.write_comments(w, 0, &rs.comments)?;
-        writeln!(
-            w,
-            "export interface {}{} {{",
-            rs.id.renamed,
-            (!rs.generic_types.is_empty())
-                .then(|| format!("<{}>", rs.generic_types.join(", ")))
-                .unwrap_or_default()
-        )?;
+        let schema = match self.zod {
+            ZodSchemas::Off => None,
+            _ => {
+                let mut schema = Vec::new();
+                writeln!(schema, "z.object({{")?;
+                rs.fields.iter().try_for_each(|f| {
+                    self.write_field_schema(&mut schema, 1, f, &rs.generic_types)
+                })?;
+                write!(schema, "}})")?;
+                Some(String::from_utf8(schema).map_err(io::Error::other)?)
+            }
+        };
+        let inferred = self.infers_type(&rs.generic_types, schema.as_deref());
 
-        rs.fields
-            .iter()
-            .try_for_each(|f| self.write_field(w, f, rs.generic_types.as_slice()))?;
+        if !inferred {
+            self.write_comments(w, 0, &rs.comments)?;
+            writeln!(
+                w,
+                "export interface {}{} {{",
+                rs.id.renamed,
+                if !rs.generic_types.is_empty() {
+                    format!("<{}>", rs.generic_types.join(", "))
+                } else {
+                    String::new()
+                }
+            )?;
 
-        writeln!(w, "}}\n")
+            rs.fields
+                .iter()
+                .try_for_each(|f| self.write_field(w, f, rs.generic_types.as_slice()))?;
+
+            writeln!(w, "}}\n")?;
+        }
+
+        if let Some(schema) = schema {
+            self.write_schema(
+                w,
+                &rs.id.renamed,
+                &rs.generic_types,
+                &rs.comments,
+                inferred,
+                schema,
+            )?;
+        }
+
+        Ok(())
     }
 
     fn write_enum(&mut self, w: &mut dyn Write, e: &RustEnum) -> io::Result<()> {
-        self.write_comments(w, 0, &e.shared().comments)?;
+        let shared = e.shared();
+        let schema = match self.zod {
+            ZodSchemas::Off => None,
+            _ => {
+                let mut schema = Vec::new();
+                self.write_enum_schema(&mut schema, e)?;
+                Some(String::from_utf8(schema).map_err(io::Error::other)?)
+            }
+        };
+        // Unit enums keep their types, which their schemas may be made of.
+        let inferred = match e {
+            RustEnum::Unit(_) => false,
+            RustEnum::Algebraic { .. } => {
+                self.infers_type(&shared.generic_types, schema.as_deref())
+            }
+        };
 
-        let generic_parameters = (!e.shared().generic_types.is_empty())
-            .then(|| format!("<{}>", e.shared().generic_types.join(", ")))
-            .unwrap_or_default();
+        if !inferred {
+            self.write_comments(w, 0, &shared.comments)?;
 
-        match e {
-            RustEnum::Unit(shared) => {
-                write!(
-                    w,
-                    "export enum {}{} {{",
-                    shared.id.renamed, generic_parameters
-                )?;
+            let generic_parameters = if !shared.generic_types.is_empty() {
+                format!("<{}>", shared.generic_types.join(", "))
+            } else {
+                String::new()
+            };
 
-                self.write_enum_variants(w, e)?;
+            match e {
+                RustEnum::Unit(shared) => match self.unit_enums {
+                    UnitEnumStyle::Enum | UnitEnumStyle::ConstEnum => {
+                        write!(
+                            w,
+                            "export {}enum {}{} {{",
+                            if self.unit_enums == UnitEnumStyle::ConstEnum {
+                                "const "
+                            } else {
+                                ""
+                            },
+                            shared.id.renamed,
+                            generic_parameters
+                        )?;
 
-                writeln!(w, "\n}}\n")
-            }
-            RustEnum::Algebraic { shared, .. } => {
-                write!(
-                    w,
-                    "export type {}{} = ",
-                    shared.id.renamed, generic_parameters
-                )?;
+                        self.write_enum_variants(w, e)?;
+
+                        writeln!(w, "\n}}\n")?;
+                    }
+                    UnitEnumStyle::Union | UnitEnumStyle::ConstObject => {
+                        write!(
+                            w,
+                            "export type {}{} = ",
+                            shared.id.renamed, generic_parameters
+                        )?;
 
-                self.write_enum_variants(w, e)?;
+                        match shared.variants.is_empty() {
+                            true => write!(w, "never")?,
+                            false => self.write_enum_variants(w, e)?,
+                        }
 
-                write!(w, ";")?;
-                writeln!(w)?;
-                writeln!(w)
+                        writeln!(w, ";\n")?;
+
+                        if self.unit_enums == UnitEnumStyle::ConstObject {
+                            writeln!(w, "export const {} = {{", shared.id.renamed)?;
+                            for variant in &shared.variants {
+                                let variant = variant.shared();
+                                self.write_comments(w, 1, &variant.comments)?;
+                                writeln!(
+                                    w,
+                                    "\t{}: {:?},",
+                                    variant.id.original, variant.id.renamed
+                                )?;
+                            }
+                            writeln!(w, "}} as const;\n")?;
+                        }
+                    }
+                },
+                RustEnum::Algebraic { shared, .. } => {
+                    write!(
+                        w,
+                        "export type {}{} = ",
+                        shared.id.renamed, generic_parameters
+                    )?;
+
+                    self.write_enum_variants(w, e)?;
+
+                    write!(w, ";")?;
+                    writeln!(w)?;
+                    writeln!(w)?;
+                }
             }
         }
+
+        if let Some(schema) = schema {
+            self.write_schema(
+                w,
+                &shared.id.renamed,
+                &shared.generic_types,
+                &shared.comments,
+                inferred,
+                schema,
+            )?;
+        }
+
+        if self.helpers {
+            self.write_enum_helpers(w, e)?;
+        }
+
+        Ok(())
     }
 
     fn write_imports(
@@ -178,11 +385,33 @@ impl Language for TypeScript {
         w: &mut dyn Write,
         imports: ScopedCrateTypes<'_>,
     ) -> std::io::Result<()> {
-        for (path, ty) in imports {
+        for (crate_name, ty) in imports {
             write!(w, "import {{ ")?;
-            let ty_list = ty.iter().join(", ");
+            let ty_list = match self.zod {
+                ZodSchemas::Off => ty.iter().join(", "),
+                _ => ty.iter().map(|ty| format!("{ty}, {ty}Schema")).join(", "),
+            };
             write!(w, "{ty_list}")?;
-            writeln!(w, " }} from \"./{path}\";")?;
+            match self.import_paths.get(crate_name) {
+                Some(path) => writeln!(w, " }} from \"{path}\";")?,
+                None => writeln!(w, " }} from \"./{crate_name}\";")?,
+            }
+        }
+        writeln!(w)
+    }
+
+    fn set_import_paths(&mut self, import_paths: HashMap<CrateName, String>) {
+        self.import_paths = import_paths;
+    }
+
+    fn write_type_map_imports(
+        &mut self,
+        w: &mut dyn Write,
+        imports: &BTreeSet<MappedImport>,
+    ) -> std::io::Result<()> {
+        for (module, imports) in &imports.iter().group_by(|import| &import.import) {
+            let names = imports.map(|import| &import.type_name).join(", ");
+            writeln!(w, "import type {{ {names} }} from \"{module}\";")?;
         }
         writeln!(w)
     }
@@ -193,6 +422,408 @@ impl Language for TypeScript {
 }
 
 impl TypeScript {
+    /// Write the type guards and constructors of the variants of an algebraic enum,
+    /// and a function calling the case of the variant of a value, like a `match`.
+    fn write_enum_helpers(&mut self, w: &mut dyn Write, e: &RustEnum) -> io::Result<()> {
+        let RustEnum::Algebraic {
+            tag_key,
+            content_key,
+            shared,
+        } = e
+        else {
+            return Ok(());
+        };
+        if shared.variants.is_empty() {
+            return Ok(());
+        }
+
+        let generic_parameters = if !shared.generic_types.is_empty() {
+            format!("<{}>", shared.generic_types.join(", "))
+        } else {
+            String::new()
+        };
+        let name = format!("{}{generic_parameters}", shared.id.renamed);
+        let tag_property = typescript_property_aware_rename(tag_key);
+        let tag_access = typescript_property_access(tag_key);
+        let content_property = typescript_property_aware_rename(content_key);
+        let content_access = typescript_property_access(content_key);
+
+        // The type of the content of each variant, if it has any.
+        let mut contents = Vec::with_capacity(shared.variants.len());
+        for variant in &shared.variants {
+            contents.push(match variant {
+                RustEnumVariant::Unit(_) => None,
+                RustEnumVariant::Tuple { ty, .. } => Some(format!(
+                    "{}{}",
+                    self.format_type(ty, &shared.generic_types)
+                        .map_err(io::Error::other)?,
+                    if ty.is_optional() { " | undefined" } else { "" }
+                )),
+                RustEnumVariant::AnonymousStruct {
+                    shared: variant, ..
+                } => Some(format!(
+                    "Extract<{name}, {{ {tag_property}: {:?} }}>[{content_key:?}]",
+                    variant.id.renamed
+                )),
+            });
+        }
+
+        for (variant, content) in shared.variants.iter().zip(&contents) {
+            let variant = variant.shared();
+            let function_name = format!("{}{}", shared.id.renamed, variant.id.original);
+            let tag = format!("{:?}", variant.id.renamed);
+            writeln!(
+                w,
+                "export function is{function_name}{generic_parameters}(value: {name}): value is Extract<{name}, {{ {tag_property}: {tag} }}> {{"
+            )?;
+            writeln!(w, "\treturn value{tag_access} === {tag};")?;
+            writeln!(w, "}}\n")?;
+
+            let function_name = function_name.to_camel_case();
+            match content {
+                None => {
+                    writeln!(
+                        w,
+                        "export function {function_name}{generic_parameters}(): {name} {{"
+                    )?;
+                    writeln!(w, "\treturn {{ {tag_property}: {tag} }};")?;
+                }
+                Some(content) => {
+                    writeln!(
+                        w,
+                        "export function {function_name}{generic_parameters}(content: {content}): {name} {{"
+                    )?;
+                    let content = match content_property.as_str() {
+                        "content" => "content".to_owned(),
+                        property => format!("{property}: content"),
+                    };
+                    writeln!(w, "\treturn {{ {tag_property}: {tag}, {content} }};")?;
+                }
+            }
+            writeln!(w, "}}\n")?;
+        }
+
+        let result = match shared.generic_types.contains(&"R".to_owned()) {
+            true => "Result",
+            false => "R",
+        };
+        let match_parameters = shared
+            .generic_types
+            .iter()
+            .map(String::as_str)
+            .chain([result])
+            .join(", ");
+        writeln!(
+            w,
+            "export function match{}<{match_parameters}>(value: {name}, cases: {{",
+            shared.id.renamed
+        )?;
+        for (variant, content) in shared.variants.iter().zip(&contents) {
+            let parameter = content
+                .as_ref()
+                .map(|content| format!("content: {content}"))
+                .unwrap_or_default();
+            writeln!(
+                w,
+                "\t{}: ({parameter}) => {result};",
+                variant.shared().id.original
+            )?;
+        }
+        writeln!(w, "}}): {result} {{")?;
+        writeln!(w, "\tswitch (value{tag_access}) {{")?;
+        for (variant, content) in shared.variants.iter().zip(&contents) {
+            let variant = variant.shared();
+            let argument = if content.is_some() {
+                format!("value{content_access}")
+            } else {
+                String::new()
+            };
+            writeln!(w, "\t\tcase {:?}:", variant.id.renamed)?;
+            writeln!(w, "\t\t\treturn cases.{}({argument});", variant.id.original)?;
+        }
+        writeln!(w, "\t}}")?;
+        writeln!(w, "}}\n")
+    }
+
+    /// Whether a type is only inferred from its schema, instead of being written as
+    /// a TypeScript type.
+    fn infers_type(&self, generic_types: &[String], schema: Option<&str>) -> bool {
+        self.zod == ZodSchemas::Instead
+            && generic_types.is_empty()
+            // TypeScript can't infer the type of a schema that depends on itself.
+            && schema.is_some_and(|schema| !schema.contains("z.lazy("))
+    }
+
+    /// Write the schema of a type, and the type inferred from it unless there's a
+    /// TypeScript type for it. Generic types have functions taking the schemas of
+    /// their parameters instead.
+    fn write_schema(
+        &mut self,
+        w: &mut dyn Write,
+        name: &str,
+        generic_types: &[String],
+        comments: &[String],
+        inferred: bool,
+        schema: String,
+    ) -> io::Result<()> {
+        if inferred {
+            self.write_comments(w, 0, comments)?;
+        }
+        write!(w, "export const {name}Schema")?;
+        if !generic_types.is_empty() {
+            write!(
+                w,
+                " = <{}>({})",
+                generic_types
+                    .iter()
+                    .map(|ty| format!("{ty} extends z.ZodTypeAny"))
+                    .join(", "),
+                generic_types
+                    .iter()
+                    .map(|ty| format!("{ty}: {ty}"))
+                    .join(", ")
+            )?;
+        }
+        // TypeScript can't infer the type of a schema that depends on itself, so it
+        // is given the TypeScript type, which recursive types always have, and checked
+        // against it. The input is left unknown, since `None`s are parsed from `null`s.
+        // The objects of generic schemas can't be checked against a generic type.
+        if schema.contains("z.lazy(") {
+            match generic_types.is_empty() {
+                true => write!(w, ": z.ZodType<{name}, z.ZodTypeDef, unknown>")?,
+                false => write!(w, ": z.ZodTypeAny")?,
+            }
+        }
+        match generic_types.is_empty() {
+            true => writeln!(w, " = {schema};")?,
+            false => writeln!(w, " => {schema};")?,
+        }
+        if inferred {
+            writeln!(w, "export type {name} = z.infer<typeof {name}Schema>;")?;
+        }
+        self.undeclared_schemas.remove(name);
+        writeln!(w)
+    }
+
+    fn write_enum_schema(&mut self, w: &mut dyn Write, e: &RustEnum) -> io::Result<()> {
+        let (tag_key, content_key, shared) = match e {
+            RustEnum::Unit(shared) if shared.variants.is_empty() => return write!(w, "z.never()"),
+            RustEnum::Unit(shared) => {
+                let values = shared.variants.iter().map(|v| &v.shared().id);
+                return match self.unit_enums {
+                    UnitEnumStyle::Enum | UnitEnumStyle::ConstObject => {
+                        write!(w, "z.nativeEnum({})", shared.id.renamed)
+                    }
+                    // Const enums don't exist at runtime, but their values are inlined.
+                    UnitEnumStyle::ConstEnum => write!(
+                        w,
+                        "z.enum([{}])",
+                        values
+                            .map(|id| format!("{}.{}", shared.id.renamed, id.original))
+                            .join(", ")
+                    ),
+                    UnitEnumStyle::Union => write!(
+                        w,
+                        "z.enum([{}])",
+                        values.map(|id| format!("{:?}", id.renamed)).join(", ")
+                    ),
+                };
+            }
+            RustEnum::Algebraic {
+                tag_key,
+                content_key,
+                shared,
+            } => (tag_key, content_key, shared),
+        };
+        if shared.variants.is_empty() {
+            return write!(w, "z.never()");
+        }
+
+        writeln!(w, "z.discriminatedUnion({tag_key:?}, [")?;
+        let tag_key = typescript_property_aware_rename(tag_key);
+        let content_key = typescript_property_aware_rename(content_key);
+        for variant in &shared.variants {
+            self.write_comments(w, 1, &variant.shared().comments)?;
+            let tag = format!("{tag_key}: z.literal({:?})", variant.shared().id.renamed);
+            match variant {
+                RustEnumVariant::Unit(_) => writeln!(w, "\tz.object({{ {tag} }}),")?,
+                RustEnumVariant::Tuple { ty, .. } => {
+                    let content = self
+                        .zod_property(None, ty, false, &shared.generic_types)
+                        .map_err(io::Error::other)?;
+                    writeln!(w, "\tz.object({{ {tag}, {content_key}: {content} }}),")?
+                }
+                RustEnumVariant::AnonymousStruct { fields, .. } => {
+                    writeln!(w, "\tz.object({{ {tag}, {content_key}: z.object({{")?;
+                    fields.iter().try_for_each(|f| {
+                        self.write_field_schema(w, 2, f, &shared.generic_types)
+                    })?;
+                    writeln!(w, "\t}}) }}),")?
+                }
+            }
+        }
+        write!(w, "])")
+    }
+
+    fn write_field_schema(
+        &mut self,
+        w: &mut dyn Write,
+        indent: usize,
+        field: &RustField,
+        generic_types: &[String],
+    ) -> io::Result<()> {
+        self.write_comments(w, indent, &field.comments)?;
+        let schema = self
+            .zod_property(
+                zod_override(field),
+                &field.ty,
+                field.has_default,
+                generic_types,
+            )
+            .map_err(io::Error::other)?;
+        writeln!(
+            w,
+            "{}{}: {schema},",
+            "\t".repeat(indent),
+            typescript_property_aware_rename(&field.id.renamed)
+        )
+    }
+
+    /// The schema of a property, which serde leaves out or writes as `null` when it's `None`.
+    fn zod_property(
+        &mut self,
+        schema_override: Option<&str>,
+        ty: &RustType,
+        has_default: bool,
+        generic_types: &[String],
+    ) -> Result<String, RustTypeFormatError> {
+        let mut inner = ty;
+        while let RustType::Special(SpecialRustType::Option(ty)) = inner {
+            inner = ty;
+        }
+        let schema = match schema_override {
+            Some(schema) => schema.to_owned(),
+            None => self.zod_schema(inner, generic_types)?,
+        };
+        Ok(if ty.is_double_optional() {
+            // The type keeps the `null` of `Some(None)`.
+            format!("{schema}.nullish()")
+        } else if ty.is_optional() {
+            format!("{schema}.nullish(){NULL_AS_UNDEFINED}")
+        } else if has_default {
+            format!("{schema}.optional()")
+        } else {
+            schema
+        })
+    }
+
+    /// The Zod schema of a type.
+    fn zod_schema(
+        &mut self,
+        ty: &RustType,
+        generic_types: &[String],
+    ) -> Result<String, RustTypeFormatError> {
+        let (id, parameters) = match ty {
+            RustType::Special(special_ty) => {
+                return self.zod_special_schema(special_ty, generic_types)
+            }
+            RustType::Simple { id } => (id, [].as_slice()),
+            RustType::Generic { id, parameters } => (id, parameters.as_slice()),
+        };
+        if self.type_mappings.contains_key(id) {
+            if let Some(mapping) = self.zod_type_mappings.get(id).cloned() {
+                let parameters = parameters
+                    .iter()
+                    .map(|p| self.zod_schema(p, generic_types))
+                    .collect::<Result<Vec<_>, _>>()?;
+                return mapping.apply(id, &parameters);
+            }
+            // Without a schema, mapped types can only be checked if they're primitives.
+            return Ok(match self.format_type(ty, generic_types)?.as_str() {
+                "string" => "z.string()".to_owned(),
+                "number" => "z.number()".to_owned(),
+                "boolean" => "z.boolean()".to_owned(),
+                _ => "z.unknown()".to_owned(),
+            });
+        }
+        if generic_types.contains(id) {
+            return Ok(id.clone());
+        }
+
+        let mut schema = format!("{id}Schema");
+        if !parameters.is_empty() {
+            let parameters = parameters
+                .iter()
+                .map(|p| self.zod_schema(p, generic_types))
+                .collect::<Result<Vec<_>, _>>()?;
+            schema = format!("{schema}({})", parameters.join(", "));
+        }
+        // Types that depend on each other can't all come after each other.
+        if self.undeclared_schemas.contains(id) {
+            schema = format!("z.lazy(() => {schema})");
+        }
+        Ok(schema)
+    }
+
+    fn zod_special_schema(
+        &mut self,
+        special_ty: &SpecialRustType,
+        generic_types: &[String],
+    ) -> Result<String, RustTypeFormatError> {
+        match special_ty {
+            SpecialRustType::Vec(rtype) | SpecialRustType::Slice(rtype) => Ok(format!(
+                "z.array({})",
+                self.zod_schema(rtype, generic_types)?
+            )),
+            SpecialRustType::Array(rtype, len) => {
+                let schema = self.zod_schema(rtype, generic_types)?;
+                Ok(format!(
+                    "z.tuple([{}])",
+                    std::iter::repeat_n(&schema, *len).join_with(", ")
+                ))
+            }
+            SpecialRustType::Option(rtype) => Ok(format!(
+                "{}.nullable(){NULL_AS_UNDEFINED}",
+                self.zod_schema(rtype, generic_types)?
+            )),
+            SpecialRustType::HashMap(rtype1, rtype2) => {
+                let key = match rtype1.as_ref() {
+                    RustType::Simple { id } if generic_types.contains(id) => {
+                        return Err(RustTypeFormatError::GenericKeyForbiddenInTS(id.clone()));
+                    }
+                    _ => self.zod_schema(rtype1, generic_types)?,
+                };
+                // The keys of JSON objects are always strings.
+                let key = match key.strip_prefix("z.number()") {
+                    Some(checks) => format!("z.coerce.number(){checks}"),
+                    None => key,
+                };
+                Ok(format!(
+                    "z.record({key}, {})",
+                    self.zod_schema(rtype2, generic_types)?
+                ))
+            }
+            SpecialRustType::Unit => Ok("z.null()".into()),
+            SpecialRustType::String | SpecialRustType::Char => Ok("z.string()".into()),
+            SpecialRustType::I8 => Ok("z.number().int().min(-128).max(127)".into()),
+            SpecialRustType::U8 => Ok("z.number().int().min(0).max(255)".into()),
+            SpecialRustType::I16 => Ok("z.number().int().min(-32768).max(32767)".into()),
+            SpecialRustType::U16 => Ok("z.number().int().min(0).max(65535)".into()),
+            SpecialRustType::I32 => Ok("z.number().int().min(-2147483648).max(2147483647)".into()),
+            SpecialRustType::U32 => Ok("z.number().int().min(0).max(4294967295)".into()),
+            SpecialRustType::I54 => Ok("z.number().int().safe()".into()),
+            SpecialRustType::U53 => Ok("z.number().int().nonnegative().safe()".into()),
+            SpecialRustType::F32 | SpecialRustType::F64 => Ok("z.number()".into()),
+            SpecialRustType::Bool => Ok("z.boolean()".into()),
+            SpecialRustType::U64
+            | SpecialRustType::I64
+            | SpecialRustType::ISize
+            | SpecialRustType::USize => {
+                panic!("64 bit types not allowed in Typeshare")
+            }
+        }
+    }
+
     fn write_enum_variants(&mut self, w: &mut dyn Write, e: &RustEnum) -> io::Result<()> {
         match e {
             // Write all the unit variants out (there can only be unit variants in
@@ -201,7 +832,14 @@ impl TypeScript {
                 RustEnumVariant::Unit(shared) => {
                     writeln!(w)?;
                     self.write_comments(w, 1, &shared.comments)?;
-                    write!(w, "\t{} = {:?},", shared.id.original, &shared.id.renamed)
+                    match self.unit_enums {
+                        UnitEnumStyle::Enum | UnitEnumStyle::ConstEnum => {
+                            write!(w, "\t{} = {:?},", shared.id.original, &shared.id.renamed)
+                        }
+                        UnitEnumStyle::Union | UnitEnumStyle::ConstObject => {
+                            write!(w, "\t| {:?}", &shared.id.renamed)
+                        }
+                    }
                 }
                 _ => unreachable!(),
             }),
@@ -213,6 +851,8 @@ impl TypeScript {
                 content_key,
                 shared,
             } => shared.variants.iter().try_for_each(|v| {
+                let tag_key = typescript_property_aware_rename(tag_key);
+                let content_key = typescript_property_aware_rename(content_key);
                 writeln!(w)?;
                 self.write_comments(w, 1, &v.shared().comments)?;
                 match v {
@@ -224,14 +864,14 @@ impl TypeScript {
                     RustEnumVariant::Tuple { ty, shared } => {
                         let r#type = self
                             .format_type(ty, e.shared().generic_types.as_slice())
-                            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+                            .map_err(io::Error::other)?;
                         write!(
                             w,
                             "\t| {{ {}: {:?}, {}{}: {} }}",
                             tag_key,
                             shared.id.renamed,
                             content_key,
-                            ty.is_optional().then_some("?").unwrap_or_default(),
+                            if ty.is_optional() { "?" } else { "" },
                             r#type
                         )
                     }
@@ -265,7 +905,7 @@ impl TypeScript {
             Some(type_override) => type_override.to_owned(),
             None => self
                 .format_type(&field.ty, generic_types)
-                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?,
+                .map_err(io::Error::other)?,
         };
 
         let optional = field.ty.is_optional() || field.has_default;
@@ -278,11 +918,11 @@ impl TypeScript {
         writeln!(
             w,
             "\t{}{}{}: {}{};",
-            is_readonly.then_some("readonly ").unwrap_or_default(),
+            if is_readonly { "readonly " } else { "" },
             typescript_property_aware_rename(&field.id.renamed),
-            optional.then_some("?").unwrap_or_default(),
+            if optional { "?" } else { "" },
             ts_ty,
-            double_optional.then_some(" | null").unwrap_or_default()
+            if double_optional { " | null" } else { "" }
         )?;
 
         Ok(())
@@ -318,6 +958,32 @@ impl TypeScript {
     }
 }
 
+/// The schema of a field given with `#[typeshare(typescript(zod = "..."))]`, or
+/// `z.unknown()` when only its type is overridden.
+fn zod_override(field: &RustField) -> Option<&str> {
+    field
+        .decorators
+        .get(&SupportedLanguage::TypeScript)?
+        .iter()
+        .find_map(|decorator| match decorator {
+            FieldDecorator::NameValue(name, schema) if name == "zod" => Some(schema.as_str()),
+            _ => None,
+        })
+        .or_else(|| {
+            field
+                .type_override(SupportedLanguage::TypeScript)
+                .map(|_| "z.unknown()")
+        })
+}
+
+/// Access a property of an object, like `.name` or `["a-name"]`.
+fn typescript_property_access(name: &str) -> String {
+    if name.chars().any(|c| c == '-') {
+        return format!("[{:?}]", name);
+    }
+    format!(".{name}")
+}
+
 fn typescript_property_aware_rename(name: &str) -> String {
     if name.chars().any(|c| c == '-') {
         return format!("{:?}", name);
@@ -2,7 +2,11 @@
 //! Contains the parser and language converters.
 use thiserror::Error;
 
+pub mod cargo;
+pub mod compat;
 pub mod context;
+pub mod filter;
+pub mod generator;
 /// Implementations for each language converter
 pub mod language;
 /// Parsing Rust code into a format the `language` modules can understand
@@ -15,7 +19,8 @@ mod target_os_check;
 mod topsort;
 mod visitors;
 
-pub use rename::RenameExt;
+pub use rename::{FieldNaming, RenameExt};
+pub use topsort::TypeOrdering;
 
 #[derive(Debug, Error)]
 #[allow(missing_docs)]
@@ -8,15 +8,19 @@ use crate::{
         RustTypeParseError,
     },
     target_os_check::accept_target_os,
+    topsort::TypeOrdering,
     visitors::{ImportedType, TypeShareVisitor},
 };
 use itertools::Either;
 use log::debug;
 use proc_macro2::Ident;
+use quote::ToTokens;
+use serde::{Deserialize, Serialize};
 use std::{
     collections::{BTreeSet, HashMap, HashSet},
     convert::TryFrom,
     ops::AddAssign,
+    path::PathBuf,
 };
 use syn::{
     ext::IdentExt, parse::ParseBuffer, punctuated::Punctuated, visit::Visit, Attribute, Expr,
@@ -29,7 +33,7 @@ const TYPESHARE: &str = "typeshare";
 const SERDE: &str = "serde";
 
 /// Supported typeshare type level decorator attributes.
-#[derive(PartialEq, Eq, Debug, Hash, Copy, Clone)]
+#[derive(PartialEq, Eq, Debug, Hash, Copy, Clone, Serialize, Deserialize)]
 pub enum DecoratorKind {
     /// The typeshare attribute for swift type constraints "swift"
     Swift,
@@ -37,6 +41,8 @@ pub enum DecoratorKind {
     SwiftGenericConstraints,
     /// The typeshare attribute for kotlin "kotlin"
     Kotlin,
+    /// The typeshare attribute for the kinds of GraphQL types "graphql"
+    GraphQl,
 }
 
 impl DecoratorKind {
@@ -46,6 +52,7 @@ impl DecoratorKind {
             DecoratorKind::Swift => "swift",
             DecoratorKind::SwiftGenericConstraints => "swiftGenericConstraints",
             DecoratorKind::Kotlin => "kotlin",
+            DecoratorKind::GraphQl => "graphql",
         }
     }
 }
@@ -78,6 +85,8 @@ pub enum ParseError {
     SerdeFlattenNotAllowed,
     #[error("IO error: {0}")]
     IOError(String),
+    #[error("proto_tag must be a positive integer, not `{0}`")]
+    InvalidProtoTag(String),
 }
 
 /// Error with it's related data.
@@ -90,7 +99,7 @@ pub struct ErrorInfo {
 }
 
 /// The results of parsing Rust source input.
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Serialize, Deserialize)]
 pub struct ParsedData {
     /// Structs defined in the source
     pub structs: Vec<RustStruct>,
@@ -107,9 +116,21 @@ pub struct ParsedData {
     /// All type names
     pub type_names: HashSet<String>,
     /// Failures during parsing.
+    #[serde(skip)]
     pub errors: Vec<ErrorInfo>,
     /// Using multi file support.
     pub multi_file: bool,
+    /// The crates the types come from, which a single output file doesn't keep as
+    /// its crate name.
+    #[serde(skip)]
+    pub source_crates: BTreeSet<CrateName>,
+    /// Where each type is declared, by its original name: the source file and the
+    /// position of the type in it.
+    #[serde(default)]
+    pub source_positions: HashMap<String, (PathBuf, usize)>,
+    /// The order to write the types in.
+    #[serde(skip)]
+    pub type_ordering: TypeOrdering,
 }
 
 // The better abstraction here is Semigroup Monoid but such
@@ -122,6 +143,8 @@ impl AddAssign<ParsedData> for ParsedData {
         self.import_types.extend(rhs.import_types);
         self.type_names.extend(rhs.type_names);
         self.errors.append(&mut rhs.errors);
+        self.source_crates.append(&mut rhs.source_crates);
+        self.source_positions.extend(rhs.source_positions);
 
         self.file_name = rhs.file_name;
         self.crate_name = rhs.crate_name;
@@ -254,6 +277,7 @@ pub(crate) fn parse_struct(s: &ItemStruct, target_os: &[String]) -> Result<RustI
                         comments: parse_comment_attrs(&f.attrs),
                         has_default,
                         decorators,
+                        proto_tag: get_proto_tag(&f.attrs)?,
                     })
                 })
                 .collect::<Result<_, ParseError>>()?;
@@ -414,6 +438,7 @@ fn parse_enum_variant(
     let shared = RustEnumVariantShared {
         id: get_ident(Some(&v.ident), &v.attrs, enum_serde_rename_all),
         comments: parse_comment_attrs(&v.attrs),
+        proto_tag: get_proto_tag(&v.attrs)?,
     };
 
     // Get the value of `#[serde(rename_all)]` for this specific variant rather
@@ -461,6 +486,7 @@ fn parse_enum_variant(
                         comments: parse_comment_attrs(&f.attrs),
                         has_default,
                         decorators,
+                        proto_tag: get_proto_tag(&f.attrs)?,
                     })
                 })
                 .collect::<Result<Vec<_>, ParseError>>()?,
@@ -627,6 +653,32 @@ fn is_redacted(attrs: &[syn::Attribute]) -> bool {
     })
 }
 
+/// Checks the field or variant for a `#[typeshare(proto_tag = N)]` attribute.
+fn get_proto_tag(attrs: &[syn::Attribute]) -> Result<Option<u32>, ParseError> {
+    let Some(value) = attrs
+        .iter()
+        .flat_map(|attr| get_meta_items(attr, TYPESHARE))
+        .find_map(|arg| match arg {
+            Meta::NameValue(name_value) if name_value.path.is_ident("proto_tag") => {
+                Some(name_value.value)
+            }
+            _ => None,
+        })
+    else {
+        return Ok(None);
+    };
+
+    match &value {
+        Expr::Lit(ExprLit {
+            lit: syn::Lit::Int(int),
+            ..
+        }) => int.base10_parse::<u32>().ok().filter(|tag| *tag > 0),
+        _ => None,
+    }
+    .map(Some)
+    .ok_or_else(|| ParseError::InvalidProtoTag(value.to_token_stream().to_string()))
+}
+
 fn serde_attr(attrs: &[syn::Attribute], ident: &str) -> bool {
     attrs.iter().any(|attr| {
         get_meta_items(attr, SERDE)
@@ -758,6 +810,7 @@ fn get_decorators(attrs: &[syn::Attribute]) -> DecoratorMap {
         DecoratorKind::Swift,
         DecoratorKind::SwiftGenericConstraints,
         DecoratorKind::Kotlin,
+        DecoratorKind::GraphQl,
     ] {
         for value in get_name_value_meta_items(attrs, decorator_kind.as_str(), TYPESHARE) {
             decorator_map
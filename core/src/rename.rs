@@ -1,5 +1,7 @@
 // Based off Serde implementation: https://github.com/serde-rs/serde/blob/7950f3cdc52d4898aa4195b853cbec12d65bb091/serde_derive/src/internals/case.rs
 
+use serde::{Deserialize, Serialize};
+
 /// Trait to rename a string using common case or character separators.
 pub trait RenameExt {
     /// Convert to camelCase.
@@ -73,6 +75,46 @@ impl RenameExt for String {
     }
 }
 
+/// How to name the properties of generated types.
+///
+/// Languages that can keep the serialized key separately from the property name,
+/// such as Swift with `CodingKeys` or Kotlin with `@SerialName`, use this to give
+/// properties idiomatic names without changing the wire format.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum FieldNaming {
+    /// Use the serialized key as the property name.
+    #[default]
+    #[serde(rename = "serialized")]
+    Serialized,
+    /// Convert the serialized key to camelCase.
+    #[serde(rename = "camelCase")]
+    CamelCase,
+    /// Convert the serialized key to PascalCase.
+    #[serde(rename = "PascalCase")]
+    PascalCase,
+    /// Convert the serialized key to snake_case.
+    #[serde(rename = "snake_case")]
+    SnakeCase,
+}
+
+impl FieldNaming {
+    /// The property name for the serialized key `key`.
+    pub fn apply(self, key: &str) -> String {
+        if key.is_empty() {
+            return String::new();
+        }
+        let words = key.replace('-', "_");
+        match self {
+            Self::Serialized => key.to_owned(),
+            Self::CamelCase => words.to_camel_case(),
+            Self::PascalCase => words.to_pascal_case(),
+            Self::SnakeCase if words.contains('_') => words.to_ascii_lowercase(),
+            Self::SnakeCase => words.to_snake_case(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -161,4 +203,21 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_field_naming() {
+        let tests = [
+            ("user_id", "user_id", "userId", "UserId", "user_id"),
+            ("userId", "userId", "userId", "UserId", "user_id"),
+            ("user-id", "user-id", "userId", "UserId", "user_id"),
+            ("URL", "URL", "url", "Url", "url"),
+        ];
+
+        for (key, serialized, camel_case, pascal_case, snake_case) in tests {
+            assert_eq!(FieldNaming::Serialized.apply(key), serialized);
+            assert_eq!(FieldNaming::CamelCase.apply(key), camel_case);
+            assert_eq!(FieldNaming::PascalCase.apply(key), pascal_case);
+            assert_eq!(FieldNaming::SnakeCase.apply(key), snake_case);
+        }
+    }
 }
@@ -1,4 +1,5 @@
 use quote::ToTokens;
+use serde::{Deserialize, Serialize};
 use std::collections::BTreeSet;
 use std::str::FromStr;
 use std::{collections::HashMap, convert::TryFrom};
@@ -13,7 +14,7 @@ use crate::visitors::accept_type;
 pub type DecoratorMap = HashMap<DecoratorKind, BTreeSet<String>>;
 
 /// Identifier used in Rust structs, enums, and fields. It includes the `original` name and the `renamed` value after the transformation based on `serde` attributes.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Id {
     /// The original identifier name
     pub original: String,
@@ -36,7 +37,7 @@ impl std::fmt::Display for Id {
 }
 
 /// Rust struct.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RustStruct {
     /// The identifier for the struct.
     pub id: Id,
@@ -78,7 +79,7 @@ impl Ord for RustStruct {
 /// ```
 /// pub struct MasterPassword(String);
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RustTypeAlias {
     /// The identifier for the alias.
     pub id: Id,
@@ -115,7 +116,7 @@ impl PartialOrd for RustTypeAlias {
 }
 
 /// Rust field definition.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RustField {
     /// Identifier for the field.
     pub id: Id,
@@ -130,10 +131,12 @@ pub struct RustField {
     /// Language-specific decorators assigned to a given field.
     /// The keys are language names (e.g. SupportedLanguage::TypeScript), the values are field decorators (e.g. readonly)
     pub decorators: HashMap<SupportedLanguage, BTreeSet<FieldDecorator>>,
+    /// The field number from `#[typeshare(proto_tag = N)]`, for Protocol Buffers.
+    pub proto_tag: Option<u32>,
 }
 
 /// A single decorator on a field in Rust code.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum FieldDecorator {
     /// A boolean flag enabled by its existence as a decorator: for example, `readonly`.
     Word(String),
@@ -151,7 +154,7 @@ impl FieldDecorator {
 }
 
 /// A Rust type.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum RustType {
     /// A type with generic parameters. Consists of a type ID + parameters that come
     /// after in angled brackets. Examples include:
@@ -182,7 +185,7 @@ pub enum RustType {
 }
 
 /// A special rust type that needs a manual type conversion
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SpecialRustType {
     /// Represents `Vec<T>` from the standard library
     Vec(Box<RustType>),
@@ -463,6 +466,28 @@ pub enum RustTypeFormatError {
     GenericsForbiddenInGo(String),
     #[error("Generic type `{0}` cannot be used as a map key in Typescript")]
     GenericKeyForbiddenInTS(String),
+    #[error("Generic parameter `{0}` is forbidden in Protocol Buffers")]
+    GenericsForbiddenInProtobuf(String),
+    #[error("`{0}` nests a list or map in another one or in an option, which Protocol Buffers can't represent")]
+    NestedCollectionInProtobuf(String),
+    #[error("`{0}` cannot be used as a map key in Protocol Buffers, only integers, strings and booleans can")]
+    MapKeyForbiddenInProtobuf(String),
+    #[error("Generic type `{0}` is forbidden in GraphQL")]
+    GenericsForbiddenInGraphQl(String),
+    #[error("`{0}` is an output type, which GraphQL inputs can't use")]
+    OutputTypeInGraphQlInput(String),
+    #[error(
+        "Type mapping for `{0}` needs a JSON converter in Dart, as JSON values can't be cast to it"
+    )]
+    MappedTypeWithoutConverterInDart(String),
+    #[error(
+        "Type mapping for `{base}` uses generic parameter {index} but only {count} were given"
+    )]
+    MissingMappingParameter {
+        base: String,
+        index: usize,
+        count: usize,
+    },
 }
 
 impl SpecialRustType {
@@ -555,7 +580,7 @@ impl SpecialRustType {
 }
 
 /// Parsed information about a Rust enum definition
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum RustEnum {
     /// A unit enum
     ///
@@ -625,7 +650,7 @@ impl RustEnum {
 }
 
 /// Enum information shared among different enum types
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RustEnumShared {
     /// The enum's ident
     pub id: Id,
@@ -647,7 +672,7 @@ pub struct RustEnumShared {
 }
 
 /// Parsed information about a Rust enum variant
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum RustEnumVariant {
     /// A unit variant
     Unit(RustEnumVariantShared),
@@ -679,12 +704,14 @@ impl RustEnumVariant {
 }
 
 /// Variant information shared among different variant types
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RustEnumVariantShared {
     /// The variant's ident
     pub id: Id,
     /// Comments applied to the variant
     pub comments: Vec<String>,
+    /// The number from `#[typeshare(proto_tag = N)]`, for Protocol Buffers.
+    pub proto_tag: Option<u32>,
 }
 
 /// An enum that encapsulates units of code generation for Typeshare.
@@ -699,3 +726,14 @@ pub enum RustItem {
     /// A `type` definition or newtype struct.
     Alias(RustTypeAlias),
 }
+
+impl RustItem {
+    /// The identifier of the item.
+    pub fn id(&self) -> &Id {
+        match self {
+            RustItem::Struct(s) => &s.id,
+            RustItem::Enum(e) => &e.shared().id,
+            RustItem::Alias(a) => &a.id,
+        }
+    }
+}
@@ -1,9 +1,55 @@
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+};
+
+use serde::{Deserialize, Serialize};
 
 use crate::rust_types::{
     RustEnum, RustEnumVariant, RustItem, RustStruct, RustType, RustTypeAlias, SpecialRustType,
 };
 
+/// The order to write the types of a file in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum TypeOrdering {
+    /// Types come after the types they depend on, and are otherwise in the order
+    /// typeshare has always written them in: structs, then enums, then aliases.
+    #[default]
+    Legacy,
+    /// Types come after the types they depend on, and are otherwise sorted by name.
+    Dependencies,
+    /// Types are sorted by name. Languages that can't use a type before it is
+    /// declared still put types after the types they depend on.
+    Alphabetical,
+    /// Types are in the order they are declared in, by source file path. Languages
+    /// that can't use a type before it is declared still put types after the types
+    /// they depend on.
+    Source,
+}
+
+/// Sort the types of a file. Without `forward_references`, types always come after
+/// the types they depend on.
+pub(crate) fn sort_items(
+    items: &mut [RustItem],
+    ordering: TypeOrdering,
+    source_positions: &HashMap<String, (PathBuf, usize)>,
+    forward_references: bool,
+) {
+    match ordering {
+        TypeOrdering::Legacy => {}
+        TypeOrdering::Dependencies | TypeOrdering::Alphabetical => items.sort_by(|a, b| {
+            (&a.id().renamed, &a.id().original).cmp(&(&b.id().renamed, &b.id().original))
+        }),
+        TypeOrdering::Source => items.sort_by_key(|item| source_positions.get(&item.id().original)),
+    }
+    if matches!(ordering, TypeOrdering::Legacy | TypeOrdering::Dependencies) || !forward_references
+    {
+        topsort(items);
+    }
+}
+
 fn get_dependencies_from_type(
     tp: &RustType,
     types: &HashMap<String, &RustItem>,
@@ -154,8 +200,8 @@ fn toposort_impl(graph: &Vec<Vec<usize>>) -> Vec<usize> {
                 if !seen.contains(dependant) {
                     seen.push(*dependant);
                 } else {
-                    // cycle
-                    return;
+                    // cycle, skip the dependency that closes it
+                    continue;
                 }
                 // recurse
                 let dependencies = &graph[*dependant];
@@ -181,7 +227,7 @@ fn toposort_impl(graph: &Vec<Vec<usize>>) -> Vec<usize> {
     res
 }
 
-pub(crate) fn topsort(things: &mut [RustItem]) {
+fn topsort(things: &mut [RustItem]) {
     let types = HashMap::from_iter(things.iter().map(|thing| {
         let id = match thing {
             RustItem::Enum(e) => match e {
@@ -200,12 +246,20 @@ pub(crate) fn topsort(things: &mut [RustItem]) {
 
     let dag: Vec<Vec<usize>> = things
         .iter()
-        .map(|thing| {
+        .enumerate()
+        .map(|(index, thing)| {
             let mut deps = Vec::new();
             get_dependencies(thing, &types, &mut deps, &mut HashSet::new());
-            deps.iter()
+            let mut deps = deps
+                .iter()
                 .map(|dep| get_index(types.get(dep).unwrap(), things))
-                .collect()
+                // Recursive types depend on themselves.
+                .filter(|&dep| dep != index)
+                .collect::<Vec<_>>();
+            // Dependencies keep the order of the things, so ties are broken the same way.
+            deps.sort_unstable();
+            deps.dedup();
+            deps
         })
         .collect();
     sort_by_indices(things, toposort_impl(&dag));
@@ -242,3 +296,68 @@ fn test_toposort_impl_cycles() {
     let res = toposort_impl(&dag);
     assert!((res == vec![0, 1, 2]) || (res == vec![1, 0, 2]))
 }
+
+#[test]
+fn test_sort_items() {
+    use crate::{context::ParseContext, context::ParseFileContext, parser::parse};
+
+    let parsed_data = parse(
+        &ParseContext::default(),
+        ParseFileContext {
+            source_code: "
+                #[typeshare] struct Zebra { apple: Apple }
+                #[typeshare] struct Mango {}
+                #[typeshare] struct Apple { mango: Mango }
+            "
+            .to_owned(),
+            crate_name: "fruits".into(),
+            file_name: "fruits.ts".into(),
+            file_path: "fruits/src/lib.rs".into(),
+        },
+    )
+    .unwrap()
+    .unwrap();
+    let sorted = |ordering, forward_references| {
+        let mut items = parsed_data
+            .structs
+            .iter()
+            .cloned()
+            .map(RustItem::Struct)
+            .collect::<Vec<_>>();
+        sort_items(
+            &mut items,
+            ordering,
+            &parsed_data.source_positions,
+            forward_references,
+        );
+        items
+            .iter()
+            .map(|item| item.id().original.clone())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        sorted(TypeOrdering::Legacy, true),
+        ["Mango", "Apple", "Zebra"]
+    );
+    assert_eq!(
+        sorted(TypeOrdering::Dependencies, true),
+        ["Mango", "Apple", "Zebra"]
+    );
+    assert_eq!(
+        sorted(TypeOrdering::Alphabetical, true),
+        ["Apple", "Mango", "Zebra"]
+    );
+    assert_eq!(
+        sorted(TypeOrdering::Alphabetical, false),
+        ["Mango", "Apple", "Zebra"]
+    );
+    assert_eq!(
+        sorted(TypeOrdering::Source, true),
+        ["Zebra", "Mango", "Apple"]
+    );
+    assert_eq!(
+        sorted(TypeOrdering::Source, false),
+        ["Mango", "Apple", "Zebra"]
+    );
+}
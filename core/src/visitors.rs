@@ -10,6 +10,7 @@ use crate::{
     target_os_check::accept_target_os,
 };
 use log::debug;
+use serde::{Deserialize, Serialize};
 use std::{collections::HashSet, ops::Not, path::PathBuf};
 use syn::{visit::Visit, Attribute, ItemUse, UseTree};
 
@@ -85,7 +86,16 @@ impl<'a> TypeShareVisitor<'a> {
     #[inline]
     fn collect_result(&mut self, result: Result<RustItem, ParseError>) {
         match result {
-            Ok(data) => self.parsed_data.push(data),
+            Ok(data) => {
+                let position = (
+                    self.file_path.clone(),
+                    self.parsed_data.source_positions.len(),
+                );
+                self.parsed_data
+                    .source_positions
+                    .insert(data.id().original.clone(), position);
+                self.parsed_data.push(data)
+            }
             Err(error) => self.parsed_data.errors.push(ErrorInfo {
                 file_name: self.file_path.to_string_lossy().into_owned(),
                 error,
@@ -336,7 +346,7 @@ pub(crate) fn accept_type(type_name: &str) -> bool {
 }
 
 /// An imported type reference.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(test, derive(Ord, PartialOrd))]
 pub struct ImportedType {
     /// Crate this type belongs to.
@@ -610,6 +620,9 @@ mod test {
             ignored_types: Vec::new(),
             multi_file: true,
             target_os: Vec::new(),
+            crate_manifests: None,
+            parse_cache: None,
+            filter: None,
         };
 
         let file: File = syn::parse_str(rust_code).unwrap();
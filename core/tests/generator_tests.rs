@@ -0,0 +1,635 @@
+use std::{
+    fs,
+    ops::Deref,
+    path::{Path, PathBuf},
+};
+use typeshare_core::{
+    generator::{FileLayout, FileTemplates, Generator, GeneratorError, Output},
+    language::{
+        CSharp, Dart, GraphQl, Java, JsonSchema, OpenApi, OpenApiFormat, Protobuf, Python,
+        SupportedLanguage, TypeScript, ZodSchemas,
+    },
+};
+
+const TEST_INPUT: &str = "data/tests/can_generate_simple_struct_with_a_comment";
+
+/// A scratch directory for a single test, which is removed again when dropped.
+struct ScratchDir(PathBuf);
+
+impl Deref for ScratchDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for ScratchDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+/// A fresh scratch directory for a single test.
+fn scratch_dir(test_name: &str) -> ScratchDir {
+    let dir = std::env::temp_dir().join(format!(
+        "typeshare-generator-{test_name}-{}",
+        std::process::id()
+    ));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    ScratchDir(dir)
+}
+
+fn typescript() -> Box<TypeScript> {
+    Box::new(TypeScript {
+        no_version_header: true,
+        ..Default::default()
+    })
+}
+
+#[test]
+fn generates_single_file() {
+    let dir = scratch_dir("single_file");
+    let output = dir.join("output.ts");
+
+    Generator::new()
+        .lang(SupportedLanguage::TypeScript, typescript())
+        .input(TEST_INPUT)
+        .output(Output::File(output.clone()))
+        .run()
+        .unwrap();
+
+    assert_eq!(
+        fs::read_to_string(output).unwrap(),
+        fs::read_to_string(format!("{TEST_INPUT}/output.ts")).unwrap()
+    );
+}
+
+#[test]
+fn generates_one_file_per_crate() {
+    let dir = scratch_dir("multi_file");
+    for crate_name in ["first", "second"] {
+        let src = dir.join("input").join(crate_name).join("src");
+        fs::create_dir_all(&src).unwrap();
+        fs::write(
+            src.join("lib.rs"),
+            format!("#[typeshare]\npub struct {crate_name} {{ field: String }}\n"),
+        )
+        .unwrap();
+    }
+
+    Generator::new()
+        .lang(SupportedLanguage::TypeScript, typescript())
+        .input(dir.join("input"))
+        .output(Output::Folder(dir.join("output")))
+        .run()
+        .unwrap();
+
+    assert!(dir.join("output/first.ts").is_file());
+    assert!(dir.join("output/second.ts").is_file());
+}
+
+/// Write the crates `first` and `second`, with `second` using the type of `first`.
+fn write_dependent_crates(dir: &std::path::Path) {
+    for (crate_name, source) in [
+        (
+            "first",
+            "#[typeshare]\npub struct First { field: String }\n",
+        ),
+        (
+            "second",
+            "use first::First;\n#[typeshare]\npub struct Second { first: First }\n",
+        ),
+    ] {
+        let src = dir.join("input").join(crate_name).join("src");
+        fs::create_dir_all(&src).unwrap();
+        fs::write(src.join("lib.rs"), source).unwrap();
+    }
+}
+
+#[test]
+fn generates_files_with_layout() {
+    let dir = scratch_dir("layout");
+    write_dependent_crates(&dir);
+
+    Generator::new()
+        .lang(SupportedLanguage::TypeScript, typescript())
+        .input(dir.join("input"))
+        .output(Output::Folder(dir.join("output")))
+        .file_layout(FileLayout::new("{crate}/index.{ext}").unwrap())
+        .run()
+        .unwrap();
+
+    assert!(dir.join("output/first/index.ts").is_file());
+    let second = fs::read_to_string(dir.join("output/second/index.ts")).unwrap();
+    assert!(
+        second.contains(r#"import { First } from "../first/index";"#),
+        "{second}"
+    );
+}
+
+#[test]
+fn generates_python_packages_with_layout() {
+    let dir = scratch_dir("python_layout");
+    write_dependent_crates(&dir);
+
+    Generator::new()
+        .lang(
+            SupportedLanguage::Python,
+            Box::new(Python {
+                no_version_header: true,
+                ..Default::default()
+            }),
+        )
+        .input(dir.join("input"))
+        .output(Output::Folder(dir.join("output")))
+        .file_layout(FileLayout::new("{crate_snake}/models.py").unwrap())
+        .run()
+        .unwrap();
+
+    let second = fs::read_to_string(dir.join("output/second/models.py")).unwrap();
+    assert!(
+        second.contains("from ..first.models import First"),
+        "{second}"
+    );
+    for package in ["", "first", "second"] {
+        assert!(dir
+            .join("output")
+            .join(package)
+            .join("__init__.py")
+            .is_file());
+    }
+}
+
+#[test]
+fn generates_java_classes_named_after_files() {
+    let dir = scratch_dir("java");
+    write_dependent_crates(&dir);
+    let java = || {
+        Box::new(Java {
+            package: "com.example".to_owned(),
+            no_version_header: true,
+            ..Default::default()
+        })
+    };
+
+    Generator::new()
+        .lang(SupportedLanguage::Java, java())
+        .input(dir.join("input"))
+        .output(Output::File(dir.join("Models.java")))
+        .run()
+        .unwrap();
+    let models = fs::read_to_string(dir.join("Models.java")).unwrap();
+    assert!(models.contains("public final class Models {"), "{models}");
+
+    Generator::new()
+        .lang(SupportedLanguage::Java, java())
+        .input(dir.join("input"))
+        .output(Output::Folder(dir.join("output")))
+        .run()
+        .unwrap();
+    let second = fs::read_to_string(dir.join("output/Second.java")).unwrap();
+    assert!(
+        second.starts_with("package com.example.second;\n"),
+        "{second}"
+    );
+    assert!(
+        second.contains("import com.example.first.First.First;\n\npublic final class Second {"),
+        "{second}"
+    );
+}
+
+#[test]
+fn generates_csharp_namespace_per_crate() {
+    let dir = scratch_dir("csharp");
+    write_dependent_crates(&dir);
+
+    Generator::new()
+        .lang(
+            SupportedLanguage::CSharp,
+            Box::new(CSharp {
+                namespace: "Example".to_owned(),
+                no_version_header: true,
+                ..Default::default()
+            }),
+        )
+        .input(dir.join("input"))
+        .output(Output::Folder(dir.join("output")))
+        .run()
+        .unwrap();
+
+    let second = fs::read_to_string(dir.join("output/Second.cs")).unwrap();
+    assert!(
+        second.contains("using Example.First;\n\nnamespace Example.Second;\n"),
+        "{second}"
+    );
+}
+
+#[test]
+fn generates_dart_package_imports() {
+    let dir = scratch_dir("dart");
+    write_dependent_crates(&dir);
+
+    Generator::new()
+        .lang(
+            SupportedLanguage::Dart,
+            Box::new(Dart {
+                package: "example/models".to_owned(),
+                no_version_header: true,
+                ..Default::default()
+            }),
+        )
+        .input(dir.join("input"))
+        .output(Output::Folder(dir.join("output")))
+        .file_layout(FileLayout::new("{crate}/types.{ext}").unwrap())
+        .run()
+        .unwrap();
+
+    let second = fs::read_to_string(dir.join("output/second/types.dart")).unwrap();
+    assert!(
+        second.starts_with("import 'package:example/models/first/types.dart';\n\nclass Second"),
+        "{second}"
+    );
+}
+
+#[test]
+fn generates_json_schema_references_between_files() {
+    let dir = scratch_dir("jsonschema");
+    write_dependent_crates(&dir);
+
+    Generator::new()
+        .lang(
+            SupportedLanguage::JsonSchema,
+            Box::new(JsonSchema {
+                no_version_header: true,
+                ..Default::default()
+            }),
+        )
+        .input(dir.join("input"))
+        .output(Output::Folder(dir.join("output")))
+        .file_layout(FileLayout::new("{crate}/schema.{ext}").unwrap())
+        .run()
+        .unwrap();
+
+    let second = fs::read_to_string(dir.join("output/second/schema.json")).unwrap();
+    assert!(
+        second.contains(r##""$ref": "../first/schema.json#/$defs/First""##),
+        "{second}"
+    );
+}
+
+#[test]
+fn merges_openapi_schemas_into_existing_spec() {
+    let dir = scratch_dir("openapi");
+    write_dependent_crates(&dir);
+    let spec = dir.join("openapi.json");
+    fs::write(
+        &spec,
+        r#"{
+  "openapi": "3.1.0",
+  "info": { "title": "Example", "version": "2.0.0" },
+  "paths": { "/first": { "get": { "responses": { "200": { "description": "OK" } } } } },
+  "components": {
+    "schemas": {
+      "Error": { "type": "string" },
+      "First": { "type": "null" },
+      "Removed": { "type": "null" }
+    }
+  },
+  "x-typeshare": { "schemas": ["First", "Removed"] }
+}"#,
+    )
+    .unwrap();
+
+    Generator::new()
+        .lang(
+            SupportedLanguage::OpenApi,
+            Box::new(OpenApi {
+                no_version_header: true,
+                format: OpenApiFormat::Json,
+                spec: Some(spec.clone()),
+                ..Default::default()
+            }),
+        )
+        .input(dir.join("input"))
+        .output(Output::File(spec.clone()))
+        .run()
+        .unwrap();
+
+    let merged: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&spec).unwrap()).unwrap();
+    assert_eq!(merged["info"]["version"], "2.0.0");
+    assert!(merged["paths"]["/first"]["get"].is_object(), "{merged}");
+    let schemas = &merged["components"]["schemas"];
+    assert_eq!(schemas["Error"]["type"], "string");
+    assert_eq!(schemas["First"]["type"], "object");
+    assert_eq!(
+        schemas["Second"]["properties"]["first"]["$ref"],
+        "#/components/schemas/First"
+    );
+    assert!(schemas.get("Removed").is_none(), "{merged}");
+    assert_eq!(
+        merged["x-typeshare"]["schemas"],
+        serde_json::json!(["First", "Second"])
+    );
+}
+
+/// Generate Protocol Buffers files for the crates in `dir`, keeping field numbers in `dir/typeshare.lock`.
+fn generate_protobuf(dir: &std::path::Path) -> Result<(), GeneratorError> {
+    Generator::new()
+        .lang(
+            SupportedLanguage::Protobuf,
+            Box::new(Protobuf {
+                no_version_header: true,
+                lock_file: Some(dir.join("typeshare.lock")),
+                ..Default::default()
+            }),
+        )
+        .input(dir.join("input"))
+        .output(Output::Folder(dir.join("output")))
+        .file_layout(FileLayout::new("{crate}/types.{ext}").unwrap())
+        .run()
+}
+
+#[test]
+fn generates_protobuf_imports_with_stable_field_numbers() {
+    let dir = scratch_dir("protobuf");
+    write_dependent_crates(&dir);
+    generate_protobuf(&dir).unwrap();
+
+    let second = fs::read_to_string(dir.join("output/second/types.proto")).unwrap();
+    assert!(second.contains("import \"first/types.proto\";"), "{second}");
+    let lock = fs::read_to_string(dir.join("typeshare.lock")).unwrap();
+    assert!(lock.contains("[messages.First]\nfield = 1\n"), "{lock}");
+
+    // New fields don't take the numbers of existing or removed ones, wherever they are.
+    fs::write(
+        dir.join("input/first/src/lib.rs"),
+        "#[typeshare]\npub struct First { added: String, field: String }\n",
+    )
+    .unwrap();
+    generate_protobuf(&dir).unwrap();
+    fs::write(
+        dir.join("input/first/src/lib.rs"),
+        "#[typeshare]\npub struct First { other: String, added: String }\n",
+    )
+    .unwrap();
+    generate_protobuf(&dir).unwrap();
+
+    let first = fs::read_to_string(dir.join("output/first/types.proto")).unwrap();
+    assert!(
+        first.contains(
+            "  reserved 1;\n  reserved \"field\";\n  string other = 3;\n  string added = 2;\n"
+        ),
+        "{first}"
+    );
+}
+
+#[test]
+fn refuses_to_renumber_protobuf_fields() {
+    let dir = scratch_dir("protobuf-renumber");
+    write_dependent_crates(&dir);
+    generate_protobuf(&dir).unwrap();
+
+    fs::write(
+        dir.join("input/first/src/lib.rs"),
+        "#[typeshare]\npub struct First { #[typeshare(proto_tag = 2)] field: String }\n",
+    )
+    .unwrap();
+    let err = generate_protobuf(&dir).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("`First.field` is numbered 1 in the lock file and can't be renumbered to 2"),
+        "{err}"
+    );
+}
+
+#[test]
+fn declares_graphql_scalars_of_all_crates_once() {
+    let dir = scratch_dir("graphql");
+    for (crate_name, source) in [
+        ("first", "#[typeshare]\npub struct First { at: DateTime }\n"),
+        (
+            "second",
+            "#[typeshare]\npub struct Second { at: DateTime, data: HashMap<String, String> }\n",
+        ),
+    ] {
+        let src = dir.join("input").join(crate_name).join("src");
+        fs::create_dir_all(&src).unwrap();
+        fs::write(src.join("lib.rs"), source).unwrap();
+    }
+
+    Generator::new()
+        .lang(
+            SupportedLanguage::GraphQl,
+            Box::new(GraphQl {
+                type_mappings: [("DateTime".to_owned(), "DateTime".into())].into(),
+                no_version_header: true,
+                ..Default::default()
+            }),
+        )
+        .input(dir.join("input"))
+        .output(Output::Folder(dir.join("output")))
+        .run()
+        .unwrap();
+
+    let first = fs::read_to_string(dir.join("output/first.graphql")).unwrap();
+    assert!(!first.contains("scalar"), "{first}");
+    assert_eq!(
+        fs::read_to_string(dir.join("output/scalars.graphql")).unwrap(),
+        "scalar DateTime\nscalar JSON\n"
+    );
+}
+
+#[test]
+fn inlines_graphql_aliases_of_other_crates() {
+    let dir = scratch_dir("graphql-aliases");
+    for (crate_name, source) in [
+        (
+            "first",
+            "use second::Stamp;\n#[typeshare]\npub struct First { at: Stamp }\n",
+        ),
+        (
+            "second",
+            "#[typeshare]\npub type Stamp = Option<DateTime>;\n",
+        ),
+    ] {
+        let src = dir.join("input").join(crate_name).join("src");
+        fs::create_dir_all(&src).unwrap();
+        fs::write(src.join("lib.rs"), source).unwrap();
+    }
+
+    Generator::new()
+        .lang(
+            SupportedLanguage::GraphQl,
+            Box::new(GraphQl {
+                type_mappings: [("DateTime".to_owned(), "DateTime".into())].into(),
+                no_version_header: true,
+                ..Default::default()
+            }),
+        )
+        .input(dir.join("input"))
+        .output(Output::Folder(dir.join("output")))
+        .run()
+        .unwrap();
+
+    let first = fs::read_to_string(dir.join("output/first.graphql")).unwrap();
+    assert_eq!(first, "type First {\n  at: DateTime\n}\n\n");
+    assert_eq!(
+        fs::read_to_string(dir.join("output/scalars.graphql")).unwrap(),
+        "scalar DateTime\n"
+    );
+}
+
+#[test]
+fn imports_zod_schemas_of_other_crates() {
+    let dir = scratch_dir("zod");
+    write_dependent_crates(&dir);
+    Generator::new()
+        .lang(
+            SupportedLanguage::TypeScript,
+            Box::new(TypeScript {
+                no_version_header: true,
+                zod: ZodSchemas::Instead,
+                ..Default::default()
+            }),
+        )
+        .input(dir.join("input"))
+        .output(Output::Folder(dir.join("output")))
+        .run()
+        .unwrap();
+
+    let second = fs::read_to_string(dir.join("output/second.ts")).unwrap();
+    assert!(
+        second.contains("import { First, FirstSchema } from \"./first\";"),
+        "{second}"
+    );
+    assert!(second.contains("\tfirst: FirstSchema,\n"), "{second}");
+}
+
+#[test]
+fn writes_header_and_footer() {
+    let dir = scratch_dir("templates");
+    write_dependent_crates(&dir);
+
+    Generator::new()
+        .lang(SupportedLanguage::TypeScript, typescript())
+        .input(dir.join("input"))
+        .output(Output::File(dir.join("output.ts")))
+        .file_templates(FileTemplates {
+            header: Some("/* eslint-disable */\n// {crates}: {license}".to_owned()),
+            footer: Some("// end".to_owned()),
+            license: "MIT".to_owned(),
+        })
+        .run()
+        .unwrap();
+
+    let output = fs::read_to_string(dir.join("output.ts")).unwrap();
+    assert!(
+        output.starts_with("/* eslint-disable */\n// first, second: MIT\nexport interface First"),
+        "{output}"
+    );
+    assert!(output.ends_with("}\n\n// end\n"), "{output}");
+}
+
+#[test]
+fn removes_files_that_are_no_longer_generated() {
+    let dir = scratch_dir("orphans");
+    let write_crate = |crate_name: &str| {
+        let src = dir.join("input").join(crate_name).join("src");
+        fs::create_dir_all(&src).unwrap();
+        fs::write(
+            src.join("lib.rs"),
+            format!("#[typeshare]\npub struct {crate_name} {{ field: String }}\n"),
+        )
+        .unwrap();
+    };
+    let run = |check: bool| {
+        Generator::new()
+            .lang(SupportedLanguage::TypeScript, typescript())
+            .input(dir.join("input"))
+            .output(Output::Folder(dir.join("output")))
+            .check(check)
+            .run()
+    };
+
+    write_crate("first");
+    write_crate("second");
+    run(false).unwrap();
+    run(true).unwrap();
+    fs::write(dir.join("output/handwritten.ts"), "").unwrap();
+
+    fs::remove_dir_all(dir.join("input/second")).unwrap();
+    match run(true) {
+        Err(GeneratorError::OutOfDate { changed, orphaned }) => {
+            assert!(changed.is_empty());
+            assert_eq!(orphaned, [dir.join("output/second.ts")]);
+        }
+        result => panic!("expected out of date files, got {result:?}"),
+    }
+    assert!(dir.join("output/second.ts").is_file());
+
+    run(false).unwrap();
+    assert!(dir.join("output/first.ts").is_file());
+    assert!(!dir.join("output/second.ts").exists());
+    assert!(dir.join("output/handwritten.ts").is_file());
+    run(true).unwrap();
+}
+
+#[test]
+fn requires_a_language() {
+    let result = Generator::new()
+        .input(TEST_INPUT)
+        .output(Output::File("unused.ts".into()))
+        .run();
+
+    assert!(matches!(result, Err(GeneratorError::MissingLanguage)));
+}
+
+#[test]
+fn generates_source_code() {
+    let dir = scratch_dir("source");
+    let output = dir.join("output.ts");
+
+    Generator::new()
+        .lang(SupportedLanguage::TypeScript, typescript())
+        .source(fs::read_to_string(format!("{TEST_INPUT}/input.rs")).unwrap())
+        .output(Output::File(output.clone()))
+        .run()
+        .unwrap();
+
+    assert_eq!(
+        fs::read_to_string(output).unwrap(),
+        fs::read_to_string(format!("{TEST_INPUT}/output.ts")).unwrap()
+    );
+}
+
+#[test]
+fn generates_source_code_without_types() {
+    let dir = scratch_dir("source_without_types");
+    let output = dir.join("output.ts");
+
+    Generator::new()
+        .lang(SupportedLanguage::TypeScript, Box::<TypeScript>::default())
+        .source("pub struct NotShared;")
+        .output(Output::File(output.clone()))
+        .run()
+        .unwrap();
+
+    let output = fs::read_to_string(output).unwrap();
+    assert!(output.contains("Generated by typeshare"));
+    assert!(!output.contains("NotShared"));
+}
+
+#[test]
+fn source_code_needs_a_single_file() {
+    let dir = scratch_dir("source_to_folder");
+    let result = Generator::new()
+        .lang(SupportedLanguage::TypeScript, typescript())
+        .source("#[typeshare]\npub struct Foo;")
+        .output(Output::Folder(dir.to_path_buf()))
+        .run();
+
+    assert!(matches!(result, Err(GeneratorError::SourceToFolder)));
+}
@@ -11,7 +11,7 @@ use std::{
 };
 use typeshare_core::{
     context::{ParseContext, ParseFileContext},
-    language::{CrateName, Language},
+    language::{CrateName, Language, TypeMapping},
     reconcile::reconcile_aliases,
 };
 
@@ -151,6 +151,30 @@ macro_rules! output_file_for_ident {
     (go) => {
         "output.go"
     };
+    (java) => {
+        "output.java"
+    };
+    (csharp) => {
+        "output.cs"
+    };
+    (dart) => {
+        "output.dart"
+    };
+    (jsonschema) => {
+        "output.json"
+    };
+    (openapi) => {
+        "output.yaml"
+    };
+    (protobuf) => {
+        "output.proto"
+    };
+    (graphql) => {
+        "output.graphql"
+    };
+    (zod) => {
+        "output.zod.ts"
+    };
     (python) => {
         "output.py"
     };
@@ -271,6 +295,133 @@ macro_rules! language_instance {
         })
     };
 
+    // Default java
+    (java) => {
+        language_instance!(java {
+            package: "com.agilebits.onepassword".to_string(),
+        })
+    };
+
+    // java with configuration fields forwarded
+    (java {$($field:ident: $val:expr),* $(,)?}) => {
+        #[allow(clippy::needless_update)]
+        Box::new(typeshare_core::language::Java {
+            no_version_header: true,
+            $($field: $val,)*
+            ..Default::default()
+        })
+    };
+
+    // Default C#
+    (csharp) => {
+        language_instance!(csharp {
+            namespace: "AgileBits.OnePassword".to_string(),
+        })
+    };
+
+    // C# with configuration fields forwarded
+    (csharp {$($field:ident: $val:expr),* $(,)?}) => {
+        #[allow(clippy::needless_update)]
+        Box::new(typeshare_core::language::CSharp {
+            no_version_header: true,
+            $($field: $val,)*
+            ..Default::default()
+        })
+    };
+
+    // Default GraphQL
+    (graphql) => {
+        language_instance!(graphql { })
+    };
+
+    // GraphQL with configuration fields forwarded
+    (graphql {$($field:ident: $val:expr),* $(,)?}) => {
+        #[allow(clippy::needless_update)]
+        Box::new(typeshare_core::language::GraphQl {
+            no_version_header: true,
+            $($field: $val,)*
+            ..Default::default()
+        })
+    };
+
+    // Default TypeScript with Zod schemas instead of interfaces
+    (zod) => {
+        language_instance!(zod { })
+    };
+
+    // TypeScript with Zod schemas, with configuration fields forwarded
+    (zod {$($field:ident: $val:expr),* $(,)?}) => {
+        #[allow(clippy::needless_update)]
+        Box::new(typeshare_core::language::TypeScript {
+            no_version_header: true,
+            zod: typeshare_core::language::ZodSchemas::Instead,
+            $($field: $val,)*
+            ..Default::default()
+        })
+    };
+
+    // Default Protocol Buffers
+    (protobuf) => {
+        language_instance!(protobuf { })
+    };
+
+    // Protocol Buffers with configuration fields forwarded
+    (protobuf {$($field:ident: $val:expr),* $(,)?}) => {
+        #[allow(clippy::needless_update)]
+        Box::new(typeshare_core::language::Protobuf {
+            no_version_header: true,
+            $($field: $val,)*
+            ..Default::default()
+        })
+    };
+
+    // Default OpenAPI
+    (openapi) => {
+        language_instance!(openapi {
+            title: "Example".to_owned(),
+        })
+    };
+
+    // OpenAPI with configuration fields forwarded
+    (openapi {$($field:ident: $val:expr),* $(,)?}) => {
+        #[allow(clippy::needless_update)]
+        Box::new(typeshare_core::language::OpenApi {
+            no_version_header: true,
+            $($field: $val,)*
+            ..Default::default()
+        })
+    };
+
+    // Default JSON Schema
+    (jsonschema) => {
+        language_instance!(jsonschema { })
+    };
+
+    // JSON Schema with configuration fields forwarded
+    (jsonschema {$($field:ident: $val:expr),* $(,)?}) => {
+        #[allow(clippy::needless_update)]
+        Box::new(typeshare_core::language::JsonSchema {
+            no_version_header: true,
+            $($field: $val,)*
+            ..Default::default()
+        })
+    };
+
+    // Default Dart
+    (dart) => {
+        language_instance!(dart { })
+    };
+
+    // Dart with configuration fields forwarded
+    (dart {$($field:ident: $val:expr),* $(,)?}) => {
+        #[allow(clippy::needless_update)]
+        Box::new(typeshare_core::language::Dart {
+            no_version_header: true,
+            $($field: $val,)*
+            ..Default::default()
+        })
+    };
+
      // Default Go
     (go) => {
         language_instance!(go { })
@@ -346,7 +497,7 @@ macro_rules! target_os {
 ///
 /// This macro outputs the following structure:
 ///
-/// ```
+/// ```ignore
 /// mod $test {
 ///     #[test]
 ///     fn kotlin() {
@@ -402,48 +553,56 @@ macro_rules! tests {
     )*};
 }
 
-static KOTLIN_MAPPINGS: Lazy<HashMap<String, String>> = Lazy::new(|| {
+static KOTLIN_MAPPINGS: Lazy<HashMap<String, TypeMapping>> = Lazy::new(|| {
     [("Url", "String"), ("DateTime", "String")]
         .iter()
-        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .map(|(k, v)| (k.to_string(), TypeMapping::parse(v)))
         .collect()
 });
 
-static SCALA_MAPPINGS: Lazy<HashMap<String, String>> = Lazy::new(|| {
+static SCALA_MAPPINGS: Lazy<HashMap<String, TypeMapping>> = Lazy::new(|| {
     [("Url", "String"), ("DateTime", "String")]
         .iter()
-        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .map(|(k, v)| (k.to_string(), TypeMapping::parse(v)))
         .collect()
 });
 
-static SWIFT_MAPPINGS: Lazy<HashMap<String, String>> = Lazy::new(|| {
+static SWIFT_MAPPINGS: Lazy<HashMap<String, TypeMapping>> = Lazy::new(|| {
     [("Url", "String"), ("DateTime", "Date")]
         .iter()
-        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .map(|(k, v)| (k.to_string(), TypeMapping::parse(v)))
         .collect()
 });
 
-static TYPESCRIPT_MAPPINGS: Lazy<HashMap<String, String>> = Lazy::new(|| {
+static TYPESCRIPT_MAPPINGS: Lazy<HashMap<String, TypeMapping>> = Lazy::new(|| {
     [("Url", "string"), ("DateTime", "string")]
         .iter()
-        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .map(|(k, v)| (k.to_string(), TypeMapping::parse(v)))
         .collect()
 });
 
-static GO_MAPPINGS: Lazy<HashMap<String, String>> = Lazy::new(|| {
+static GO_MAPPINGS: Lazy<HashMap<String, TypeMapping>> = Lazy::new(|| {
     [("Url", "string"), ("DateTime", "string")]
         .iter()
-        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .map(|(k, v)| (k.to_string(), TypeMapping::parse(v)))
         .collect()
 });
 
-static PYTHON_MAPPINGS: Lazy<HashMap<String, String>> = Lazy::new(|| {
+static PYTHON_MAPPINGS: Lazy<HashMap<String, TypeMapping>> = Lazy::new(|| {
     [("Url", "AnyUrl"), ("DateTime", "datetime")]
         .iter()
-        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .map(|(k, v)| (k.to_string(), TypeMapping::parse(v)))
         .collect()
 });
 
+/// Build a type mapping field from pairs of names.
+fn mappings<T: for<'a> From<&'a str>>(mappings: &[(&str, &str)]) -> HashMap<String, T> {
+    mappings
+        .iter()
+        .map(|(k, v)| (k.to_string(), T::from(v)))
+        .collect()
+}
+
 tests! {
     /// Enums
     can_generate_algebraic_enum: [
@@ -458,7 +617,15 @@ tests! {
             package: "com.agilebits.onepassword".to_string(),
             module_name: "colorsModule".to_string(),
         },
+        java,
+        csharp,
+        dart,
+        jsonschema,
+        openapi,
+        protobuf,
+        graphql,
         typescript,
+        zod,
         go,
         python
     ];
@@ -468,7 +635,13 @@ tests! {
         },
         kotlin,
         scala,
-        typescript
+        java,
+        csharp,
+        dart,
+        jsonschema,
+        openapi,
+        typescript,
+        zod
     ];
     can_generate_generic_struct: [
         swift {
@@ -477,7 +650,13 @@ tests! {
         },
         kotlin,
         scala,
+        java,
+        csharp,
+        dart,
+        jsonschema,
+        openapi,
         typescript,
+        zod,
     ];
     can_generate_generic_type_alias: [
         swift {
@@ -485,9 +664,15 @@ tests! {
         },
         kotlin,
         scala,
-        typescript
+        java,
+        csharp,
+        dart,
+        jsonschema,
+        openapi,
+        typescript,
+        zod
     ];
-    can_generate_slice_of_user_type: [swift, kotlin, scala, typescript, go, python];
+    can_generate_slice_of_user_type: [swift, kotlin, scala, typescript, zod, go, python];
     can_generate_readonly_fields: [
         typescript
     ];
@@ -497,13 +682,22 @@ tests! {
         },
         kotlin,
         scala,
+        java,
+        csharp,
+        dart,
+        jsonschema,
+        openapi,
+        protobuf,
+        graphql,
         typescript,
+        zod,
         go,
         python
     ];
-    can_generate_bare_string_enum: [swift, kotlin, scala, typescript, go, python ];
+    can_generate_bare_string_enum: [swift, kotlin, scala, typescript, go, python, java, csharp, dart, jsonschema, openapi, protobuf, graphql, zod];
     can_generate_double_option_pattern: [
-        typescript
+        typescript,
+        zod
     ];
     can_recognize_types_inside_modules: [
         swift, kotlin, scala, typescript, go, python
@@ -526,13 +720,13 @@ tests! {
         python
     ];
     can_apply_prefix_correctly: [ swift { prefix: "OP".to_string(), }, kotlin { prefix: "OP".to_string(), }, scala,  typescript, go, python ];
-    can_generate_empty_algebraic_enum: [ swift { prefix: "OP".to_string(), }, kotlin { prefix: "OP".to_string(), }, scala,  typescript, go, python ];
+    can_generate_empty_algebraic_enum: [ swift { prefix: "OP".to_string(), }, kotlin { prefix: "OP".to_string(), }, scala,  typescript, go, python, java, csharp, dart, jsonschema, openapi, protobuf, graphql, zod];
     can_generate_algebraic_enum_with_skipped_variants: [swift, kotlin, scala,  typescript, go, python];
     can_generate_struct_with_skipped_fields: [swift, kotlin, scala,  typescript, go, python];
     enum_is_properly_named_with_serde_overrides: [swift, kotlin, scala,  typescript, go, python];
-    can_handle_quote_in_serde_rename: [swift, kotlin, scala,  typescript, go, python];
-    can_handle_anonymous_struct: [swift, kotlin, scala,  typescript, go, python];
-    test_generate_char: [swift, kotlin, scala, typescript, go, python];
+    can_handle_quote_in_serde_rename: [swift, kotlin, scala,  typescript, go, python, java, csharp, dart, jsonschema, openapi, protobuf, graphql, zod];
+    can_handle_anonymous_struct: [swift, kotlin, scala,  typescript, go, python, java, csharp, dart, jsonschema, openapi, protobuf, graphql, zod];
+    test_generate_char: [swift, kotlin, scala, typescript, go, python, java, csharp, dart, jsonschema, openapi, protobuf, graphql, zod];
     anonymous_struct_with_rename: [
         swift {
             prefix: "Core".to_string(),
@@ -543,18 +737,138 @@ tests! {
         go,
         python
     ];
-    can_override_types: [swift, kotlin, scala, typescript, go];
+    can_override_types: [swift, kotlin, scala, typescript, go, java, csharp, dart, jsonschema, openapi, protobuf, graphql, zod];
+    can_import_mapped_types: [
+        swift {
+            type_mappings: super::mappings(&[("DateTime", "Date"), ("Decimal", "BigDecimal"), ("Url", "URL")]),
+            type_mapping_imports: super::mappings(&[("Decimal", "BigDecimal"), ("Url", "Foundation")]),
+        },
+        kotlin {
+            package: "com.agilebits.onepassword".to_string(),
+            module_name: String::new(),
+            type_mappings: super::mappings(&[("DateTime", "Instant"), ("Decimal", "BigDecimal"), ("Url", "String")]),
+            type_mapping_imports: super::mappings(&[("DateTime", "kotlinx.datetime.Instant"), ("Decimal", "java.math.BigDecimal")]),
+        },
+        scala {
+            package: "com.agilebits.onepassword".to_string(),
+            module_name: String::new(),
+            type_mappings: super::mappings(&[("DateTime", "Instant"), ("Decimal", "BigDecimal"), ("Url", "String")]),
+            type_mapping_imports: super::mappings(&[("DateTime", "java.time.Instant")]),
+        },
+        java {
+            package: "com.agilebits.onepassword".to_string(),
+            type_mappings: super::mappings(&[("DateTime", "Instant"), ("Decimal", "BigDecimal"), ("Url", "URI")]),
+            type_mapping_imports: super::mappings(&[("DateTime", "java.time.Instant"), ("Decimal", "java.math.BigDecimal"), ("Url", "java.net.URI")]),
+        },
+        csharp {
+            namespace: "AgileBits.OnePassword".to_string(),
+            type_mappings: super::mappings(&[("DateTime", "DateTimeOffset"), ("Decimal", "decimal"), ("Url", "Uri")]),
+            type_mapping_imports: super::mappings(&[("DateTime", "System"), ("Url", "System")]),
+        },
+        dart {
+            type_mappings: super::mappings(&[("DateTime", "DateTime"), ("Decimal", "Decimal"), ("Url", "Uri")]),
+            type_mapping_imports: super::mappings(&[("Decimal", "package:decimal/decimal.dart")]),
+            json_converters: [
+                ("DateTime", "DateTime.parse", "dateTimeToJson"),
+                ("Decimal", "Decimal.fromJson", "decimalToJson"),
+                ("Url", "Uri.parse", "uriToJson"),
+            ]
+            .into_iter()
+            .map(|(name, from_json, to_json)| (name.to_owned(), typeshare_core::language::JsonConverter {
+                from_json: from_json.to_owned(),
+                to_json: to_json.to_owned(),
+            }))
+            .collect(),
+        },
+        jsonschema {
+            type_mappings: super::mappings(&[("DateTime", r#"{"type": "string", "format": "date-time"}"#), ("Decimal", "string"), ("Url", r#"{"type": "string", "format": "uri"}"#)]),
+        },
+        openapi {
+            title: "Example".to_owned(),
+            type_mappings: super::mappings(&[("DateTime", r#"{"type": "string", "format": "date-time"}"#), ("Decimal", "string"), ("Url", r#"{"type": "string", "format": "uri"}"#)]),
+        },
+        protobuf {
+            type_mappings: super::mappings(&[("DateTime", "google.protobuf.Timestamp"), ("Decimal", "string"), ("Url", "string")]),
+            type_mapping_imports: super::mappings(&[("DateTime", "google/protobuf/timestamp.proto")]),
+        },
+        graphql {
+            type_mappings: super::mappings(&[("DateTime", "DateTime"), ("Decimal", "Decimal"), ("Url", "String")]),
+            scalar_specifications: super::mappings(&[("DateTime", "https://scalars.graphql.org/andimarek/date-time")]),
+        },
+        typescript {
+            type_mappings: super::mappings(&[("DateTime", "Date"), ("Decimal", "Decimal"), ("Url", "URL")]),
+            type_mapping_imports: super::mappings(&[("Decimal", "decimal.js")]),
+        },
+        zod {
+            type_mappings: super::mappings(&[("DateTime", "Date"), ("Decimal", "Decimal"), ("Url", "string")]),
+            zod_type_mappings: super::mappings(&[("DateTime", "z.coerce.date()")]),
+            type_mapping_imports: super::mappings(&[("Decimal", "decimal.js")]),
+        },
+        go {
+            type_mappings: super::mappings(&[("DateTime", "time.Time"), ("Decimal", "decimal.Decimal"), ("Url", "string")]),
+            type_mapping_imports: super::mappings(&[("DateTime", "time"), ("Decimal", "github.com/shopspring/decimal")]),
+        },
+        python {
+            type_mappings: super::mappings(&[("DateTime", "datetime"), ("Decimal", "Decimal"), ("Url", "AnyUrl")]),
+            type_mapping_imports: super::mappings(&[("Decimal", "decimal"), ("Url", "pydantic")]),
+        }
+    ];
+    can_use_idiomatic_field_names: [
+        swift {
+            field_naming: typeshare_core::FieldNaming::CamelCase,
+        },
+        kotlin {
+            field_naming: typeshare_core::FieldNaming::CamelCase,
+        },
+        go,
+        python
+    ];
+    can_assign_proto_tags: [protobuf { package: "acme.models".to_string() }];
+    can_generate_graphql_input_types: [graphql];
+    can_generate_graphql_renamed_types: [graphql];
+    can_generate_typescript_helpers: [typescript { helpers: true }];
+    can_generate_zod_schemas: [
+        typescript {
+            zod: typeshare_core::language::ZodSchemas::Alongside,
+        },
+        zod
+    ];
+    can_map_generic_types: [
+        swift {
+            type_mappings: super::mappings(&[("IndexMap", "[{0}: {1}]"), ("IndexSet", "Set<$T>")]),
+        },
+        kotlin {
+            type_mappings: super::mappings(&[("IndexMap", "Map<{0}, {1}>"), ("IndexSet", "Set<$T>")]),
+        },
+        typescript {
+            type_mappings: super::mappings(&[("IndexMap", "Map<{0}, {1}>"), ("IndexSet", "Set<$T>")]),
+        },
+        go {
+            type_mappings: super::mappings(&[("IndexMap", "map[{0}]{1}"), ("IndexSet", "[]$T")]),
+        },
+        python {
+            type_mappings: super::mappings(&[("IndexMap", "Dict[{0}, {1}]"), ("IndexSet", "Set[$T]")]),
+        }
+    ];
 
     /// Structs
-    can_generate_simple_struct_with_a_comment: [kotlin, swift, typescript, scala, go, python];
-    generate_types: [kotlin, swift, typescript, scala,  go, python];
+    can_generate_simple_struct_with_a_comment: [kotlin, swift, typescript, scala, go, python, java, csharp, dart, jsonschema, openapi, protobuf, graphql, zod];
+    generate_types: [kotlin, swift, typescript, scala,  go, python, java, csharp, dart, jsonschema, openapi, protobuf, graphql, zod];
     can_handle_serde_rename: [
         swift {
             prefix: "TypeShareX_".to_string(),
         },
         kotlin,
         scala,
+        java,
+        csharp,
+        dart,
+        jsonschema,
+        openapi,
+        protobuf,
+        graphql,
         typescript,
+        zod,
         go,
         python
     ];
@@ -562,8 +876,8 @@ tests! {
     generates_empty_structs_and_initializers: [swift, kotlin, scala, typescript, go,python];
     test_default_decorators: [swift { default_decorators: vec!["Sendable".into(), "Identifiable".into()]}];
     test_default_generic_constraints: [swift { default_generic_constraints: typeshare_core::language::GenericConstraints::from_config(vec!["Sendable".into(), "Identifiable".into()]) }];
-    test_i54_u53_type: [swift, kotlin, scala,  typescript, go, python];
-    test_serde_default_struct: [swift, kotlin, scala,  typescript, go, python];
+    test_i54_u53_type: [swift, kotlin, scala,  typescript, zod, go, python];
+    test_serde_default_struct: [swift, kotlin, scala,  typescript, go, python, java, csharp, dart, jsonschema, openapi, protobuf, graphql, zod];
     test_serde_iso8601: [
         swift {
             prefix: String::new(),
@@ -612,8 +926,8 @@ tests! {
             type_mappings: super::PYTHON_MAPPINGS.clone()
         }
     ];
-    test_type_alias: [ swift { prefix: "OP".to_string(), }, kotlin, scala,  typescript, go, python ];
-    test_optional_type_alias: [swift, kotlin, scala, typescript, go, python];
+    test_type_alias: [ swift { prefix: "OP".to_string(), }, kotlin, scala,  typescript, go, python, java, csharp, dart, jsonschema, openapi, protobuf, graphql, zod];
+    test_optional_type_alias: [swift, kotlin, scala, typescript, go, python, java, csharp, dart, jsonschema, openapi, protobuf, graphql, zod];
     test_serialized_as: [ swift { prefix: "OP".to_string(), }, kotlin, scala,  typescript, go, python ];
     test_serialized_as_tuple: [
         swift {
@@ -629,19 +943,19 @@ tests! {
     ];
     can_handle_serde_rename_all: [swift, kotlin, scala,  typescript, go,python];
     can_handle_serde_rename_on_top_level: [swift { prefix: "OP".to_string(), }, kotlin, scala,  typescript, go, python];
-    can_generate_unit_structs: [swift, kotlin, scala, typescript, go, python];
-    kebab_case_rename: [swift, kotlin, scala,  typescript, go, python];
+    can_generate_unit_structs: [swift, kotlin, scala, typescript, go, python, java, csharp, dart, jsonschema, openapi, protobuf, graphql, zod];
+    kebab_case_rename: [swift, kotlin, scala,  typescript, go, python, java, csharp, dart, jsonschema, openapi, protobuf, graphql, zod];
 
     /// Globals get topologically sorted
     orders_types: [swift, kotlin, go, python];
 
     /// Other
-    use_correct_integer_types: [swift, kotlin, scala,  typescript, go, python];
+    use_correct_integer_types: [swift, kotlin, scala,  typescript, go, python, java, csharp, dart, jsonschema, openapi, protobuf, graphql, zod];
     // Only swift supports generating types with keywords
     generate_types_with_keywords: [swift];
     // TODO: how is this different from generates_empty_structs_and_initializers?
     use_correct_decoded_variable_name: [swift, kotlin, scala,  typescript, go, python];
-    can_handle_unit_type: [swift { codablevoid_constraints: vec!["Equatable".into()]} , kotlin, scala,  typescript, go, python];
+    can_handle_unit_type: [swift { codablevoid_constraints: vec!["Equatable".into()]} , kotlin, scala,  typescript, go, python, java, csharp, dart, jsonschema, openapi, protobuf, graphql, zod];
 
     //3 tests for adding decorators to enums and structs
     const_enum_decorator: [ swift{ prefix: "OP".to_string(), } ];
@@ -672,5 +986,46 @@ tests! {
     generic_struct_with_constraints_and_decorators: [swift { codablevoid_constraints: vec!["Equatable".into()] }];
     excluded_by_target_os: [ swift, kotlin, scala, typescript, go,python ] target_os: ["android", "macos"];
     // excluded_by_target_os_full_module: [swift] target_os: "ios";
-    serde_rename_references: [ swift, kotlin, scala, typescript, go ];
+    serde_rename_references: [ swift, kotlin, scala, typescript, go, jsonschema, openapi, graphql ];
+    serde_rename_generic_references: [ typescript, jsonschema, openapi ];
+}
+
+/// Unit enums in every style, with their schemas. The styles are all TypeScript, so
+/// they can't share an output file like the languages of other tests.
+mod can_generate_typescript_unit_enum_styles {
+    use super::check;
+    use typeshare_core::language::{TypeScript, UnitEnumStyle, ZodSchemas};
+
+    const TEST_NAME: &str = "can_generate_typescript_unit_enum_styles";
+
+    fn check_style(file_name: &str, unit_enums: UnitEnumStyle) -> Result<(), anyhow::Error> {
+        crate::init_log();
+        let typescript = TypeScript {
+            no_version_header: true,
+            zod: ZodSchemas::Alongside,
+            unit_enums,
+            ..Default::default()
+        };
+        check(TEST_NAME, file_name, Box::new(typescript), &[])
+    }
+
+    #[test]
+    fn enum_style() -> Result<(), anyhow::Error> {
+        check_style("output.enum.ts", UnitEnumStyle::Enum)
+    }
+
+    #[test]
+    fn const_enum_style() -> Result<(), anyhow::Error> {
+        check_style("output.const_enum.ts", UnitEnumStyle::ConstEnum)
+    }
+
+    #[test]
+    fn union_style() -> Result<(), anyhow::Error> {
+        check_style("output.union.ts", UnitEnumStyle::Union)
+    }
+
+    #[test]
+    fn const_object_style() -> Result<(), anyhow::Error> {
+        check_style("output.const_object.ts", UnitEnumStyle::ConstObject)
+    }
 }